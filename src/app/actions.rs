@@ -1,9 +1,12 @@
+use std::collections::{HashMap, HashSet};
 use std::time::Duration;
 use anyhow::Result;
-use chrono::Utc;
+use chrono::{Datelike, Timelike, Utc, Weekday};
 use rodio::Source;
-use crate::app::state::{App, AsyncNotification, Dialog, Screen, Toast, ToastType};
-use crate::settings::SettingsField;
+use crate::app::state::{App, AsyncNotification, AutoStopField, AutoStopMode, AutoStopRule, Dialog, InstanceAction, InstanceLifecycle, InstanceLifecycleState, LaunchInstanceField, Screen, ScheduleAction, SsoFailureReason, Toast, ToastType, Workspace, AUTO_STOP_DAY_ORDER};
+use crate::assistant::{AssistantClient, ProposedAction, ResourceContext};
+use crate::settings::{Settings, SettingsField};
+use crate::theme;
 
 // Helper function to play sound
 fn play_alert_sound() {
@@ -17,6 +20,20 @@ fn play_alert_sound() {
     });
 }
 
+/// Fire a native OS desktop notification on a background thread, so a slow or
+/// missing notification daemon (e.g. a headless box) can't stall the UI thread.
+fn send_desktop_notification(summary: &str, body: &str) {
+    let summary = summary.to_string();
+    let body = body.to_string();
+    std::thread::spawn(move || {
+        let _ = notify_rust::Notification::new()
+            .summary(&summary)
+            .body(&body)
+            .appname("AWS Cloud Controller")
+            .show();
+    });
+}
+
 // Helper to format duration
 fn format_duration(duration: chrono::Duration) -> String {
     let hours = duration.num_hours();
@@ -24,88 +41,240 @@ fn format_duration(duration: chrono::Duration) -> String {
     format!("{}h {}m", hours, minutes)
 }
 
+/// Preset auto-stop durations shown in the `ScheduleAutoStop` dialog, cycled with
+/// ←/→. The trailing `None` entry is "Custom", which drops into free-text entry
+/// via `auto_stop_duration_input` instead of carrying a fixed `Duration`.
+pub(crate) const AUTO_STOP_PRESETS: &[(&str, Option<Duration>)] = &[
+    ("15m", Some(Duration::from_secs(15 * 60))),
+    ("30m", Some(Duration::from_secs(30 * 60))),
+    ("1h", Some(Duration::from_secs(60 * 60))),
+    ("2h", Some(Duration::from_secs(2 * 60 * 60))),
+    ("4h", Some(Duration::from_secs(4 * 60 * 60))),
+    ("Custom", None),
+];
+
+/// Default preset selected when the dialog opens (matches the old fixed 1-hour stop).
+pub(crate) const AUTO_STOP_DEFAULT_PRESET: usize = 2;
+
+/// How long a due instance sits in `Draining` before `check_scheduled_actions` actually
+/// issues the stop - a cancellable warning window.
+const AUTO_STOP_DRAIN_GRACE_MINUTES: i64 = 2;
+
+/// How long an `instance_lifecycle` entry is kept around before being garbage-collected,
+/// regardless of state - long enough to cover a `Draining` entry's grace period plus a
+/// slow `Stopping` API call, short enough that a stale entry (e.g. the instance was
+/// stopped or terminated by some other means mid-drain) doesn't linger indefinitely.
+const AUTO_STOP_LIFECYCLE_TTL: chrono::Duration = chrono::Duration::hours(1);
+
+/// Ceiling on how long a spawned `aws sso login` subprocess may run before it's treated
+/// as hung (the browser flow never completed) and reported as a timeout.
+const SSO_LOGIN_TIMEOUT: Duration = Duration::from_secs(180);
+
+/// Ceiling on how long rebuilding the AWS client for a newly-activated profile may take
+/// before it's treated as hung.
+const PROFILE_ACTIVATION_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// An action offered in the command palette
+pub struct PaletteCommand {
+    pub label: &'static str,
+    pub action: PaletteAction,
+}
+
+/// Identifies an action the command palette can dispatch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaletteAction {
+    StartInstance,
+    StopInstance,
+    TerminateInstance,
+    ScheduleAutoStop,
+    Refresh,
+    SwitchToHome,
+    SwitchToEc2,
+    SwitchToLambda,
+    SwitchToAbout,
+    OpenSettings,
+    ShowHelp,
+    ConfigureAws,
+    Quit,
+}
+
+/// Static list of commands shown in the palette, in default (unfiltered) order
+pub const PALETTE_COMMANDS: &[PaletteCommand] = &[
+    PaletteCommand { label: "Start instance", action: PaletteAction::StartInstance },
+    PaletteCommand { label: "Stop instance", action: PaletteAction::StopInstance },
+    PaletteCommand { label: "Terminate instance", action: PaletteAction::TerminateInstance },
+    PaletteCommand { label: "Schedule auto-stop", action: PaletteAction::ScheduleAutoStop },
+    PaletteCommand { label: "Refresh", action: PaletteAction::Refresh },
+    PaletteCommand { label: "Switch to Home", action: PaletteAction::SwitchToHome },
+    PaletteCommand { label: "Switch to EC2", action: PaletteAction::SwitchToEc2 },
+    PaletteCommand { label: "Switch to Lambda", action: PaletteAction::SwitchToLambda },
+    PaletteCommand { label: "Switch to About", action: PaletteAction::SwitchToAbout },
+    PaletteCommand { label: "Open settings", action: PaletteAction::OpenSettings },
+    PaletteCommand { label: "Show help", action: PaletteAction::ShowHelp },
+    PaletteCommand { label: "Configure AWS", action: PaletteAction::ConfigureAws },
+    PaletteCommand { label: "Quit", action: PaletteAction::Quit },
+];
+
 impl App {
     // --- Toast & Notification Methods ---
 
-    /// Add a toast notification
+    /// Add a toast notification, with its TTL defaulted from `toast_type`
     pub fn add_toast(&mut self, message: String, toast_type: ToastType) {
         self.toasts.push(Toast {
             message,
+            ttl: toast_type.default_ttl(),
             toast_type,
             created_at: Utc::now(),
+            paused_since: None,
         });
     }
 
-    /// Remove toasts older than 5 seconds
+    /// Expire toasts whose TTL has elapsed, pausing the countdown for whichever toast is
+    /// topmost (the last one pushed, so the first one `render_toasts` draws) and resuming
+    /// it - by shifting `created_at` forward by however long it sat paused - the moment
+    /// something else takes over as topmost.
     pub fn cleanup_old_toasts(&mut self) {
         let now = Utc::now();
-        self.toasts.retain(|toast| {
-            now.signed_duration_since(toast.created_at).num_seconds() < 5
-        });
+        let topmost = self.toasts.len().checked_sub(1);
+
+        for (idx, toast) in self.toasts.iter_mut().enumerate() {
+            if Some(idx) == topmost {
+                toast.paused_since.get_or_insert(now);
+            } else if let Some(paused_since) = toast.paused_since.take() {
+                toast.created_at += now - paused_since;
+            }
+        }
+
+        self.toasts.retain(|toast| toast.paused_since.is_some() || now - toast.created_at < toast.ttl);
+    }
+
+    /// Dismiss whichever toast is currently topmost (the one drawn on top by
+    /// `render_toasts`), if any are showing. Bound to `Action::DismissToast`.
+    pub fn dismiss_topmost_toast(&mut self) {
+        self.toasts.pop();
+    }
+
+    /// Dismiss the toast at the given index into `toasts`, as hit-tested against
+    /// `toast_areas` by a click. A no-op if the index is stale (e.g. the toast already
+    /// expired between the click and this call).
+    pub fn dismiss_toast_at(&mut self, index: usize) {
+        if index < self.toasts.len() {
+            self.toasts.remove(index);
+        }
     }
 
     // --- Refresh & Data Loading Methods ---
 
-    /// Refresh data from AWS
+    /// Kick off a refresh of whichever resource the current screen shows. The actual AWS
+    /// call runs on a spawned task and reports back through `async_tx`/`check_async_notifications`
+    /// (as `Ec2Refreshed`/`LambdaRefreshed`/`AsgRefreshed`) so the render loop and key
+    /// handling don't block on the list call. On the EC2 screen the CloudWatch metrics
+    /// fetch for the selected instance (`refresh_selected_instance_metrics`) is still
+    /// awaited inline here.
+    #[tracing::instrument(skip(self), fields(operation = "refresh_data", screen = ?self.current_screen))]
     pub async fn refresh_data(&mut self) -> Result<()> {
+        // We already track token expiry proactively (see `check_credential_expiry`); if it's
+        // passed, don't bother making a doomed API call just to learn that from the error string.
+        if let Some(expiration) = self.credential_expiration {
+            if expiration <= Utc::now() {
+                self.dialog = Dialog::SessionExpired;
+                self.dialog_scroll_offset = 0;
+                self.status_message = "AWS credentials expired".to_string();
+                self.log_manager.warning("Skipping refresh: credentials already expired".to_string());
+                return Ok(());
+            }
+        }
+
         self.is_loading = true;
         self.status_message = "Loading...".to_string();
 
+        let client = self.aws_client.clone();
+        let tx = self.async_tx.clone();
         match self.current_screen {
             Screen::Ec2 | Screen::Home => {
-                match self.aws_client.list_ec2_instances().await {
-                    Ok(instances) => {
-                        let count = instances.len();
-                        self.ec2_instances = instances;
-                        self.status_message = format!("Loaded {} EC2 instances", count);
-                        self.log_manager.success(format!("Refreshed EC2: {} instances loaded", count));
-                    }
-                    Err(e) => {
-                        let error_str = e.to_string();
-                        self.status_message = format!("Error: {}", error_str);
-                        self.log_manager.error(format!("Failed to load EC2 instances: {}", error_str));
-                        
-                        if Self::is_session_expired_error(&error_str) {
-                            self.dialog = Dialog::SessionExpired;
-                            self.dialog_scroll_offset = 0;
-                            self.log_manager.warning("AWS session token expired - credentials need refresh".to_string());
-                        }
-                    }
-                }
+                // Shares `active_profile_task` with `activate_profile`: a profile switch
+                // that lands while this refresh is still in flight aborts it, so a
+                // response for the *old* profile can't land after the new profile's data.
+                self.spawn_profile_task(async move {
+                    let start = std::time::Instant::now();
+                    let result = client.list_ec2_instances().await.map_err(|e| e.to_string());
+                    tracing::info!(operation = "refresh_data", resource = "ec2", outcome = if result.is_ok() { "success" } else { "error" }, latency_ms = start.elapsed().as_millis() as u64, "EC2 refresh finished");
+                    let _ = tx.send(AsyncNotification::Ec2Refreshed(result));
+                });
             }
             Screen::Lambda => {
-                match self.aws_client.list_lambda_functions().await {
-                    Ok(functions) => {
-                        let count = functions.len();
-                        self.lambda_functions = functions;
-                        self.status_message = format!("Loaded {} Lambda functions", count);
-                        self.log_manager.success(format!("Refreshed Lambda: {} functions loaded", count));
-                    }
-                    Err(e) => {
-                        let error_str = e.to_string();
-                        self.status_message = format!("Error: {}", error_str);
-                        self.log_manager.error(format!("Failed to load Lambda functions: {}", error_str));
-                        
-                        if Self::is_session_expired_error(&error_str) {
-                            self.dialog = Dialog::SessionExpired;
-                            self.dialog_scroll_offset = 0;
-                            self.log_manager.warning("AWS session token expired - credentials need refresh".to_string());
-                        }
-                    }
-                }
+                // Own abort slot (`active_lambda_task`) so a second Lambda refresh
+                // cancels the first instead of letting both land out of order, without
+                // touching an unrelated in-flight EC2 or Auto Scaling refresh. Also
+                // tagged with `active_workspace` so a workspace switch, which doesn't abort
+                // this task (see `switch_workspace`), still gets caught and
+                // dropped in `check_async_notifications` rather than corrupting the
+                // now-active workspace's data.
+                let workspace_id = self.active_workspace;
+                Self::spawn_abortable(&mut self.active_lambda_task, async move {
+                    let start = std::time::Instant::now();
+                    let result = client.list_lambda_functions().await.map_err(|e| e.to_string());
+                    tracing::info!(operation = "refresh_data", resource = "lambda", outcome = if result.is_ok() { "success" } else { "error" }, latency_ms = start.elapsed().as_millis() as u64, "Lambda refresh finished");
+                    let _ = tx.send(AsyncNotification::LambdaRefreshed(workspace_id, result));
+                });
+            }
+            Screen::AutoScaling => {
+                // Own abort slot (`active_asg_task`) and workspace tag, same reasoning as
+                // the Lambda branch above.
+                let workspace_id = self.active_workspace;
+                Self::spawn_abortable(&mut self.active_asg_task, async move {
+                    let start = std::time::Instant::now();
+                    let result = client.list_auto_scaling_groups().await.map_err(|e| e.to_string());
+                    tracing::info!(operation = "refresh_data", resource = "asg", outcome = if result.is_ok() { "success" } else { "error" }, latency_ms = start.elapsed().as_millis() as u64, "Auto Scaling Groups refresh finished");
+                    let _ = tx.send(AsyncNotification::AsgRefreshed(workspace_id, result));
+                });
             }
             Screen::About | Screen::Logs => {
+                self.is_loading = false;
                 self.status_message = "Nothing to refresh on this screen".to_string();
             }
         }
 
-        self.is_loading = false;
+        if self.current_screen == Screen::Ec2 {
+            self.refresh_selected_instance_metrics().await;
+        }
+
         self.last_refresh = Some(Utc::now());
         Ok(())
     }
-    
+
+    /// Fetch fresh CloudWatch datapoints for the selected EC2 instance and append them
+    /// to its ring-buffered metric history
+    async fn refresh_selected_instance_metrics(&mut self) {
+        let Some(instance) = self.ec2_instances.get(self.ec2_selected) else {
+            return;
+        };
+        let instance_id = instance.id.clone();
+
+        match self.aws_client.get_instance_metrics(&instance_id).await {
+            Ok(metrics) => {
+                let history = self.ec2_metrics.entry(instance_id).or_default();
+                for point in &metrics.cpu {
+                    history.push_cpu((point.timestamp.timestamp() as f64, point.value));
+                }
+                for point in &metrics.network_in {
+                    history.push_network_in((point.timestamp.timestamp() as f64, point.value));
+                }
+                for point in &metrics.network_out {
+                    history.push_network_out((point.timestamp.timestamp() as f64, point.value));
+                }
+            }
+            Err(e) => {
+                self.log_manager.warning(format!("Failed to fetch CloudWatch metrics: {}", e));
+            }
+        }
+    }
+
     /// Check if auto-refresh should trigger
     pub async fn check_auto_refresh(&mut self) -> Result<()> {
+        // Scheduled stops fire regardless of which screen is open or whether a dialog is up
+        self.check_scheduled_actions().await?;
+
         if self.current_screen == Screen::About || self.dialog != Dialog::None {
             return Ok(());
         }
@@ -176,52 +345,40 @@ impl App {
     pub async fn start_selected_instance(&mut self) -> Result<()> {
         if let Some(instance) = self.ec2_instances.get(self.ec2_selected) {
             let id = instance.id.clone();
-            let name = instance.name.clone();
-            self.status_message = format!("Starting {}...", id);
-            
-            match self.aws_client.start_instance(&id).await {
-                Ok(_) => {
-                    self.status_message = format!("Started {}", id);
-                    self.add_toast(format!("✓ Started: {}", name), ToastType::Success);
-                    self.log_manager.success(format!("Started EC2 instance: {} ({})", name, id));
-                    self.activate_boost_refresh();
-                    self.refresh_data().await?;
-                }
-                Err(e) => {
-                    self.status_message = format!("Failed to start: {}", e);
-                    self.add_toast(format!("✗ Failed to start: {}", name), ToastType::Error);
-                    self.log_manager.error(format!("Failed to start {}: {}", name, e));
-                }
-            }
+            self.start_instance(&id).await?;
         }
         Ok(())
     }
 
+    /// Start an arbitrary EC2 instance by id, e.g. one proposed by the ops assistant. The
+    /// EC2 API call runs on a spawned task and reports back via `InstanceActionDone` so
+    /// this returns as soon as the request is dispatched.
+    #[tracing::instrument(skip(self), fields(operation = "start_instance", instance_id = %instance_id))]
+    pub async fn start_instance(&mut self, instance_id: &str) -> Result<()> {
+        self.status_message = format!("Starting {}...", instance_id);
+        self.spawn_instance_action(instance_id, InstanceAction::Start);
+        Ok(())
+    }
+
     /// Stop the selected EC2 instance
     pub async fn stop_selected_instance(&mut self) -> Result<()> {
         if let Some(instance) = self.ec2_instances.get(self.ec2_selected) {
             let id = instance.id.clone();
-            let name = instance.name.clone();
-            self.status_message = format!("Stopping {}...", id);
-            
-            match self.aws_client.stop_instance(&id).await {
-                Ok(_) => {
-                    self.status_message = format!("Stopped {}", id);
-                    self.add_toast(format!("✓ Stopped: {}", name), ToastType::Success);
-                    self.log_manager.success(format!("Stopped EC2 instance: {} ({})", name, id));
-                    self.activate_boost_refresh();
-                    self.refresh_data().await?;
-                }
-                Err(e) => {
-                    self.status_message = format!("Failed to stop: {}", e);
-                    self.add_toast(format!("✗ Failed to stop: {}", name), ToastType::Error);
-                    self.log_manager.error(format!("Failed to stop {}: {}", name, e));
-                }
-            }
+            self.stop_instance(&id).await?;
         }
         Ok(())
     }
 
+    /// Stop an arbitrary EC2 instance by id, e.g. one proposed by the ops assistant. The
+    /// EC2 API call runs on a spawned task and reports back via `InstanceActionDone` so
+    /// this returns as soon as the request is dispatched.
+    #[tracing::instrument(skip(self), fields(operation = "stop_instance", instance_id = %instance_id))]
+    pub async fn stop_instance(&mut self, instance_id: &str) -> Result<()> {
+        self.status_message = format!("Stopping {}...", instance_id);
+        self.spawn_instance_action(instance_id, InstanceAction::Stop);
+        Ok(())
+    }
+
     /// Confirm termination dialog
     pub fn confirm_terminate_instance(&mut self) -> Result<()> {
         if let Some(instance) = self.ec2_instances.get(self.ec2_selected) {
@@ -231,27 +388,167 @@ impl App {
         Ok(())
     }
 
-    /// Terminate an EC2 instance
+    /// Terminate an EC2 instance. The EC2 API call runs on a spawned task and reports
+    /// back via `InstanceActionDone` so this returns as soon as the request is dispatched.
+    #[tracing::instrument(skip(self), fields(operation = "terminate_instance", instance_id = %instance_id))]
     pub async fn terminate_instance(&mut self, instance_id: &str) -> Result<()> {
-        let instance_name = self.ec2_instances.iter()
-            .find(|i| i.id == instance_id)
-            .map(|i| i.name.clone())
-            .unwrap_or_else(|| instance_id.to_string());
-            
         self.status_message = format!("Terminating {}...", instance_id);
-        
-        match self.aws_client.terminate_instance(instance_id).await {
+        self.spawn_instance_action(instance_id, InstanceAction::Terminate);
+        Ok(())
+    }
+
+    /// Reboot the selected EC2 instance in place (not a stop-then-start), marking it
+    /// `rebooting` so the table shows that rather than briefly reading "stopped"
+    pub async fn reboot_selected_instance(&mut self) -> Result<()> {
+        if let Some(instance) = self.ec2_instances.get(self.ec2_selected) {
+            let id = instance.id.clone();
+            self.status_message = format!("Rebooting {}...", id);
+            self.rebooting_instances.insert(id.clone());
+            self.spawn_instance_action(&id, InstanceAction::Reboot);
+        }
+        Ok(())
+    }
+
+    /// Dispatch `action` against `instance_id` on a background task, cloning the AWS
+    /// client and `async_tx` so the EC2 call's latency never blocks the render loop.
+    /// The result comes back through `AsyncNotification::InstanceActionDone`.
+    fn spawn_instance_action(&self, instance_id: &str, action: InstanceAction) {
+        let client = self.aws_client.clone();
+        let tx = self.async_tx.clone();
+        let id = instance_id.to_string();
+
+        tokio::spawn(async move {
+            let start = std::time::Instant::now();
+            let result = match action {
+                InstanceAction::Start => client.start_instance(&id).await,
+                InstanceAction::Stop => client.stop_instance(&id).await,
+                InstanceAction::Terminate => client.terminate_instance(&id).await,
+                InstanceAction::Reboot => client.reboot_instance(&id).await,
+            };
+            let result = result.map(|_| ()).map_err(|e| e.to_string());
+            tracing::info!(
+                operation = %format!("{:?}", action).to_lowercase(),
+                instance_id = %id,
+                outcome = if result.is_ok() { "success" } else { "error" },
+                latency_ms = start.elapsed().as_millis() as u64,
+                "EC2 instance action finished"
+            );
+            let _ = tx.send(AsyncNotification::InstanceActionDone { id, action, result });
+        });
+    }
+
+    /// Open the "Launch instance" dialog with sensible defaults
+    pub fn open_launch_instance_dialog(&mut self) {
+        self.launch_ami_id.clear();
+        self.launch_instance_type = "t3.micro".to_string();
+        self.launch_key_name.clear();
+        self.launch_security_group.clear();
+        self.launch_name.clear();
+        self.launch_spot = false;
+        self.launch_count = 1;
+        self.launch_field = LaunchInstanceField::AmiId;
+        self.launch_error = None;
+        self.dialog = Dialog::LaunchInstance;
+        self.dialog_scroll_offset = 0;
+    }
+
+    /// Launch new EC2 instance(s) with the dialog's drafted parameters, on a background
+    /// task since a spot request can take a while to fulfill. The new instance(s) will
+    /// churn through pending → running, so the result handler boosts the refresh cadence
+    /// the same way start/stop/terminate do. Reports back via `InstanceLaunchDone`.
+    pub async fn launch_instance(&mut self) -> Result<()> {
+        let params = crate::aws::LaunchInstanceParams {
+            ami_id: self.launch_ami_id.trim().to_string(),
+            instance_type: self.launch_instance_type.trim().to_string(),
+            key_name: self.launch_key_name.trim().to_string(),
+            security_group: self.launch_security_group.trim().to_string(),
+            name: self.launch_name.trim().to_string(),
+            count: self.launch_count.max(1) as i32,
+        };
+        let spot = self.launch_spot;
+
+        self.status_message = format!("Launching {} {}instance(s)...", params.count, if spot { "spot " } else { "" });
+        self.is_loading = true;
+
+        let client = self.aws_client.clone();
+        let tx = self.async_tx.clone();
+        tokio::spawn(async move {
+            let start = std::time::Instant::now();
+            let result = if spot {
+                client.request_spot_instance(&params).await
+            } else {
+                client.run_instances(&params).await
+            }
+            .map_err(|e| e.to_string());
+            tracing::info!(
+                operation = "launch_instance",
+                spot,
+                outcome = if result.is_ok() { "success" } else { "error" },
+                latency_ms = start.elapsed().as_millis() as u64,
+                "EC2 instance launch finished"
+            );
+            let _ = tx.send(AsyncNotification::InstanceLaunchDone { spot, result });
+        });
+        Ok(())
+    }
+
+    // --- Auto Scaling Group Actions ---
+
+    /// Scale the selected Auto Scaling Group's desired capacity to zero, e.g. for cost
+    /// savings outside business hours
+    pub async fn scale_selected_group_to_zero(&mut self) -> Result<()> {
+        let Some(group) = self.asg_groups.get(self.asg_selected) else {
+            return Ok(());
+        };
+        let group_name = group.name.clone();
+        self.status_message = format!("Scaling {} to zero...", group_name);
+
+        match self.aws_client.set_desired_capacity(&group_name, 0).await {
+            Ok(_) => {
+                self.status_message = format!("Scaled {} to zero", group_name);
+                self.add_toast(format!("✓ Scaled to zero: {}", group_name), ToastType::Success);
+                self.log_manager.success(format!("Scaled Auto Scaling Group {} to zero", group_name));
+                self.activate_boost_refresh();
+                self.refresh_data().await?;
+            }
+            Err(e) => {
+                self.status_message = format!("Failed to scale {}: {}", group_name, e);
+                self.add_toast(format!("✗ Failed to scale: {}", group_name), ToastType::Error);
+                self.log_manager.error(format!("Failed to scale {} to zero: {}", group_name, e));
+            }
+        }
+        Ok(())
+    }
+
+    /// Confirm detaching the selected instance from its Auto Scaling Group (e.g. before
+    /// stopping it standalone, so the group doesn't just replace it)
+    pub fn confirm_detach_selected_instance(&mut self) -> Result<()> {
+        if let Some(group) = self.asg_groups.get(self.asg_selected) {
+            if let Some(instance_id) = group.instance_ids.get(self.asg_instance_selected) {
+                self.dialog = Dialog::ConfirmDetachInstance(group.name.clone(), instance_id.clone());
+                self.dialog_scroll_offset = 0;
+            }
+        }
+        Ok(())
+    }
+
+    /// Detach an instance from an Auto Scaling Group, decrementing desired capacity so
+    /// the group doesn't immediately launch a replacement
+    pub async fn detach_instance(&mut self, group_name: &str, instance_id: &str) -> Result<()> {
+        self.status_message = format!("Detaching {} from {}...", instance_id, group_name);
+
+        match self.aws_client.detach_instances(group_name, &[instance_id.to_string()], true).await {
             Ok(_) => {
-                self.status_message = format!("Terminated {}", instance_id);
-                self.add_toast(format!("✓ Terminated: {}", instance_name), ToastType::Success);
-                self.log_manager.success(format!("Terminated EC2 instance: {} ({})", instance_name, instance_id));
+                self.status_message = format!("Detached {} from {}", instance_id, group_name);
+                self.add_toast(format!("✓ Detached: {}", instance_id), ToastType::Success);
+                self.log_manager.success(format!("Detached {} from Auto Scaling Group {}", instance_id, group_name));
                 self.activate_boost_refresh();
                 self.refresh_data().await?;
             }
             Err(e) => {
-                self.status_message = format!("Failed to terminate: {}", e);
-                self.add_toast(format!("✗ Failed to terminate: {}", instance_name), ToastType::Error);
-                self.log_manager.error(format!("Failed to terminate {}: {}", instance_name, e));
+                self.status_message = format!("Failed to detach {}: {}", instance_id, e);
+                self.add_toast(format!("✗ Failed to detach: {}", instance_id), ToastType::Error);
+                self.log_manager.error(format!("Failed to detach {} from {}: {}", instance_id, group_name, e));
             }
         }
         Ok(())
@@ -262,196 +559,1489 @@ impl App {
         if let Some(instance) = self.ec2_instances.get(self.ec2_selected) {
             self.dialog = Dialog::ScheduleAutoStop(instance.id.clone());
             self.dialog_scroll_offset = 0;
+            self.auto_stop_mode = AutoStopMode::Duration;
+            self.auto_stop_action = ScheduleAction::Stop;
+            self.auto_stop_field = AutoStopField::Mode;
+            self.auto_stop_preset_index = AUTO_STOP_DEFAULT_PRESET;
+            self.auto_stop_duration_input.clear();
+            self.auto_stop_duration_error = None;
+            self.auto_stop_days.clear();
+            self.auto_stop_hour = 18;
+            self.auto_stop_minute = 0;
         }
         Ok(())
     }
 
-    /// Schedule auto-stop for an instance
-    pub fn schedule_auto_stop(&mut self, instance_id: &str, duration: Duration) -> Result<()> {
-        let stop_time = Utc::now() + chrono::Duration::from_std(duration)?;
-        let instance_name = self.ec2_instances.iter()
-            .find(|i| i.id == instance_id)
-            .map(|i| i.name.clone())
-            .unwrap_or_else(|| instance_id.to_string());
-        
-        self.auto_stop_schedules.retain(|(id, _)| id != instance_id);
-        self.auto_stop_schedules.push((instance_id.to_string(), stop_time));
-        
-        self.status_message = format!("Scheduled auto-stop for {} at {}", instance_id, stop_time.format("%H:%M:%S"));
-        self.add_toast(format!("⏰ Scheduled: {}", instance_name), ToastType::Success);
-        self.log_manager.success(format!("Scheduled auto-stop for {} ({}) at {}", instance_name, instance_id, stop_time.format("%H:%M:%S")));
-        Ok(())
-    }
-
-    /// Check for alerts
-    pub fn check_alerts(&mut self) {
-        let now = Utc::now();
-        if let Some(last_check) = self.last_alert_check {
-            if now.signed_duration_since(last_check).num_seconds() < 30 {
-                return;
+    /// The `ScheduleAutoStop` dialog's field order for the current mode, used by Up/Down
+    /// to move `auto_stop_field` and by ←/→ to edit whichever field is selected
+    fn auto_stop_field_order(&self) -> Vec<AutoStopField> {
+        let mut fields = vec![AutoStopField::Mode, AutoStopField::Action];
+        match self.auto_stop_mode {
+            AutoStopMode::Duration => fields.push(AutoStopField::Preset),
+            AutoStopMode::Recurring => {
+                fields.extend(AUTO_STOP_DAY_ORDER.iter().map(|day| AutoStopField::Day(*day)));
+                fields.push(AutoStopField::Hour);
+                fields.push(AutoStopField::Minute);
             }
         }
-        self.last_alert_check = Some(now);
+        fields
+    }
 
-        let threshold = chrono::Duration::from_std(self.config.alerts.alert_threshold)
-            .unwrap_or(chrono::Duration::hours(1));
+    /// Move `auto_stop_field` to the next/previous field for the current mode, with
+    /// wraparound (mirrors `navigate_settings_field`'s up=prev/down=next convention)
+    pub fn navigate_auto_stop_field(&mut self, up: bool) {
+        let order = self.auto_stop_field_order();
+        let len = order.len() as i32;
+        let current = order.iter().position(|f| *f == self.auto_stop_field).unwrap_or(0) as i32;
+        let next = if up { current - 1 } else { current + 1 };
+        self.auto_stop_field = order[next.rem_euclid(len) as usize];
+    }
 
-        for instance in &self.ec2_instances {
-            if instance.state == "running" {
-                let has_schedule = self.auto_stop_schedules.iter().any(|(id, _)| *id == instance.id);
-                if !has_schedule {
-                    if let Some(launch_time) = instance.launch_time {
-                        let running_duration = now.signed_duration_since(launch_time);
-                        if running_duration > threshold {
-                            let alert_msg = format!(
-                                "⚠️ Instance {} ({}) running for {} without auto-stop!",
-                                instance.name, instance.id, format_duration(running_duration)
-                            );
-                            if !self.pending_alerts.contains(&alert_msg) {
-                                self.pending_alerts.push(alert_msg.clone());
-                                self.dialog = Dialog::Alert(alert_msg);
-                                self.dialog_scroll_offset = 0;
-                                if self.config.alerts.sound_enabled {
-                                    play_alert_sound();
-                                }
-                            }
-                        }
-                    }
+    /// Edit whichever `ScheduleAutoStop` field ←/→ is currently pointed at: toggle the
+    /// mode, cycle the duration preset, toggle a day on/off, or nudge the hour/minute.
+    pub fn modify_auto_stop_field(&mut self, delta: i32) {
+        match self.auto_stop_field {
+            AutoStopField::Mode => {
+                self.auto_stop_mode = self.auto_stop_mode.toggled();
+                self.auto_stop_field = AutoStopField::Mode;
+                self.auto_stop_duration_error = None;
+            }
+            AutoStopField::Action => {
+                self.auto_stop_action = self.auto_stop_action.toggled();
+                self.auto_stop_duration_error = None;
+            }
+            AutoStopField::Preset => {
+                let len = AUTO_STOP_PRESETS.len() as i32;
+                let current = self.auto_stop_preset_index as i32;
+                self.auto_stop_preset_index = ((current + delta).rem_euclid(len)) as usize;
+                self.auto_stop_duration_input.clear();
+                self.auto_stop_duration_error = None;
+            }
+            AutoStopField::Day(day) => {
+                if !self.auto_stop_days.remove(&day) {
+                    self.auto_stop_days.insert(day);
                 }
+                self.auto_stop_duration_error = None;
+            }
+            AutoStopField::Hour => {
+                self.auto_stop_hour = (self.auto_stop_hour as i32 + delta).rem_euclid(24) as u32;
+            }
+            AutoStopField::Minute => {
+                self.auto_stop_minute = (self.auto_stop_minute as i32 + delta * 5).rem_euclid(60) as u32;
             }
         }
     }
-    
-    // --- Settings Methods ---
 
-    pub fn open_settings_dialog(&mut self) {
-        self.settings_draft = Some(self.settings.clone());
-        self.settings_selected_field = SettingsField::RefreshInterval;
-        self.dialog = Dialog::Settings;
-        self.dialog_scroll_offset = 0;
-        self.log_manager.info("Opened settings dialog".to_string());
+    /// Whether the "Custom" entry (the last preset) is currently selected in Duration mode.
+    pub fn auto_stop_custom_selected(&self) -> bool {
+        self.auto_stop_mode == AutoStopMode::Duration
+            && self.auto_stop_preset_index == AUTO_STOP_PRESETS.len() - 1
     }
-    
-    pub fn save_settings(&mut self) {
-        if let Some(draft) = self.settings_draft.take() {
-            self.settings = draft;
-            self.auto_refresh_interval = self.settings.refresh_interval();
-            if let Err(e) = self.settings.save() {
-                self.add_toast(format!("Failed to save settings: {}", e), ToastType::Error);
-                self.log_manager.error(format!("Failed to save settings: {}", e));
+
+    /// Parse a duration string like `30m`, `2h`, or `1h30m` into a `Duration`.
+    /// A bare number with no unit suffix (e.g. `90`) is treated as minutes.
+    pub fn parse_auto_stop_duration(input: &str) -> Option<Duration> {
+        let input = input.trim().to_lowercase();
+        if input.is_empty() {
+            return None;
+        }
+
+        if let Ok(minutes) = input.parse::<u64>() {
+            return (minutes > 0).then(|| Duration::from_secs(minutes * 60));
+        }
+
+        let mut total_secs: u64 = 0;
+        let mut digits = String::new();
+        let mut saw_unit = false;
+
+        for ch in input.chars() {
+            if ch.is_ascii_digit() {
+                digits.push(ch);
             } else {
-                self.add_toast("Settings saved".to_string(), ToastType::Success);
-                self.log_manager.success("Settings saved".to_string());
+                let value: u64 = digits.parse().ok()?;
+                digits.clear();
+                total_secs += match ch {
+                    'h' => value * 3600,
+                    'm' => value * 60,
+                    's' => value,
+                    _ => return None,
+                };
+                saw_unit = true;
             }
         }
-        self.dialog = Dialog::None;
-    }
-    
-    pub fn cancel_settings(&mut self) {
-        self.settings_draft = None;
-        self.dialog = Dialog::None;
-        self.log_manager.info("Settings dialog cancelled".to_string());
-    }
-    
-    pub fn modify_current_setting(&mut self, delta: i32) {
-        if let Some(ref mut draft) = self.settings_draft {
-            let forward = delta > 0;
-            match self.settings_selected_field {
-                SettingsField::RefreshInterval => draft.cycle_refresh_interval(forward),
-                SettingsField::ShowLogsPanel => draft.toggle_logs_panel(),
-                SettingsField::LogLevel => draft.cycle_log_level(forward),
-                SettingsField::AlertThreshold => draft.cycle_alert_threshold(forward),
-                SettingsField::SoundEnabled => draft.toggle_sound(),
-                SettingsField::TestSound => {} 
-            }
+
+        if !digits.is_empty() || !saw_unit || total_secs == 0 {
+            return None;
         }
-    }
-    
-    pub fn navigate_settings_field(&mut self, up: bool) {
-        self.settings_selected_field = if up {
-            self.settings_selected_field.prev()
-        } else {
-            self.settings_selected_field.next()
-        };
-    }
 
-    /// Trigger a test alert
-    pub fn trigger_test_alert(&mut self) {
-        play_alert_sound();
-        self.add_toast("🔔 Test Alert: System Sound Working".to_string(), ToastType::Info);
-        self.log_manager.info("Triggered test alert sound".to_string());
+        Some(Duration::from_secs(total_secs))
     }
 
-    // --- Auth & Profile Methods ---
+    /// Schedule a one-shot stop or start for an instance after the given duration
+    pub fn schedule_auto_stop(&mut self, instance_id: &str, duration: Duration, action: ScheduleAction) -> Result<()> {
+        let fire_time = Utc::now() + chrono::Duration::from_std(duration)?;
+        let instance_name = self.ec2_instances.iter()
+            .find(|i| i.id == instance_id)
+            .map(|i| i.name.clone())
+            .unwrap_or_else(|| instance_id.to_string());
+        let verb = action.label().to_lowercase();
 
-    pub async fn login_with_sso(&mut self) -> Result<()> {
-        self.status_message = "Initiating AWS SSO Login...".to_string();
-        self.add_toast("🔑 Starting AWS SSO login... check browser".to_string(), ToastType::Info);
-        
-        let tx = self.async_tx.clone();
-        let profile = if !self.available_profiles.is_empty() {
-             Some(self.available_profiles[self.selected_profile_index].clone())
-        } else {
-             None
-        };
+        self.auto_stop_schedules.retain(|(id, _)| id != instance_id);
+        self.auto_stop_schedules.push((instance_id.to_string(), AutoStopRule::Once(fire_time, action)));
 
-        std::thread::spawn(move || {
-            let mut cmd = std::process::Command::new("aws");
-            cmd.arg("sso").arg("login");
-            if let Some(ref p) = profile {
-                cmd.arg("--profile").arg(p);
-            }
-            match cmd.output() {
-                Ok(output) => {
-                    if output.status.success() {
-                        let profile_name = profile.clone().unwrap_or_else(|| "default".to_string());
-                        let _ = tx.send(AsyncNotification::SsoLoginSuccess("Login successful".to_string(), profile_name));
-                    } else {
-                        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
-                        let err_msg = if stderr.trim().is_empty() {
-                            String::from_utf8_lossy(&output.stdout).to_string()
-                        } else {
-                            stderr
-                        };
-                        let _ = tx.send(AsyncNotification::SsoLoginFailed(err_msg));
-                    }
-                }
-                Err(e) => {
-                     let _ = tx.send(AsyncNotification::SsoLoginFailed(e.to_string()));
-                }
-            }
-        });
-        self.log_manager.info("Spawned 'aws sso login' thread".to_string());
+        self.status_message = format!("Scheduled auto-{} for {} at {}", verb, instance_id, fire_time.format("%H:%M:%S"));
+        self.add_toast(format!("⏰ Scheduled: {}", instance_name), ToastType::Success);
+        self.log_manager.success(format!("Scheduled auto-{} for {} ({}) at {}", verb, instance_name, instance_id, fire_time.format("%H:%M:%S")));
         Ok(())
     }
 
-    pub async fn activate_profile(&mut self, profile_name: &str) -> Result<()> {
-        self.status_message = format!("Switching to profile: {}...", profile_name);
-        self.add_toast(format!("🔄 Switching to profile '{}'...", profile_name), ToastType::Info);
-        self.is_loading = true;
-        
-        std::env::set_var("AWS_PROFILE", profile_name);
-        self.log_manager.info(format!("Set AWS_PROFILE={} and re-initializing client", profile_name));
+    /// Schedule a recurring stop or start for an instance: it fires every matching
+    /// weekday (UTC) at the given hour:minute, until the rule is replaced or the
+    /// instance is re-scheduled
+    pub fn schedule_recurring_auto_stop(
+        &mut self,
+        instance_id: &str,
+        days: HashSet<Weekday>,
+        hour: u32,
+        minute: u32,
+        action: ScheduleAction,
+    ) -> Result<()> {
+        let instance_name = self.ec2_instances.iter()
+            .find(|i| i.id == instance_id)
+            .map(|i| i.name.clone())
+            .unwrap_or_else(|| instance_id.to_string());
+        let verb = action.label().to_lowercase();
 
-        let tx = self.async_tx.clone();
-        let region = self.config.aws_region.clone();
-        let profile_name_owned = profile_name.to_string();
+        let day_list = AUTO_STOP_DAY_ORDER
+            .iter()
+            .filter(|d| days.contains(d))
+            .map(|d| d.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
 
-        tokio::spawn(async move {
-            match crate::aws::AwsClient::new(region.as_deref()).await {
-                Ok(client) => {
-                    let _ = tx.send(AsyncNotification::ProfileActivated(client, profile_name_owned));
-                },
-                Err(e) => {
-                    let _ = tx.send(AsyncNotification::ProfileActivationFailed(e.to_string()));
-                }
-            }
-        });
+        self.auto_stop_schedules.retain(|(id, _)| id != instance_id);
+        self.auto_stop_schedules.push((
+            instance_id.to_string(),
+            AutoStopRule::Recurring { days, hour, minute, action, last_fired: None },
+        ));
 
+        self.status_message = format!("Scheduled recurring auto-{} for {} at {:02}:{:02} ({})", verb, instance_id, hour, minute, day_list);
+        self.add_toast(format!("⏰ Recurring schedule set: {}", instance_name), ToastType::Success);
+        self.log_manager.success(format!(
+            "Scheduled recurring auto-{} for {} ({}) at {:02}:{:02} on {}",
+            verb, instance_name, instance_id, hour, minute, day_list
+        ));
         Ok(())
     }
 
-    pub async fn check_async_notifications(&mut self) -> Result<()> {
+    /// Fire any one-shot or recurring schedule rules whose time has come. Recurring
+    /// rules record `last_fired` so a rule matching during minute 18:00 doesn't re-fire
+    /// on every subsequent tick within that minute.
+    ///
+    /// A newly-due Stop rule isn't actioned immediately: it enters `Draining` in
+    /// `App::instance_lifecycle` for [`AUTO_STOP_DRAIN_GRACE_MINUTES`] minutes first (a
+    /// cancellable warning window, logged and toasted), and only transitions to
+    /// `Stopping` - which actually calls `stop_instance` - once that grace period
+    /// elapses with the instance still tracked. Every lifecycle entry also carries a TTL
+    /// so a stale one (e.g. the instance was stopped or terminated by some other means
+    /// mid-drain) is garbage-collected rather than lingering forever. A due Start rule
+    /// skips the drain pipeline entirely and calls `start_instance` directly - there's
+    /// nothing to gracefully wind down when bringing an instance up.
+    async fn check_scheduled_actions(&mut self) -> Result<()> {
+        let now = Utc::now();
+        let drain_grace = chrono::Duration::minutes(AUTO_STOP_DRAIN_GRACE_MINUTES);
+        let mut due: Vec<(String, ScheduleAction)> = Vec::new();
+
+        for (instance_id, rule) in self.auto_stop_schedules.iter_mut() {
+            match rule {
+                AutoStopRule::Once(fire_time, action) => {
+                    if now >= *fire_time {
+                        due.push((instance_id.clone(), *action));
+                    }
+                }
+                AutoStopRule::Recurring { days, hour, minute, action, last_fired } => {
+                    let fired_this_minute = last_fired
+                        .map(|t| t.date_naive() == now.date_naive() && t.hour() == now.hour() && t.minute() == now.minute())
+                        .unwrap_or(false);
+
+                    if !fired_this_minute && days.contains(&now.weekday()) && now.hour() == *hour && now.minute() == *minute {
+                        *last_fired = Some(now);
+                        due.push((instance_id.clone(), *action));
+                    }
+                }
+            }
+        }
+
+        // One-shot rules are consumed once fired; recurring rules stay registered for next time.
+        let due_ids: HashSet<&String> = due.iter().map(|(id, _)| id).collect();
+        self.auto_stop_schedules
+            .retain(|(id, rule)| !(matches!(rule, AutoStopRule::Once(..)) && due_ids.contains(id)));
+
+        // Newly-due Stop instances enter the Draining grace period instead of stopping
+        // right away. One already mid-lifecycle (e.g. still draining from a previous
+        // fire) is left alone rather than having its grace period reset. Start rules
+        // bypass draining entirely and fire immediately.
+        for (instance_id, action) in due {
+            let instance_name = self.ec2_instances.iter()
+                .find(|i| i.id == instance_id)
+                .map(|i| i.name.clone())
+                .unwrap_or_else(|| instance_id.clone());
+
+            match action {
+                ScheduleAction::Start => {
+                    let is_stopped = self.ec2_instances.iter().any(|i| i.id == instance_id && i.state == "stopped");
+                    if !is_stopped {
+                        continue;
+                    }
+                    self.log_manager.info(format!("Auto-start rule fired for {} ({})", instance_name, instance_id));
+                    self.start_instance(&instance_id).await?;
+                }
+                ScheduleAction::Stop => {
+                    if self.instance_lifecycle.contains_key(&instance_id) {
+                        continue;
+                    }
+                    let is_running = self.ec2_instances.iter().any(|i| i.id == instance_id && i.state == "running");
+                    if !is_running {
+                        continue;
+                    }
+
+                    self.instance_lifecycle.insert(instance_id.clone(), InstanceLifecycle {
+                        state: InstanceLifecycleState::Draining,
+                        last_action_date: now,
+                        ttl: AUTO_STOP_LIFECYCLE_TTL,
+                    });
+                    self.log_manager.info(format!(
+                        "Auto-stop rule fired for {} ({}); draining for {}m before stop",
+                        instance_name, instance_id, AUTO_STOP_DRAIN_GRACE_MINUTES
+                    ));
+                    self.add_toast(format!("⏳ Draining: {} (stop in {}m)", instance_name, AUTO_STOP_DRAIN_GRACE_MINUTES), ToastType::Info);
+                }
+            }
+        }
+
+        // Advance Draining entries whose grace period has elapsed into Stopping, and
+        // garbage-collect any entry (Draining or Stopping) whose TTL has lapsed.
+        let mut to_stop = Vec::new();
+        self.instance_lifecycle.retain(|instance_id, lifecycle| {
+            if lifecycle.is_expired(now) {
+                return false;
+            }
+            if lifecycle.state == InstanceLifecycleState::Draining
+                && now.signed_duration_since(lifecycle.last_action_date) >= drain_grace
+            {
+                lifecycle.state = InstanceLifecycleState::Stopping;
+                lifecycle.last_action_date = now;
+                to_stop.push(instance_id.clone());
+            }
+            true
+        });
+
+        for instance_id in to_stop {
+            let instance_name = self.ec2_instances.iter()
+                .find(|i| i.id == instance_id)
+                .map(|i| i.name.clone())
+                .unwrap_or_else(|| instance_id.clone());
+            self.log_manager.info(format!("Drain grace period elapsed for {} ({}); issuing stop", instance_name, instance_id));
+            self.stop_instance(&instance_id).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Cancel the selected EC2 instance's pending auto-stop drain, if it's currently in
+    /// one - this is what makes the `Draining` grace period (see `check_scheduled_actions`)
+    /// an actually cancellable warning window rather than just a delayed stop. A no-op
+    /// (with an explanatory toast) for an instance that isn't draining, including one
+    /// that's already past the grace period and into `Stopping`.
+    fn cancel_selected_instance_drain(&mut self) {
+        let Some(instance) = self.ec2_instances.get(self.ec2_selected) else {
+            return;
+        };
+        let instance_id = instance.id.clone();
+        let instance_name = instance.name.clone();
+
+        match self.instance_lifecycle.get(&instance_id).map(|l| l.state) {
+            Some(InstanceLifecycleState::Draining) => {
+                self.instance_lifecycle.remove(&instance_id);
+                self.log_manager.info(format!("Cancelled pending auto-stop drain for {} ({})", instance_name, instance_id));
+                self.add_toast(format!("✅ Cancelled auto-stop for {}", instance_name), ToastType::Success);
+            }
+            Some(InstanceLifecycleState::Stopping) => {
+                self.add_toast(format!("{} is already stopping - too late to cancel", instance_name), ToastType::Info);
+            }
+            Some(InstanceLifecycleState::Active) | None => {
+                self.add_toast(format!("{} has no pending auto-stop to cancel", instance_name), ToastType::Info);
+            }
+        }
+    }
+
+    /// Fan out a cost/idle alert to every enabled delivery channel: an in-app toast, an
+    /// optional beep, and (if `notifications_enabled`) a native desktop notification so
+    /// the warning reaches the user even when the TUI isn't focused. The caller is still
+    /// responsible for the `Dialog::warning` popup itself.
+    fn deliver_alert(&mut self, toast_message: String, notification_title: &str, notification_body: String) {
+        self.add_toast(toast_message, ToastType::Error);
+        if self.settings.sound_enabled {
+            play_alert_sound();
+        }
+        if self.settings.notifications_enabled {
+            send_desktop_notification(notification_title, &notification_body);
+        }
+    }
+
+    /// Check for alerts
+    pub fn check_alerts(&mut self) {
+        let now = Utc::now();
+        if let Some(last_check) = self.last_alert_check {
+            if now.signed_duration_since(last_check).num_seconds() < 30 {
+                return;
+            }
+        }
+        self.last_alert_check = Some(now);
+
+        if let Some(profile) = self.active_profile_name.clone() {
+            self.credential_expiration = crate::aws::get_credential_expiration(&profile);
+        }
+        self.check_session_renewal();
+        self.check_credential_expiry();
+
+        let threshold = chrono::Duration::from_std(self.config.alerts.alert_threshold)
+            .unwrap_or(chrono::Duration::hours(1));
+
+        // Forget Slack dedup state for any instance that's since stopped, so a later
+        // crossing (after a restart) alerts again
+        let running_ids: HashSet<&str> = self.ec2_instances.iter().filter(|i| i.state == "running").map(|i| i.id.as_str()).collect();
+        self.slack_alerted_instances.retain(|id| running_ids.contains(id.as_str()));
+
+        let mut newly_alerted = Vec::new();
+        let mut newly_alerted_slack = Vec::new();
+        for instance in &self.ec2_instances {
+            if instance.state != "running" {
+                continue;
+            }
+            let has_schedule = self.auto_stop_schedules.iter().any(|(id, _)| *id == instance.id);
+            if has_schedule {
+                // Scheduled since the last crossing; let a future unscheduled crossing
+                // alert Slack again
+                self.slack_alerted_instances.remove(&instance.id);
+                continue;
+            }
+            let Some(launch_time) = instance.launch_time else { continue };
+            let running_duration = now.signed_duration_since(launch_time);
+            if running_duration <= threshold {
+                continue;
+            }
+            let alert_msg = format!(
+                "⚠️ Instance {} ({}) running for {} without auto-stop!",
+                instance.name, instance.id, format_duration(running_duration)
+            );
+            if !self.pending_alerts.contains(&alert_msg) {
+                newly_alerted.push((instance.name.clone(), instance.id.clone(), alert_msg, format_duration(running_duration)));
+            }
+            // Slack gets its own, stricter dedup (once per crossing, not once per
+            // distinct duration string) so it doesn't re-fire every 30s like the toast can
+            if self.config.alerts.slack_webhook_url.is_some() && self.slack_alerted_instances.insert(instance.id.clone()) {
+                newly_alerted_slack.push((instance.clone(), running_duration));
+            }
+        }
+
+        for (name, id, alert_msg, running_for) in newly_alerted {
+            self.pending_alerts.push(alert_msg.clone());
+            self.dialog = Dialog::warning(alert_msg.clone());
+            self.dialog_scroll_offset = 0;
+            self.deliver_alert(
+                alert_msg,
+                "Instance running without auto-stop",
+                format!("{} ({}) has been running for {}", name, id, running_for),
+            );
+        }
+
+        for (instance, running_duration) in newly_alerted_slack {
+            self.send_slack_alert(instance, running_duration);
+        }
+    }
+
+    /// POST a long-running-instance alert to the configured Slack incoming webhook (see
+    /// `Settings::slack_webhook_url_env`), on a spawned task so the render loop never
+    /// blocks on the HTTP round-trip. Reports back through `AsyncNotification::SlackAlertSent`.
+    fn send_slack_alert(&self, instance: crate::aws::Ec2Instance, running_duration: chrono::Duration) {
+        let Some(webhook_url) = self.config.alerts.slack_webhook_url.clone() else {
+            return;
+        };
+        let region = self.aws_client.region.clone();
+        let tx = self.async_tx.clone();
+
+        let cost_line = match crate::aws::estimate_hourly_cost_usd(&instance.instance_type) {
+            Some(hourly) => {
+                let hours = running_duration.num_seconds() as f64 / 3600.0;
+                format!("~${:.2} so far (${:.3}/hr est.)", hours * hourly, hourly)
+            }
+            None => "unknown (no rate on file for this instance type)".to_string(),
+        };
+        let text = format!(
+            "⚠️ *{}* (`{}`) in `{}` has been running for {} without auto-stop.\nEstimated cost: {}",
+            instance.name, instance.id, region, format_duration(running_duration), cost_line
+        );
+
+        tokio::spawn(async move {
+            let result = reqwest::Client::new()
+                .post(&webhook_url)
+                .json(&serde_json::json!({ "text": text }))
+                .send()
+                .await
+                .and_then(|r| r.error_for_status())
+                .map(|_| ())
+                .map_err(|e| e.to_string());
+            let _ = tx.send(AsyncNotification::SlackAlertSent { instance_id: instance.id, result });
+        });
+    }
+
+    /// Proactively renew the active AWS session once its expiry enters the refresh
+    /// window (`settings.session_renewal_threshold()`), rather than waiting for an API
+    /// call to fail and bounce the user to `Dialog::SessionExpired`. Re-runs
+    /// `aws::run_sso_login` on a background thread (mirroring `login_with_sso`); on
+    /// success the client is rebuilt via `activate_profile` once `SessionRenewed` comes
+    /// back. If the renewal itself fails, nothing here handles it -
+    /// `check_credential_expiry`'s reactive warning (and, eventually, a failed API
+    /// call) is left to catch it.
+    fn check_session_renewal(&mut self) {
+        if self.session_renewal_in_flight {
+            return;
+        }
+        let Some(expiration) = self.credential_expiration else {
+            return;
+        };
+        let remaining = expiration.signed_duration_since(Utc::now());
+        let refresh_window = self.settings.session_renewal_threshold();
+        if remaining <= chrono::Duration::zero() || remaining > refresh_window {
+            return;
+        }
+        let Some(profile) = self.active_profile_name.clone() else {
+            return;
+        };
+
+        self.session_renewal_in_flight = true;
+        self.log_manager.info(format!("Proactively renewing AWS session for profile '{}' (expires in {})", profile, format_duration(remaining)));
+        self.add_toast(format!("🔑 Session expires in {} - renewing...", format_duration(remaining)), ToastType::Info);
+
+        let tx = self.async_tx.clone();
+        let renewal_profile = profile.clone();
+        std::thread::spawn(move || {
+            let result = crate::aws::run_sso_login(Some(&renewal_profile));
+            match result {
+                Ok(()) => {
+                    let _ = tx.send(AsyncNotification::SessionRenewed(renewal_profile));
+                }
+                Err(e) => {
+                    let _ = tx.send(AsyncNotification::SessionRenewalFailed(e.to_string()));
+                }
+            }
+        });
+    }
+
+    /// Warn via a pending alert once the active credentials are close to expiring
+    fn check_credential_expiry(&mut self) {
+        let expiry_warning = self.settings.session_renewal_threshold();
+
+        let Some(expiration) = self.credential_expiration else {
+            return;
+        };
+        let remaining = expiration.signed_duration_since(Utc::now());
+
+        if remaining > expiry_warning {
+            self.credential_expiry_alerted = false;
+            return;
+        }
+
+        if self.credential_expiry_alerted {
+            return;
+        }
+        self.credential_expiry_alerted = true;
+
+        let alert_msg = if remaining.num_seconds() <= 0 {
+            "🔑 AWS credentials have expired. Please re-authenticate.".to_string()
+        } else {
+            format!("🔑 AWS credentials expire in {}. Please re-authenticate soon.", format_duration(remaining))
+        };
+        self.pending_alerts.push(alert_msg.clone());
+        self.dialog = Dialog::warning(alert_msg);
+        self.dialog_scroll_offset = 0;
+        if self.config.alerts.sound_enabled {
+            play_alert_sound();
+        }
+    }
+
+    /// Humanized "expires in 42m" / "expired 5m ago" status-bar segment for the active
+    /// credentials, and whether it should be rendered in an urgent/expired color
+    pub fn credential_expiry_display(&self) -> Option<(String, bool)> {
+        let expiration = self.credential_expiration?;
+        let (text, is_expired) = crate::aws::format_expiry(expiration);
+        let remaining = expiration.signed_duration_since(Utc::now());
+        let is_urgent = is_expired || remaining <= chrono::Duration::minutes(5);
+        Some((text, is_urgent))
+    }
+
+    // --- Settings Methods ---
+
+    /// Re-point `self.settings_watcher` at whichever settings file is now live, after
+    /// its path has changed underneath it (currently only `toggle_format`). Mirrors the
+    /// watcher setup in `App::new` exactly - same reload closure, same notification
+    /// channel - just re-armed against `Settings::watch`'s freshly re-resolved path
+    /// instead of the stale one the old watcher is still holding a handle to.
+    fn rearm_settings_watcher(&mut self) {
+        let reload_tx = self.async_tx.clone();
+        let watcher = Settings::watch(move |result| {
+            let notification = match result {
+                Ok(s) => AsyncNotification::SettingsReloaded(s),
+                Err(e) => AsyncNotification::SettingsReloadFailed(e.to_string()),
+            };
+            let _ = reload_tx.send(notification);
+        });
+        match watcher {
+            Ok(w) => self.settings_watcher = Some(w),
+            Err(e) => self.log_manager.warning(format!("Failed to re-arm settings watcher: {}", e)),
+        }
+    }
+
+    pub fn open_settings_dialog(&mut self) {
+        self.settings_draft = Some(self.settings.clone());
+        self.settings_selected_field = SettingsField::Profile;
+        self.dialog = Dialog::Settings;
+        self.dialog_scroll_offset = 0;
+        self.log_manager.info("Opened settings dialog".to_string());
+    }
+    
+    pub fn save_settings(&mut self) {
+        if let Some(draft) = self.settings_draft.take() {
+            self.settings = draft;
+            self.auto_refresh_interval = self.settings.refresh_interval();
+            self.log_manager.configure_outputs(self.settings.log_outputs.clone());
+            self.theme = theme::theme_styles(self.settings.theme_palette);
+            theme::apply_accent_overrides(&mut self.theme, &self.keymap.resolve_theme_overrides());
+            self.settings_profiles.update_active(self.settings.clone());
+            if let Err(e) = self.settings_profiles.save() {
+                self.add_toast(format!("Failed to save settings: {}", e), ToastType::Error);
+                self.log_manager.error(format!("Failed to save settings: {}", e));
+            } else {
+                self.add_toast("Settings saved".to_string(), ToastType::Success);
+                self.log_manager.success("Settings saved".to_string());
+            }
+        }
+        self.dialog = Dialog::None;
+    }
+    
+    pub fn cancel_settings(&mut self) {
+        self.settings_draft = None;
+        self.dialog = Dialog::None;
+        self.log_manager.info("Settings dialog cancelled".to_string());
+    }
+    
+    pub fn modify_current_setting(&mut self, delta: i32) {
+        let forward = delta > 0;
+        if self.settings_selected_field == SettingsField::Profile {
+            // Switching profiles discards in-progress edits to the previous one, same
+            // as switching AWS profiles does - save first if that's not what you want
+            match self.settings_profiles.cycle_profile(forward) {
+                Ok((name, settings)) => {
+                    self.settings_draft = Some(settings);
+                    self.log_manager.info(format!("Switched settings profile to '{}'", name));
+                }
+                Err(e) => {
+                    self.log_manager.warning(format!("Failed to cycle settings profile: {}", e));
+                }
+            }
+            return;
+        }
+        if self.settings_selected_field == SettingsField::FileFormat {
+            // Like the profile switch above, this is a store-level property rather than
+            // a per-profile one, so it takes effect (and persists) immediately
+            if let Err(e) = self.settings_profiles.toggle_format() {
+                self.log_manager.warning(format!("Failed to switch settings file format: {}", e));
+            } else {
+                self.log_manager.info(format!("Settings now persisted as {}", self.settings_profiles.format.label()));
+                // `toggle_format` moved the live settings file to a new path; the watcher
+                // armed in `App::new` is still pointed at the old (now-deleted) one and
+                // would never fire again otherwise.
+                self.rearm_settings_watcher();
+            }
+            return;
+        }
+        if let Some(ref mut draft) = self.settings_draft {
+            match self.settings_selected_field {
+                SettingsField::Profile => unreachable!("handled above"),
+                SettingsField::FileFormat => unreachable!("handled above"),
+                SettingsField::ProfileAlias => {}
+                SettingsField::RefreshInterval => draft.cycle_refresh_interval(forward),
+                SettingsField::ShowLogsPanel => draft.toggle_logs_panel(),
+                SettingsField::LogLevel => draft.cycle_log_level(forward),
+                SettingsField::AlertThreshold => draft.cycle_alert_threshold(forward),
+                SettingsField::SessionRenewalThreshold => draft.cycle_session_renewal_threshold(forward),
+                SettingsField::SoundEnabled => draft.toggle_sound(),
+                SettingsField::NotificationsEnabled => draft.toggle_notifications(),
+                SettingsField::StopOnExit => draft.toggle_stop_on_exit(),
+                SettingsField::FileLogging => draft.toggle_file_logging(),
+                SettingsField::StderrLogging => draft.toggle_stderr_logging(),
+                SettingsField::Theme => draft.cycle_theme_palette(forward),
+                SettingsField::TestSound => {}
+            }
+        }
+    }
+
+    /// Fine-grained +/-step nudge of the selected field's numeric value (Shift+Left/Right)
+    pub fn nudge_current_setting(&mut self, steps: i32) {
+        if let Some(ref mut draft) = self.settings_draft {
+            match self.settings_selected_field {
+                SettingsField::RefreshInterval => draft.nudge_refresh_interval(steps as i64),
+                SettingsField::AlertThreshold => draft.nudge_alert_threshold(steps as i64),
+                SettingsField::SessionRenewalThreshold => draft.nudge_session_renewal_threshold(steps as i64),
+                _ => {}
+            }
+        }
+    }
+
+    /// Begin direct numeric entry for the selected field, seeding the input buffer with
+    /// its current formatted value (e.g. "60s") so editing feels like tweaking it rather
+    /// than starting from a blank field
+    pub fn start_settings_value_edit(&mut self) {
+        let Some(draft) = self.settings_draft.as_ref() else { return };
+        let current = match self.settings_selected_field {
+            SettingsField::RefreshInterval => draft.format_refresh_interval(),
+            SettingsField::AlertThreshold => draft.format_alert_threshold(),
+            SettingsField::SessionRenewalThreshold => draft.format_session_renewal_threshold(),
+            SettingsField::ProfileAlias => {
+                let Some(profile) = self.active_profile_name.as_deref() else { return };
+                draft.profile_aliases.get(profile).cloned().unwrap_or_default()
+            }
+            _ => return,
+        };
+        self.settings_value_edit = Some(current);
+        self.settings_value_edit_error = None;
+    }
+
+    /// Parse the in-progress edit buffer and apply it to the draft, staying in edit mode
+    /// with an error message if it doesn't parse or is out of bounds
+    pub fn commit_settings_value_edit(&mut self) {
+        let Some(input) = self.settings_value_edit.clone() else { return };
+
+        if self.settings_selected_field == SettingsField::ProfileAlias {
+            if let Some(profile) = self.active_profile_name.clone() {
+                if let Some(draft) = self.settings_draft.as_mut() {
+                    let alias = input.trim();
+                    if alias.is_empty() || alias == profile {
+                        draft.profile_aliases.remove(&profile);
+                    } else {
+                        draft.profile_aliases.insert(profile, alias.to_string());
+                    }
+                }
+            }
+            self.settings_value_edit = None;
+            self.settings_value_edit_error = None;
+            return;
+        }
+
+        let Some(secs) = crate::settings::parse_duration_secs(&input) else {
+            self.settings_value_edit_error = Some("Enter a duration like 10s, 90s, 5m, or 6h".to_string());
+            return;
+        };
+
+        let result = match self.settings_selected_field {
+            SettingsField::RefreshInterval => self.settings_draft.as_mut().map(|d| d.set_refresh_interval_secs(secs)),
+            SettingsField::AlertThreshold => self.settings_draft.as_mut().map(|d| d.set_alert_threshold_secs(secs)),
+            SettingsField::SessionRenewalThreshold => self.settings_draft.as_mut().map(|d| d.set_session_renewal_threshold_secs(secs)),
+            _ => None,
+        };
+
+        match result {
+            Some(Ok(())) => {
+                self.settings_value_edit = None;
+                self.settings_value_edit_error = None;
+            }
+            Some(Err(e)) => self.settings_value_edit_error = Some(e),
+            None => self.settings_value_edit = None,
+        }
+    }
+
+    /// Name of the currently active settings profile, for display in the settings dialog
+    pub fn active_settings_profile_name(&self) -> &str {
+        &self.settings_profiles.current_profile
+    }
+
+    pub fn navigate_settings_field(&mut self, up: bool) {
+        self.settings_selected_field = if up {
+            self.settings_selected_field.prev()
+        } else {
+            self.settings_selected_field.next()
+        };
+    }
+
+    /// Trigger a test alert
+    pub fn trigger_test_alert(&mut self) {
+        play_alert_sound();
+        self.add_toast("🔔 Test Alert: System Sound Working".to_string(), ToastType::Info);
+        self.log_manager.info("Triggered test alert sound".to_string());
+    }
+
+    // --- Lambda Methods ---
+
+    /// Invoke a Lambda function with a JSON payload under the dialog's selected
+    /// invocation type, and fetch its recent logs
+    pub async fn invoke_selected_lambda(&mut self, function_name: &str, payload: &str) -> Result<()> {
+        let invocation_type = self.lambda_invoke_type;
+        self.status_message = format!("Invoking {} ({})...", function_name, invocation_type.label());
+        self.is_loading = true;
+
+        match self.aws_client.invoke_lambda(function_name, payload, invocation_type).await {
+            Ok(result) => {
+                let mut summary = format!("Status: {}\n", result.status_code);
+                if let Some(err) = &result.function_error {
+                    summary.push_str(&format!("Function Error: {}\n", err));
+                }
+                summary.push_str(&format!("Payload: {}", result.payload));
+                if let Some(tail) = &result.log_tail {
+                    summary.push_str(&format!("\n\nLog tail:\n{}", tail));
+                }
+                self.lambda_last_response = Some(summary);
+
+                if result.function_error.is_some() {
+                    self.status_message = format!("{} faulted", function_name);
+                    self.add_toast(format!("⚠ {} returned a function error", function_name), ToastType::Error);
+                    self.log_manager.warning(format!("Lambda function faulted: {}", function_name));
+                } else {
+                    self.status_message = format!("Invoked {}", function_name);
+                    self.add_toast(format!("✓ Invoked: {}", function_name), ToastType::Success);
+                    self.log_manager.success(format!("Invoked Lambda function: {}", function_name));
+                }
+            }
+            Err(e) => {
+                self.lambda_last_response = Some(format!("Error: {}", e));
+                self.add_toast(format!("✗ Invoke failed: {}", function_name), ToastType::Error);
+                self.log_manager.error(format!("Failed to invoke {}: {}", function_name, e));
+            }
+        }
+
+        match self.aws_client.get_lambda_logs(function_name, 20).await {
+            Ok(logs) => self.lambda_recent_logs = logs,
+            Err(e) => self.log_manager.warning(format!("Failed to fetch logs for {}: {}", function_name, e)),
+        }
+
+        self.is_loading = false;
+        Ok(())
+    }
+
+    // --- Live Search Methods ---
+
+    /// Indices into `ec2_instances` matching the live search query by fuzzy subsequence
+    /// against name or instance ID; every index when the query is empty
+    pub fn ec2_search_matches(&self) -> Vec<usize> {
+        if self.search_query.is_empty() {
+            return (0..self.ec2_instances.len()).collect();
+        }
+        self.ec2_instances
+            .iter()
+            .enumerate()
+            .filter(|(_, instance)| {
+                crate::fuzzy::fuzzy_match(&self.search_query, &instance.name).is_some()
+                    || crate::fuzzy::fuzzy_match(&self.search_query, &instance.id).is_some()
+            })
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
+    /// Indices into `lambda_functions` matching the live search query by fuzzy
+    /// subsequence against the function name; every index when the query is empty
+    pub fn lambda_search_matches(&self) -> Vec<usize> {
+        if self.search_query.is_empty() {
+            return (0..self.lambda_functions.len()).collect();
+        }
+        self.lambda_functions
+            .iter()
+            .enumerate()
+            .filter(|(_, func)| crate::fuzzy::fuzzy_match(&self.search_query, &func.name).is_some())
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
+    // --- Log Filtering / Export ---
+
+    /// Cycle the minimum level shown on the Logs screen. Session-only, like the live
+    /// search query, rather than a persisted settings change - the Settings dialog's
+    /// `SettingsField::LogLevel` remains the place to change the default permanently.
+    pub fn cycle_log_min_level(&mut self) {
+        self.settings.cycle_log_level(true);
+        self.status_message = format!("Showing {} and above", self.settings.format_log_level());
+    }
+
+    /// Flip the process-wide `tracing` filter between `telemetry::DEFAULT_FILTER_DIRECTIVE`
+    /// and `telemetry::VERBOSE_FILTER_DIRECTIVE`, so an operator can turn on debug-level
+    /// aws-sdk/hyper spans to diagnose a failing call and turn them back off, without
+    /// restarting. Distinct from `cycle_log_min_level`, which only changes what's
+    /// *displayed* on the Logs screen - this changes what `tracing` captures in the first
+    /// place, for both the rolling file and the in-app log panel.
+    pub fn toggle_verbose_tracing(&mut self) {
+        self.verbose_tracing = !self.verbose_tracing;
+        let directive = if self.verbose_tracing {
+            crate::telemetry::VERBOSE_FILTER_DIRECTIVE
+        } else {
+            crate::telemetry::DEFAULT_FILTER_DIRECTIVE
+        };
+
+        match self.tracing_filter_handle.reload(tracing_subscriber::EnvFilter::new(directive)) {
+            Ok(()) => {
+                self.status_message = format!("Verbose AWS tracing {}", if self.verbose_tracing { "enabled" } else { "disabled" });
+                self.log_manager.info(format!("Tracing filter set to \"{}\"", directive));
+            }
+            Err(e) => {
+                self.verbose_tracing = !self.verbose_tracing;
+                self.log_manager.error(format!("Failed to reload tracing filter: {}", e));
+            }
+        }
+    }
+
+    /// Write the log entries currently visible on the Logs screen (level filter plus
+    /// any active live search) to a timestamped JSONL file under the config dir, and
+    /// report the outcome via `status_message` for operators auditing account activity
+    pub fn export_logs(&mut self) {
+        let filtered = self.log_manager.filtered_entries(self.settings.log_level, &self.search_query);
+
+        let config_dir = match crate::settings::Settings::get_config_dir() {
+            Ok(dir) => dir,
+            Err(e) => {
+                self.status_message = format!("Failed to export logs: {}", e);
+                return;
+            }
+        };
+
+        let path = config_dir.join(format!("logs-export-{}.jsonl", Utc::now().format("%Y%m%dT%H%M%SZ")));
+
+        let mut contents = String::new();
+        for entry in &filtered {
+            if let Ok(line) = serde_json::to_string(entry) {
+                contents.push_str(&line);
+                contents.push('\n');
+            }
+        }
+
+        match std::fs::write(&path, contents) {
+            Ok(()) => {
+                self.status_message = format!("Exported {} log entries to {}", filtered.len(), path.display());
+                self.log_manager.success(self.status_message.clone());
+            }
+            Err(e) => {
+                self.status_message = format!("Failed to export logs: {}", e);
+                self.log_manager.error(self.status_message.clone());
+            }
+        }
+    }
+
+    // --- Command Palette Methods ---
+
+    /// Open the command palette with an empty query
+    pub fn open_command_palette(&mut self) {
+        self.command_palette_query.clear();
+        self.command_palette_selected = 0;
+        self.dialog = Dialog::CommandPalette;
+        self.dialog_scroll_offset = 0;
+    }
+
+    /// Get palette commands matching the current query, best match first
+    pub fn filtered_palette_commands(&self) -> Vec<(&'static PaletteCommand, crate::fuzzy::FuzzyMatch)> {
+        let labels: Vec<&str> = PALETTE_COMMANDS.iter().map(|c| c.label).collect();
+        crate::fuzzy::fuzzy_filter(&self.command_palette_query, &labels)
+            .into_iter()
+            .map(|(idx, m)| (&PALETTE_COMMANDS[idx], m))
+            .collect()
+    }
+
+    /// Run the currently-highlighted palette command, if any, and close the palette
+    pub async fn execute_selected_palette_command(&mut self) -> Result<()> {
+        let matches = self.filtered_palette_commands();
+        let Some((command, _)) = matches.get(self.command_palette_selected) else {
+            self.dialog = Dialog::None;
+            return Ok(());
+        };
+        let action = command.action;
+        self.dialog = Dialog::None;
+
+        match action {
+            PaletteAction::StartInstance => self.start_selected_instance().await?,
+            PaletteAction::StopInstance => self.stop_selected_instance().await?,
+            PaletteAction::TerminateInstance => self.confirm_terminate_instance()?,
+            PaletteAction::ScheduleAutoStop => self.open_schedule_dialog()?,
+            PaletteAction::Refresh => self.refresh_data().await?,
+            PaletteAction::SwitchToHome => self.current_screen = Screen::Home,
+            PaletteAction::SwitchToEc2 => self.current_screen = Screen::Ec2,
+            PaletteAction::SwitchToLambda => self.current_screen = Screen::Lambda,
+            PaletteAction::SwitchToAbout => self.current_screen = Screen::About,
+            PaletteAction::OpenSettings => self.open_settings_dialog(),
+            PaletteAction::ShowHelp => self.dialog = Dialog::Help,
+            PaletteAction::ConfigureAws => self.dialog = Dialog::ConfigureAws,
+            PaletteAction::Quit => self.should_quit = true,
+        }
+
+        Ok(())
+    }
+
+    // --- Ops Assistant Methods ---
+
+    /// Open the assistant panel with an empty prompt
+    pub fn open_assistant_dialog(&mut self) {
+        self.assistant_prompt.clear();
+        self.assistant_busy = false;
+        self.assistant_proposed = None;
+        self.assistant_selected = 0;
+        self.assistant_error = None;
+        self.dialog = Dialog::Assistant;
+        self.dialog_scroll_offset = 0;
+    }
+
+    /// Send the current prompt plus a snapshot of the EC2/Lambda state to the
+    /// configured LLM backend on a background task, delivered back via `AsyncNotification`
+    pub fn submit_assistant_prompt(&mut self) {
+        self.assistant_busy = true;
+        self.assistant_error = None;
+
+        let prompt = self.assistant_prompt.clone();
+        let context = ResourceContext::capture(&self.ec2_instances, &self.lambda_functions);
+        let kind = self.settings.assistant_provider;
+        let endpoint = self.settings.assistant_endpoint.clone();
+        let model = self.settings.assistant_model.clone();
+        let api_key_env = self.settings.assistant_api_key_env.clone()
+            .unwrap_or_else(|| kind.default_api_key_env().to_string());
+        let api_key = if api_key_env.is_empty() { None } else { std::env::var(&api_key_env).ok() };
+
+        self.log_manager.info(format!("Sent assistant prompt to {}", kind.label()));
+
+        let tx = self.async_tx.clone();
+        tokio::spawn(async move {
+            let client = AssistantClient::new(kind, endpoint, model, api_key);
+            match client.propose_actions(&prompt, &context).await {
+                Ok(actions) => {
+                    let _ = tx.send(AsyncNotification::AssistantProposed(actions));
+                }
+                Err(e) => {
+                    let _ = tx.send(AsyncNotification::AssistantFailed(e));
+                }
+            }
+        });
+    }
+
+    /// Run the currently-highlighted proposed action. Terminations are routed through
+    /// the existing `Dialog::ConfirmTerminate` rather than executed directly.
+    pub async fn execute_selected_assistant_action(&mut self) -> Result<()> {
+        let Some(proposed) = self.assistant_proposed.clone() else {
+            return Ok(());
+        };
+        let Some(action) = proposed.get(self.assistant_selected).cloned() else {
+            self.dialog = Dialog::None;
+            return Ok(());
+        };
+
+        match action {
+            ProposedAction::Start { instance_id } => {
+                self.dialog = Dialog::None;
+                self.start_instance(&instance_id).await?;
+            }
+            ProposedAction::Stop { instance_id } => {
+                self.dialog = Dialog::None;
+                self.stop_instance(&instance_id).await?;
+            }
+            ProposedAction::Terminate { instance_id } => {
+                self.dialog = Dialog::ConfirmTerminate(instance_id);
+                self.dialog_scroll_offset = 0;
+            }
+            ProposedAction::ScheduleAutoStop { instance_id, minutes } => {
+                self.dialog = Dialog::None;
+                let duration = Duration::from_secs(minutes.max(0) as u64 * 60);
+                self.schedule_auto_stop(&instance_id, duration, ScheduleAction::Stop)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // --- SSH Methods ---
+
+    /// Open the SSH key picker for the selected EC2 instance, or raise an alert if it
+    /// has no public IP to connect to
+    pub fn open_ssh_dialog(&mut self) -> Result<()> {
+        let Some(instance) = self.ec2_instances.get(self.ec2_selected) else {
+            return Ok(());
+        };
+
+        if instance.public_ip.is_none() {
+            self.dialog = Dialog::warning(format!("Instance {} has no public IP to connect to", instance.id));
+            self.dialog_scroll_offset = 0;
+            return Ok(());
+        }
+
+        self.ssh_target_instance_id = Some(instance.id.clone());
+        self.ssh_selected = 0;
+        self.ssh_unlocking = false;
+        self.ssh_passphrase_input.clear();
+        self.dialog = Dialog::Ssh;
+        self.dialog_scroll_offset = 0;
+        Ok(())
+    }
+
+    /// Unlock the selected key with the entered passphrase and launch a terminal SSH
+    /// session to the target instance, lazily spawning the in-process agent if needed.
+    /// Any failure is surfaced through the existing alert dialog rather than a crash.
+    pub async fn unlock_and_connect_ssh(&mut self) -> Result<()> {
+        let Some(instance_id) = self.ssh_target_instance_id.clone() else {
+            self.dialog = Dialog::None;
+            return Ok(());
+        };
+        let Some(info) = self.ssh_key_store.keys.get(self.ssh_selected).cloned() else {
+            self.dialog = Dialog::None;
+            return Ok(());
+        };
+        let Some(instance) = self.ec2_instances.iter().find(|i| i.id == instance_id).cloned() else {
+            self.dialog = Dialog::None;
+            return Ok(());
+        };
+        let passphrase = self.ssh_passphrase_input.clone();
+
+        if self.ssh_agent.is_none() {
+            match crate::ssh::SshAgentHandle::spawn() {
+                Ok(agent) => self.ssh_agent = Some(agent),
+                Err(e) => {
+                    self.dialog = Dialog::error("Failed to start SSH agent", format!("{:#}", e));
+                    self.dialog_scroll_offset = 0;
+                    self.alert_expanded = false;
+                    return Ok(());
+                }
+            }
+        }
+        let agent = self.ssh_agent.as_ref().expect("ssh_agent set above");
+
+        if let Err(e) = agent.unlock_key(&info, &passphrase) {
+            self.dialog = Dialog::error(format!("Failed to unlock SSH key '{}'", info.label), format!("{:#}", e));
+            self.dialog_scroll_offset = 0;
+            self.alert_expanded = false;
+            return Ok(());
+        }
+
+        let user = instance.ssh_user(&self.settings.ssh_default_user).to_string();
+        if let Err(e) = crate::ssh::launch_terminal_ssh(&instance, &agent.socket_path, &user) {
+            self.dialog = Dialog::error("Failed to launch SSH session", format!("{:#}", e));
+            self.dialog_scroll_offset = 0;
+            self.alert_expanded = false;
+            return Ok(());
+        }
+
+        self.add_toast(format!("🔐 Connecting to {}", instance.name), ToastType::Success);
+        self.log_manager.success(format!("Launched SSH session to {} via key '{}'", instance.id, info.label));
+        self.ssh_passphrase_input.clear();
+        self.ssh_unlocking = false;
+        self.dialog = Dialog::None;
+        Ok(())
+    }
+
+    /// Open the "Add SSH key" form with a blank draft
+    pub fn open_ssh_add_key_dialog(&mut self) {
+        self.ssh_add_label_input.clear();
+        self.ssh_add_path_input.clear();
+        self.ssh_add_field = crate::app::state::SshAddField::Label;
+        self.dialog = Dialog::SshAddKey;
+        self.dialog_scroll_offset = 0;
+    }
+
+    /// Persist the drafted SSH key and return to the key picker. A blank label or path
+    /// is ignored rather than saved.
+    pub fn add_ssh_key(&mut self) -> Result<()> {
+        let label = self.ssh_add_label_input.trim().to_string();
+        let path = self.ssh_add_path_input.trim().to_string();
+        if label.is_empty() || path.is_empty() {
+            return Ok(());
+        }
+
+        self.ssh_key_store.add_key(crate::ssh::SshKeyInfo {
+            label: label.clone(),
+            path: std::path::PathBuf::from(path.clone()),
+            key_type: self.ssh_add_key_type,
+        });
+
+        if let Err(e) = self.ssh_key_store.save() {
+            self.add_toast(format!("Failed to save SSH key: {}", e), ToastType::Error);
+            self.log_manager.error(format!("Failed to save SSH key store: {}", e));
+        } else {
+            self.add_toast(format!("🔑 Added SSH key: {}", label), ToastType::Success);
+            self.log_manager.success(format!("Added SSH key '{}' ({})", label, path));
+        }
+
+        self.ssh_selected = self.ssh_key_store.keys.len().saturating_sub(1);
+        self.dialog = Dialog::Ssh;
+        self.dialog_scroll_offset = 0;
+        Ok(())
+    }
+
+    /// Connect to the currently selected running EC2 instance in one keystroke. Prefers
+    /// SSM Session Manager (no inbound SSH needed), falling back to a direct SSH session
+    /// via the instance's public IP and the configured key path if the
+    /// session-manager-plugin isn't installed. Mirrors `login_with_sso`'s pattern of
+    /// doing the work on a background thread and reporting back through an
+    /// `AsyncNotification`.
+    pub async fn connect_to_selected_instance(&mut self) -> Result<()> {
+        let Some(instance) = self.ec2_instances.get(self.ec2_selected).cloned() else {
+            return Ok(());
+        };
+        if instance.state != "running" {
+            self.dialog = Dialog::warning(format!("Instance {} is not running", instance.id));
+            self.dialog_scroll_offset = 0;
+            return Ok(());
+        }
+
+        self.status_message = format!("Connecting to {}...", instance.id);
+        self.add_toast(format!("🔌 Connecting to {}...", instance.name), ToastType::Info);
+
+        let tx = self.async_tx.clone();
+        let instance_id = instance.id.clone();
+        let public_ip = instance.public_ip.clone();
+        let key_path = self.settings.ssh_key_path.clone();
+        let user = instance.ssh_user(&self.settings.ssh_default_user).to_string();
+
+        std::thread::spawn(move || {
+            let ssm_available = std::process::Command::new("session-manager-plugin")
+                .arg("--version")
+                .output()
+                .is_ok();
+
+            let (method, result) = if ssm_available {
+                let cmd = crate::ssh::TerminalCommand::new("aws").arg("ssm").arg("start-session").arg("--target").arg(instance_id.clone());
+                ("SSM", crate::ssh::launch_terminal_command(&cmd))
+            } else if let Some(host) = public_ip {
+                let mut cmd = crate::ssh::TerminalCommand::new("ssh");
+                if let Some(key_path) = key_path {
+                    cmd = cmd.arg("-i").arg(key_path);
+                }
+                cmd = cmd.arg(format!("{}@{}", user, host));
+                ("SSH", crate::ssh::launch_terminal_command(&cmd))
+            } else {
+                ("SSH", Err(anyhow::anyhow!(
+                    "session-manager-plugin not found and instance {} has no public IP for an SSH fallback",
+                    instance_id
+                )))
+            };
+
+            match result {
+                Ok(()) => {
+                    let _ = tx.send(AsyncNotification::ConnectSucceeded(method.to_string(), instance_id));
+                }
+                Err(e) => {
+                    let _ = tx.send(AsyncNotification::ConnectFailed(e.to_string()));
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    /// Graceful-shutdown path, run once before the process actually exits (either the
+    /// user pressing the quit key, or the background SIGINT/SIGTERM/ctrl-c listener
+    /// flipping `shutdown_requested`). If `settings.stop_on_exit` is set, any auto-stop
+    /// rule whose time has already elapsed is honored immediately rather than left for
+    /// a launch that might not happen soon; either way, the remaining schedules are
+    /// persisted so quitting doesn't silently cancel them.
+    pub async fn shutdown(&mut self) -> Result<()> {
+        if self.settings.stop_on_exit {
+            if let Err(e) = self.check_scheduled_actions().await {
+                self.log_manager.error(format!("Failed to honor due schedule rules on shutdown: {}", e));
+            }
+            // A due Stop rule only enters `Draining` above and no later tick will finish
+            // the grace period, so stop every draining instance now.
+            let draining: Vec<String> = self.instance_lifecycle.iter()
+                .filter(|(_, l)| l.state == InstanceLifecycleState::Draining)
+                .map(|(id, _)| id.clone())
+                .collect();
+            for instance_id in draining {
+                match self.aws_client.stop_instance(&instance_id).await {
+                    Ok(_) => self.log_manager.success(format!("Stopped {} on shutdown", instance_id)),
+                    Err(e) => self.log_manager.error(format!("Failed to stop {} on shutdown: {}", instance_id, e)),
+                }
+            }
+        }
+
+        if let Err(e) = crate::app::state::save_auto_stop_schedules(&self.auto_stop_schedules) {
+            self.log_manager.error(format!("Failed to persist auto-stop schedules on shutdown: {}", e));
+        } else {
+            self.log_manager.info(format!("Persisted {} auto-stop schedule(s) on shutdown", self.auto_stop_schedules.len()));
+        }
+
+        Ok(())
+    }
+
+    // --- Auth & Profile Methods ---
+
+    /// Mint a new monotonically increasing request id and publish it as "the latest" in
+    /// `request_seq`, so any in-flight SSO-login/profile-activation task that captured
+    /// an older id can tell - once its work finishes - that it's been superseded, and
+    /// report `SsoFailureReason::Outdated` instead of applying a stale result.
+    fn next_request_id(&self) -> u64 {
+        self.request_seq.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1
+    }
+
+    /// Abort whichever profile-activation/data-refresh task is still in flight (if any),
+    /// then spawn `future` as its replacement, wrapped so the *next* call to this method
+    /// can abort it in turn. Unlike the `request_seq` check above - which only lets a
+    /// stale task notice after the fact that it's been superseded - this actually stops
+    /// the old task's future from being polled again, so a rapid profile or region
+    /// change doesn't leave a now-pointless network call running in the background.
+    fn spawn_profile_task<F>(&mut self, future: F)
+    where
+        F: std::future::Future<Output = ()> + Send + 'static,
+    {
+        Self::spawn_abortable(&mut self.active_profile_task, future);
+    }
+
+    /// Abort whichever task currently occupies `slot` (if any), then spawn `future` as
+    /// its replacement, wrapped so the *next* call with the same slot can abort it in
+    /// turn. Used for `active_profile_task`/`active_lambda_task`/`active_asg_task` so
+    /// each resource's own superseded refresh is cancelled independently - refreshing
+    /// Lambda again before the last call returns shouldn't leave the first one running,
+    /// but it also shouldn't abort an unrelated in-flight EC2 refresh.
+    fn spawn_abortable<F>(slot: &mut Option<futures::future::AbortHandle>, future: F)
+    where
+        F: std::future::Future<Output = ()> + Send + 'static,
+    {
+        if let Some(handle) = slot.take() {
+            handle.abort();
+        }
+        let (abort_handle, abort_registration) = futures::future::AbortHandle::new_pair();
+        *slot = Some(abort_handle);
+        tokio::spawn(futures::future::Abortable::new(future, abort_registration));
+    }
+
+    pub async fn login_with_sso(&mut self) -> Result<()> {
+        self.status_message = "Initiating AWS SSO Login...".to_string();
+        self.add_toast("🔑 Starting AWS SSO login... check browser".to_string(), ToastType::Info);
+
+        let request_id = self.next_request_id();
+        let request_seq = self.request_seq.clone();
+        let shutdown_requested = self.shutdown_requested.clone();
+        let tx = self.async_tx.clone();
+        let profile = if !self.available_profiles.is_empty() {
+             Some(self.available_profiles[self.selected_profile_index].name.clone())
+        } else {
+             None
+        };
+
+        tokio::spawn(async move {
+            let login_profile = profile.clone();
+            let login = tokio::task::spawn_blocking(move || crate::aws::run_sso_login(login_profile.as_deref()));
+
+            let result = match tokio::time::timeout(SSO_LOGIN_TIMEOUT, login).await {
+                Ok(Ok(result)) => result,
+                Ok(Err(_)) => {
+                    let _ = tx.send(AsyncNotification::SsoLoginFailed(SsoFailureReason::Error("SSO login task panicked".to_string())));
+                    return;
+                }
+                Err(_) => {
+                    let _ = tx.send(AsyncNotification::SsoLoginFailed(SsoFailureReason::Timeout));
+                    return;
+                }
+            };
+
+            // A shutdown or a newer login/activation request both make this result
+            // moot - report it as such rather than applying it.
+            if shutdown_requested.load(std::sync::atomic::Ordering::SeqCst) {
+                let _ = tx.send(AsyncNotification::SsoLoginFailed(SsoFailureReason::Cancelled));
+                return;
+            }
+            if request_seq.load(std::sync::atomic::Ordering::SeqCst) != request_id {
+                let _ = tx.send(AsyncNotification::SsoLoginFailed(SsoFailureReason::Outdated));
+                return;
+            }
+
+            match result {
+                Ok(()) => {
+                    let profile_name = profile.unwrap_or_else(|| "default".to_string());
+                    let _ = tx.send(AsyncNotification::SsoLoginSuccess("Login successful".to_string(), profile_name));
+                }
+                Err(e) => {
+                     let _ = tx.send(AsyncNotification::SsoLoginFailed(SsoFailureReason::Error(e.to_string())));
+                }
+            }
+        });
+        self.log_manager.info(format!("Spawned 'aws sso login' task (request #{})", request_id));
+        Ok(())
+    }
+
+    /// Copy the live, flat EC2/Lambda/ASG fields into `workspaces[active_workspace]`, so
+    /// they aren't lost the moment `switch_workspace` overwrites them with a different
+    /// workspace's snapshot.
+    fn snapshot_active_workspace(&mut self) {
+        let ws = &mut self.workspaces[self.active_workspace];
+        ws.profile_name = self.active_profile_name.clone().unwrap_or_else(|| ws.profile_name.clone());
+        ws.ec2_instances = self.ec2_instances.clone();
+        ws.ec2_selected = self.ec2_selected;
+        ws.ec2_metrics = self.ec2_metrics.clone();
+        ws.lambda_functions = self.lambda_functions.clone();
+        ws.lambda_selected = self.lambda_selected;
+        ws.asg_groups = self.asg_groups.clone();
+        ws.asg_selected = self.asg_selected;
+        ws.asg_instance_selected = self.asg_instance_selected;
+        ws.scroll_offset = self.scroll_offset;
+        ws.last_refresh = self.last_refresh;
+    }
+
+    /// Switch to a different saved workspace by index: snapshot whatever is currently
+    /// live into the outgoing workspace, then copy the incoming workspace's last-known
+    /// data back into the flat fields every screen already reads. The displayed data is
+    /// instant (whatever was cached from last time), while a credential rebuild + fresh
+    /// `refresh_data` for the new profile happens in the background via
+    /// `activate_profile`, exactly as a same-workspace profile switch already does.
+    pub async fn switch_workspace(&mut self, idx: usize) -> Result<()> {
+        if idx == self.active_workspace || idx >= self.workspaces.len() {
+            return Ok(());
+        }
+        self.snapshot_active_workspace();
+        self.active_workspace = idx;
+        let ws = self.workspaces[idx].clone();
+        self.ec2_instances = ws.ec2_instances;
+        self.ec2_selected = ws.ec2_selected;
+        self.ec2_metrics = ws.ec2_metrics;
+        self.lambda_functions = ws.lambda_functions;
+        self.lambda_selected = ws.lambda_selected;
+        self.asg_groups = ws.asg_groups;
+        self.asg_selected = ws.asg_selected;
+        self.asg_instance_selected = ws.asg_instance_selected;
+        self.scroll_offset = ws.scroll_offset;
+        self.last_refresh = ws.last_refresh;
+        self.log_manager.info(format!("Switched to workspace '{}'", self.settings.profile_display_name(&ws.profile_name)));
+        self.activate_profile(&ws.profile_name).await
+    }
+
+    /// Cycle to the next (`delta > 0`) or previous (`delta < 0`) open workspace,
+    /// wrapping around. A no-op with an informational toast if only one is open, since
+    /// there's nothing to cycle to yet - see `open_or_switch_profile` for how a second
+    /// workspace gets created.
+    pub async fn cycle_workspace(&mut self, delta: i32) -> Result<()> {
+        if self.workspaces.len() <= 1 {
+            self.add_toast("Only one workspace open - pick another profile to open a second".to_string(), ToastType::Info);
+            return Ok(());
+        }
+        let len = self.workspaces.len() as i32;
+        let new_idx = (self.active_workspace as i32 + delta).rem_euclid(len) as usize;
+        self.switch_workspace(new_idx).await
+    }
+
+    /// Activate a profile from the `ConfigureAws`/`SessionExpired` picker. If a workspace
+    /// for that profile is already open, this just switches to it (instant, cached data)
+    /// rather than discarding it and re-fetching; otherwise it opens a brand new
+    /// workspace for the profile. This is what lets an operator keep prod and staging
+    /// loaded side by side and flip between them without re-running `ConfigureAws` for
+    /// a profile they've already visited this session.
+    pub async fn open_or_switch_profile(&mut self, profile_name: &str) -> Result<()> {
+        if let Some(idx) = self.workspaces.iter().position(|w| w.profile_name == profile_name) {
+            return self.switch_workspace(idx).await;
+        }
+        self.snapshot_active_workspace();
+        let region = self
+            .available_profiles
+            .iter()
+            .find(|p| p.name == profile_name)
+            .and_then(|p| p.region.clone());
+        self.workspaces.push(Workspace::new(profile_name.to_string(), region));
+        self.active_workspace = self.workspaces.len() - 1;
+        self.ec2_instances = Vec::new();
+        self.ec2_selected = 0;
+        self.ec2_metrics = HashMap::new();
+        self.lambda_functions = Vec::new();
+        self.lambda_selected = 0;
+        self.asg_groups = Vec::new();
+        self.asg_selected = 0;
+        self.asg_instance_selected = 0;
+        self.scroll_offset = 0;
+        self.last_refresh = None;
+        self.activate_profile(profile_name).await
+    }
+
+    #[tracing::instrument(skip(self), fields(operation = "activate_profile", profile = %profile_name))]
+    pub async fn activate_profile(&mut self, profile_name: &str) -> Result<()> {
+        let display_name = self.settings.profile_display_name(profile_name).to_string();
+        self.status_message = format!("Switching to profile: {}...", display_name);
+        self.add_toast(format!("🔄 Switching to profile '{}'...", display_name), ToastType::Info);
+        self.is_loading = true;
+
+        self.log_manager.info(format!("Rebuilding AWS client for profile '{}'", profile_name));
+
+        let request_id = self.next_request_id();
+        let request_seq = self.request_seq.clone();
+        let tx = self.async_tx.clone();
+        // Prefer the active workspace's own region (set once when the workspace was
+        // opened, and not re-read from `~/.aws/config` after that - see `Workspace::region`),
+        // then the profile's own resolved region (from `~/.aws/config`), then the global
+        // override.
+        let region = self
+            .workspaces
+            .get(self.active_workspace)
+            .and_then(|ws| ws.region.clone())
+            .or_else(|| {
+                self.available_profiles
+                    .iter()
+                    .find(|p| p.name == profile_name)
+                    .and_then(|p| p.region.clone())
+            })
+            .or_else(|| self.config.aws_region.clone());
+        let profile_name_owned = profile_name.to_string();
+        // The sentinel "resolve from the standard chain" entry isn't a real `--profile`
+        // value - passing `None` here is what makes `AwsClient::new` fall through to
+        // env/web-identity/ECS/IMDS instead of a named profile.
+        let profile_override = if profile_name_owned == crate::aws::DEFAULT_CREDENTIAL_CHAIN_PROFILE {
+            None
+        } else {
+            Some(profile_name_owned.clone())
+        };
+
+        // The actual rebuild happens on a background task (see `check_async_notifications`
+        // for where the outcome is recorded), so its latency/outcome are captured there
+        // rather than on this span, which only covers kicking the task off. Spawned via
+        // `spawn_profile_task` so switching profiles again before this resolves aborts it
+        // outright, rather than letting it run to completion only to be discarded.
+        self.spawn_profile_task(async move {
+            let start = std::time::Instant::now();
+            let outcome = tokio::time::timeout(
+                PROFILE_ACTIVATION_TIMEOUT,
+                crate::aws::AwsClient::new(profile_override.as_deref(), region.as_deref()),
+            )
+            .await;
+
+            // A newer activation request started while this one was in flight - discard
+            // this result rather than swapping in a client for a profile the user may
+            // have already moved on from.
+            if request_seq.load(std::sync::atomic::Ordering::SeqCst) != request_id {
+                tracing::info!(operation = "activate_profile", profile = %profile_name_owned, outcome = "outdated", "Superseded by a newer profile activation");
+                let _ = tx.send(AsyncNotification::ProfileActivationFailed(SsoFailureReason::Outdated));
+                return;
+            }
+
+            match outcome {
+                Ok(Ok(client)) => {
+                    tracing::info!(operation = "activate_profile", profile = %profile_name_owned, outcome = "success", latency_ms = start.elapsed().as_millis() as u64, "AWS client rebuilt");
+                    let _ = tx.send(AsyncNotification::ProfileActivated(client, profile_name_owned));
+                },
+                Ok(Err(e)) => {
+                    tracing::warn!(operation = "activate_profile", profile = %profile_name_owned, outcome = "error", latency_ms = start.elapsed().as_millis() as u64, error = %e, "AWS client rebuild failed");
+                    let _ = tx.send(AsyncNotification::ProfileActivationFailed(SsoFailureReason::Error(e.to_string())));
+                }
+                Err(_) => {
+                    tracing::warn!(operation = "activate_profile", profile = %profile_name_owned, outcome = "timeout", latency_ms = start.elapsed().as_millis() as u64, "AWS client rebuild timed out");
+                    let _ = tx.send(AsyncNotification::ProfileActivationFailed(SsoFailureReason::Timeout));
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    pub async fn check_async_notifications(&mut self) -> Result<()> {
+        // Drain tracing events forwarded by `telemetry::LogBridgeLayer` (aws-sdk/hyper
+        // retries, throttling, etc.) straight into the log manager, same cadence as the
+        // notifications below.
+        while let Ok(entry) = self.tracing_rx.try_recv() {
+            self.log_manager.log(entry.level, entry.message);
+        }
+
         while let Ok(notification) = self.async_rx.try_recv() {
             match notification {
                 AsyncNotification::SsoLoginSuccess(msg, profile) => {
@@ -461,32 +2051,263 @@ impl App {
                          self.log_manager.error(format!("Failed to activate profile after login: {}", e));
                      }
                 }
-                AsyncNotification::SsoLoginFailed(err) => {
-                     if err.contains("Missing the following required SSO configuration") {
-                         self.add_toast("❌ SSO Config Missing. Run 'aws configure sso'".to_string(), ToastType::Error);
-                         self.log_manager.error(format!("SSO Config Error: {}", err));
-                         self.status_message = "SSO Configuration Missing!".to_string();
-                     } else {
-                         self.add_toast(format!("❌ Login Failed: {}", err), ToastType::Error);
-                         self.log_manager.error(format!("Login failed: {}", err));
+                AsyncNotification::SsoLoginFailed(reason) => match reason {
+                     SsoFailureReason::Error(err) => {
+                         if err.contains("Missing the following required SSO configuration") {
+                             self.add_toast("❌ SSO Config Missing. Run 'aws configure sso'".to_string(), ToastType::Error);
+                             self.log_manager.error(format!("SSO Config Error: {}", err));
+                             self.status_message = "SSO Configuration Missing!".to_string();
+                         } else {
+                             self.add_toast(format!("❌ Login Failed: {}", err), ToastType::Error);
+                             self.log_manager.error(format!("Login failed: {}", err));
+                         }
+                     }
+                     SsoFailureReason::Timeout => {
+                         self.add_toast("⏱️ SSO login timed out - check your browser and retry".to_string(), ToastType::Error);
+                         self.log_manager.warning("SSO login timed out".to_string());
+                     }
+                     SsoFailureReason::Outdated => {
+                         self.add_toast("↩️ Discarded an outdated SSO login result".to_string(), ToastType::Info);
+                         self.log_manager.info("Discarded outdated SSO login result (superseded by a newer request)".to_string());
+                     }
+                     SsoFailureReason::Cancelled => {
+                         self.add_toast("🚫 SSO login cancelled".to_string(), ToastType::Info);
+                         self.log_manager.info("SSO login cancelled".to_string());
                      }
                 }
                 AsyncNotification::ProfileActivated(client, profile_name) => {
                     self.aws_client = client;
                     self.aws_configured = true;
                     self.active_profile_name = Some(profile_name.clone());
+                    self.credential_expiration = crate::aws::get_credential_expiration(&profile_name);
+                    self.credential_expiry_alerted = false;
                     self.is_loading = false;
                     self.dialog = Dialog::None;
-                    self.add_toast(format!("✅ Active Profile: {}", profile_name), ToastType::Success);
+                    let display_name = self.settings.profile_display_name(&profile_name).to_string();
+                    self.add_toast(format!("✅ Active Profile: {}", display_name), ToastType::Success);
                     if let Err(e) = self.refresh_data().await {
                         self.log_manager.error(format!("Failed to refresh data after profile switch: {}", e));
                     }
                 }
-                AsyncNotification::ProfileActivationFailed(err) => {
+                AsyncNotification::ProfileActivationFailed(reason) => {
+                    self.is_loading = false;
+                    match reason {
+                        SsoFailureReason::Error(err) => {
+                            self.log_manager.error(format!("Failed to switch profile: {}", err));
+                            self.add_toast("Failed to switch profile".to_string(), ToastType::Error);
+                        }
+                        SsoFailureReason::Timeout => {
+                            self.log_manager.warning("Profile activation timed out".to_string());
+                            self.add_toast("⏱️ Profile switch timed out - retry?".to_string(), ToastType::Error);
+                        }
+                        SsoFailureReason::Outdated => {
+                            self.log_manager.info("Discarded outdated profile activation result (superseded by a newer request)".to_string());
+                            self.add_toast("↩️ Discarded an outdated profile switch result".to_string(), ToastType::Info);
+                        }
+                        SsoFailureReason::Cancelled => {
+                            self.log_manager.info("Profile activation cancelled".to_string());
+                            self.add_toast("🚫 Profile switch cancelled".to_string(), ToastType::Info);
+                        }
+                    }
+                }
+                AsyncNotification::Ec2Refreshed(result) => {
+                    self.is_loading = false;
+                    match result {
+                        Ok(instances) => {
+                            let count = instances.len();
+                            self.ec2_instances = instances;
+                            // A reboot is done once the instance is observed running again, not
+                            // when the RebootInstances call returns - clear the sticky marker here.
+                            let running_ids: HashSet<String> = self.ec2_instances.iter()
+                                .filter(|i| i.state == "running")
+                                .map(|i| i.id.clone())
+                                .collect();
+                            self.rebooting_instances.retain(|id| !running_ids.contains(id));
+                            self.status_message = format!("Loaded {} EC2 instances", count);
+                            self.log_manager.success(format!("Refreshed EC2: {} instances loaded", count));
+                        }
+                        Err(error_str) => {
+                            self.status_message = format!("Error: {}", error_str);
+                            self.log_manager.error(format!("Failed to load EC2 instances: {}", error_str));
+                            if Self::is_session_expired_error(&error_str) {
+                                self.dialog = Dialog::SessionExpired;
+                                self.dialog_scroll_offset = 0;
+                                self.log_manager.warning("AWS session token expired - credentials need refresh".to_string());
+                            }
+                        }
+                    }
+                }
+                AsyncNotification::LambdaRefreshed(workspace_id, result) => {
+                    self.is_loading = false;
+                    // The user may have switched to a different workspace while this was
+                    // in flight (see `AsyncNotification::LambdaRefreshed`) - applying it now
+                    // would overwrite the newly-active workspace's functions with the
+                    // outgoing one's data.
+                    if workspace_id != self.active_workspace {
+                        self.log_manager.info("Discarding Lambda refresh for a workspace that's no longer active".to_string());
+                        continue;
+                    }
+                    match result {
+                        Ok(functions) => {
+                            let count = functions.len();
+                            self.lambda_functions = functions;
+                            self.status_message = format!("Loaded {} Lambda functions", count);
+                            self.log_manager.success(format!("Refreshed Lambda: {} functions loaded", count));
+                        }
+                        Err(error_str) => {
+                            self.status_message = format!("Error: {}", error_str);
+                            self.log_manager.error(format!("Failed to load Lambda functions: {}", error_str));
+                            if Self::is_session_expired_error(&error_str) {
+                                self.dialog = Dialog::SessionExpired;
+                                self.dialog_scroll_offset = 0;
+                                self.log_manager.warning("AWS session token expired - credentials need refresh".to_string());
+                            }
+                        }
+                    }
+                }
+                AsyncNotification::AsgRefreshed(workspace_id, result) => {
+                    self.is_loading = false;
+                    if workspace_id != self.active_workspace {
+                        self.log_manager.info("Discarding Auto Scaling refresh for a workspace that's no longer active".to_string());
+                        continue;
+                    }
+                    match result {
+                        Ok(groups) => {
+                            let count = groups.len();
+                            self.asg_groups = groups;
+                            self.status_message = format!("Loaded {} Auto Scaling Groups", count);
+                            self.log_manager.success(format!("Refreshed Auto Scaling Groups: {} loaded", count));
+                        }
+                        Err(error_str) => {
+                            self.status_message = format!("Error: {}", error_str);
+                            self.log_manager.error(format!("Failed to load Auto Scaling Groups: {}", error_str));
+                            if Self::is_session_expired_error(&error_str) {
+                                self.dialog = Dialog::SessionExpired;
+                                self.dialog_scroll_offset = 0;
+                                self.log_manager.warning("AWS session token expired - credentials need refresh".to_string());
+                            }
+                        }
+                    }
+                }
+                AsyncNotification::InstanceActionDone { id, action, result } => {
+                    let instance_name = self.ec2_instances.iter()
+                        .find(|i| i.id == id)
+                        .map(|i| i.name.clone())
+                        .unwrap_or_else(|| id.clone());
+                    match result {
+                        Ok(()) => {
+                            self.status_message = format!("{} {}", action.past_verb(), id);
+                            self.add_toast(format!("✓ {}: {}", action.past_verb(), instance_name), ToastType::Success);
+                            self.log_manager.success(format!("{} EC2 instance: {} ({})", action.past_verb(), instance_name, id));
+                            self.activate_boost_refresh();
+                            if let Err(e) = self.refresh_data().await {
+                                self.log_manager.error(format!("Failed to refresh data after {}: {}", action.past_verb().to_lowercase(), e));
+                            }
+                        }
+                        Err(e) => {
+                            if action == InstanceAction::Reboot {
+                                self.rebooting_instances.remove(&id);
+                            }
+                            self.status_message = format!("Failed to {}: {}", action.verb().to_lowercase(), e);
+                            self.add_toast(format!("✗ Failed to {}: {}", action.verb().to_lowercase(), instance_name), ToastType::Error);
+                            self.log_manager.error(format!("Failed to {} {}: {}", action.verb().to_lowercase(), instance_name, e));
+                        }
+                    }
+                }
+                AsyncNotification::InstanceLaunchDone { spot, result } => {
                     self.is_loading = false;
-                    self.log_manager.error(format!("Failed to switch profile: {}", err));
-                    self.add_toast("Failed to switch profile".to_string(), ToastType::Error);
+                    let kind = if spot { "spot" } else { "on-demand" };
+                    match result {
+                        Ok(ids) => {
+                            self.status_message = format!("Launched {} {} instance(s)", ids.len(), kind);
+                            self.add_toast(format!("✓ Launched {} {} instance(s)", ids.len(), kind), ToastType::Success);
+                            self.log_manager.success(format!("Launched {} EC2 instance(s): {}", kind, ids.join(", ")));
+                            self.activate_boost_refresh();
+                            if let Err(e) = self.refresh_data().await {
+                                self.log_manager.error(format!("Failed to refresh data after launch: {}", e));
+                            }
+                        }
+                        Err(e) => {
+                            self.status_message = format!("Failed to launch {} instance(s): {}", kind, e);
+                            self.add_toast(format!("✗ Failed to launch {} instance(s)", kind), ToastType::Error);
+                            self.log_manager.error(format!("Failed to launch {} EC2 instance(s): {}", kind, e));
+                        }
+                    }
+                }
+                AsyncNotification::IpcCommand(cmd) => {
+                    self.log_manager.info(format!("IPC: {:?}", cmd.event));
+                    if let Some(id) = &cmd.target_instance_id {
+                        match self.ec2_instances.iter().position(|i| &i.id == id) {
+                            Some(idx) => self.ec2_selected = idx,
+                            None => {
+                                self.log_manager.warning(format!("IPC command targeted unknown instance '{}'", id));
+                                continue;
+                            }
+                        }
+                    }
+                    if let Err(e) = self.handle_event(cmd.event).await {
+                        self.log_manager.error(format!("Failed to handle IPC command: {}", e));
+                    }
+                }
+                AsyncNotification::AssistantProposed(actions) => {
+                    self.assistant_busy = false;
+                    self.assistant_proposed = Some(actions);
+                    self.assistant_selected = 0;
+                    self.log_manager.info("Assistant proposed actions".to_string());
+                }
+                AsyncNotification::SettingsReloaded(settings) => {
+                    self.settings = settings;
+                    self.auto_refresh_interval = self.settings.refresh_interval();
+                    self.log_manager.configure_outputs(self.settings.log_outputs.clone());
+                    self.theme = theme::theme_styles(self.settings.theme_palette);
+                    theme::apply_accent_overrides(&mut self.theme, &self.keymap.resolve_theme_overrides());
+                    self.last_refresh = None; // Re-check against the new interval on the next tick
+                    self.log_manager.info("settings.json changed on disk, reloaded".to_string());
+                    self.add_toast("⚙️ Settings reloaded".to_string(), ToastType::Info);
+                }
+                AsyncNotification::SettingsReloadFailed(err) => {
+                    let alert_msg = format!("⚠️ settings.json changed but failed to parse, keeping previous settings: {}", err);
+                    if !self.pending_alerts.contains(&alert_msg) {
+                        self.pending_alerts.push(alert_msg);
+                    }
+                    self.log_manager.warning(format!("Failed to reload settings.json: {}", err));
+                }
+                AsyncNotification::AssistantFailed(err) => {
+                    self.assistant_busy = false;
+                    self.log_manager.error(format!("Assistant request failed: {}", err.summary));
+                    self.assistant_error = Some(err);
+                    self.dialog = Dialog::AssistantError;
+                    self.dialog_scroll_offset = 0;
+                    self.assistant_error_expanded = false;
                 }
+                AsyncNotification::ConnectSucceeded(method, instance_id) => {
+                    self.add_toast(format!("🔌 Connected to {} via {}", instance_id, method), ToastType::Success);
+                    self.log_manager.success(format!("Launched {} session to {}", method, instance_id));
+                }
+                AsyncNotification::ConnectFailed(err) => {
+                    self.add_toast("✗ Failed to connect to instance".to_string(), ToastType::Error);
+                    self.log_manager.error(format!("Failed to connect to instance: {}", err));
+                }
+                AsyncNotification::SessionRenewed(profile) => {
+                    self.session_renewal_in_flight = false;
+                    self.log_manager.success(format!("Proactively renewed AWS session for profile '{}'", profile));
+                    self.add_toast(format!("✓ Session renewed: {}", profile), ToastType::Success);
+                    if let Err(e) = self.activate_profile(&profile).await {
+                        self.log_manager.error(format!("Failed to rebuild AWS client after session renewal: {}", e));
+                    }
+                }
+                AsyncNotification::SessionRenewalFailed(err) => {
+                    self.session_renewal_in_flight = false;
+                    self.log_manager.warning(format!("Proactive session renewal failed, falling back to reactive expiry warning: {}", err));
+                    self.add_toast("⚠ Proactive session renewal failed".to_string(), ToastType::Error);
+                }
+                AsyncNotification::SlackAlertSent { instance_id, result } => match result {
+                    Ok(()) => self.log_manager.success(format!("Sent Slack alert for long-running instance {}", instance_id)),
+                    Err(e) => {
+                        self.log_manager.error(format!("Failed to send Slack alert for {}: {}", instance_id, e));
+                        self.add_toast(format!("⚠ Failed to send Slack alert for {}", instance_id), ToastType::Error);
+                    }
+                },
             }
         }
         Ok(())
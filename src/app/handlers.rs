@@ -1,6 +1,7 @@
 use std::time::Duration;
 use anyhow::Result;
-use crate::app::state::{App, Dialog, Screen};
+use crate::app::actions::AUTO_STOP_PRESETS;
+use crate::app::state::{App, AutoStopMode, Dialog, InputMode, LaunchInstanceField, Screen, SshAddField};
 use crate::event::AppEvent;
 use crate::settings::SettingsField;
 
@@ -15,57 +16,58 @@ impl App {
         match event {
             AppEvent::Quit => self.should_quit = true,
             
-            AppEvent::NavigateTab(idx) => {
-                let new_screen = match idx {
-                    0 => Screen::Home,
-                    1 => Screen::Ec2,
-                    2 => Screen::Lambda,
-                    3 => Screen::About,
-                    4 => Screen::Logs,
-                    _ => self.current_screen,
-                };
-                
-                // Skip Logs screen if disabled in settings
-                let new_screen = if new_screen == Screen::Logs && !self.settings.show_logs_panel {
-                    self.current_screen
-                } else {
-                    new_screen
-                };
-                
-                if new_screen != self.current_screen {
-                    self.current_screen = new_screen;
-                    self.scroll_offset = 0;
-                    self.log_manager.info(format!("Navigated to {:?} screen", new_screen));
-                }
-            }
-            
+            AppEvent::NavigateTab(idx) => self.navigate_to_tab(idx),
+
             AppEvent::Up => self.move_selection(-1),
             AppEvent::Down => self.move_selection(1),
-            
+            AppEvent::Scroll(delta) => self.move_selection(delta),
+            AppEvent::ClickAt(x, y) => self.handle_click(x, y),
+
             AppEvent::Refresh => self.refresh_data().await?,
             
             AppEvent::Start => self.start_selected_instance().await?,
             AppEvent::Stop => self.stop_selected_instance().await?,
             AppEvent::Terminate => self.confirm_terminate_instance()?,
             AppEvent::Schedule => self.open_schedule_dialog()?,
+            AppEvent::ScaleToZero => self.scale_selected_group_to_zero().await?,
+            AppEvent::DetachInstance => self.confirm_detach_selected_instance()?,
             AppEvent::ShowHelp => {
                 self.dialog = Dialog::Help;
                 self.dialog_scroll_offset = 0;
             }
             
-            AppEvent::Enter => self.handle_enter().await?,
-            
+            AppEvent::Enter => {
+                if self.input_mode == InputMode::Search {
+                    // Confirm the filter and leave search mode, same as broot's Enter
+                    self.input_mode = InputMode::Normal;
+                } else {
+                    self.handle_enter().await?;
+                }
+            }
+
             AppEvent::Resize(w, h) => {
                 self.window_size = (w, h);
             }
-            
+
             AppEvent::OpenSettings => {
                 self.open_settings_dialog();
             }
-            
-            // These are only used in settings dialog
-            AppEvent::ModifySettingValue(_) | AppEvent::CancelSettings => {}
-            
+
+            AppEvent::EnterSearch => {
+                self.input_mode = InputMode::Search;
+                self.search_query.clear();
+            }
+
+            // Only used in settings dialog, except `CancelSettings` doubling as "Esc" to
+            // clear and leave search mode below
+            AppEvent::ModifySettingValue(_) => {}
+            AppEvent::CancelSettings => {
+                if self.input_mode == InputMode::Search {
+                    self.input_mode = InputMode::Normal;
+                    self.search_query.clear();
+                }
+            }
+
             AppEvent::None => {},
             AppEvent::ConfigureAws => {
                 self.dialog = Dialog::ConfigureAws;
@@ -78,6 +80,69 @@ impl App {
                     self.dialog_scroll_offset = 0;
                 }
             }
+            AppEvent::OpenCommandPalette => {
+                self.open_command_palette();
+            }
+            AppEvent::OpenAssistant => {
+                self.open_assistant_dialog();
+            }
+            AppEvent::OpenSsh => self.open_ssh_dialog()?,
+            AppEvent::OpenLaunchInstance => {
+                if self.current_screen == Screen::Ec2 {
+                    self.open_launch_instance_dialog();
+                }
+            }
+            AppEvent::ConnectInstance => {
+                if self.current_screen == Screen::Ec2 {
+                    self.connect_to_selected_instance().await?;
+                }
+            }
+            AppEvent::Reboot => {
+                if self.current_screen == Screen::Ec2 {
+                    self.reboot_selected_instance().await?;
+                }
+            }
+            AppEvent::Invoke => {
+                if self.current_screen == Screen::Lambda {
+                    if let Some(func) = self.lambda_functions.get(self.lambda_selected) {
+                        self.lambda_invoke_payload.clear();
+                        self.lambda_invoke_type = crate::aws::LambdaInvocationType::RequestResponse;
+                        self.dialog = Dialog::InvokeLambda(func.name.clone());
+                        self.dialog_scroll_offset = 0;
+                    }
+                }
+            }
+            AppEvent::NextWorkspace => self.cycle_workspace(1).await?,
+            AppEvent::PrevWorkspace => self.cycle_workspace(-1).await?,
+            AppEvent::ExportLogs => {
+                if self.current_screen == Screen::Logs {
+                    self.export_logs();
+                }
+            }
+            AppEvent::CycleLogLevel => {
+                if self.current_screen == Screen::Logs {
+                    self.cycle_log_min_level();
+                }
+            }
+            AppEvent::DismissToast => self.dismiss_topmost_toast(),
+            AppEvent::CancelDrain => {
+                if self.current_screen == Screen::Ec2 {
+                    self.cancel_selected_instance_drain();
+                }
+            }
+            AppEvent::ToggleVerboseTracing => self.toggle_verbose_tracing(),
+            AppEvent::Char(c) => {
+                if self.input_mode == InputMode::Search {
+                    self.search_query.push(c);
+                    self.snap_selection_to_search();
+                }
+            }
+            AppEvent::Backspace => {
+                if self.input_mode == InputMode::Search {
+                    self.search_query.pop();
+                    self.snap_selection_to_search();
+                }
+            }
         }
 
         Ok(())
@@ -89,13 +154,30 @@ impl App {
             AppEvent::Quit => {
                 if self.dialog == Dialog::Settings {
                     self.cancel_settings();
+                } else if self.dialog == Dialog::Ssh && self.ssh_unlocking {
+                    self.ssh_unlocking = false;
+                    self.ssh_passphrase_input.clear();
                 } else {
                     self.dialog = Dialog::None;
                 }
             }
             AppEvent::Up => {
-                if self.dialog == Dialog::Settings {
-                    if self.settings_selected_field != SettingsField::RefreshInterval {
+                if self.dialog == Dialog::CommandPalette {
+                    self.command_palette_selected = self.command_palette_selected.saturating_sub(1);
+                } else if self.dialog == Dialog::Assistant {
+                    self.assistant_selected = self.assistant_selected.saturating_sub(1);
+                } else if self.dialog == Dialog::Ssh {
+                    if !self.ssh_unlocking {
+                        self.ssh_selected = self.ssh_selected.saturating_sub(1);
+                    }
+                } else if self.dialog == Dialog::SshAddKey {
+                    self.ssh_add_field = self.ssh_add_field.prev();
+                } else if self.dialog == Dialog::LaunchInstance {
+                    self.launch_field = self.launch_field.prev();
+                } else if self.dialog == Dialog::Settings {
+                    if self.settings_value_edit.is_some() {
+                        // Typing a custom value - Up/Down don't change fields
+                    } else if self.settings_selected_field != SettingsField::Profile {
                         self.navigate_settings_field(true);
                         self.ensure_dialog_selection_visible();
                     } else {
@@ -108,6 +190,8 @@ impl App {
                     } else {
                         self.dialog_scroll_offset = self.dialog_scroll_offset.saturating_sub(1);
                     }
+                } else if matches!(self.dialog, Dialog::ScheduleAutoStop(_)) {
+                    self.navigate_auto_stop_field(true);
                 } else {
                     self.dialog_scroll_offset = self.dialog_scroll_offset.saturating_sub(1);
                 }
@@ -115,26 +199,56 @@ impl App {
             AppEvent::Down => {
                 // Calculate max scroll for current dialog based on window size to handle scrolling past selection
                 let (_, h) = self.window_size;
-                
+
                 let (percent_y, content_lines): (u16, u16) = match self.dialog {
                     Dialog::Setup => (70, 27),
                     Dialog::Help => (60, 27),
-                    Dialog::Settings => (60, 15),
+                    Dialog::Settings => (60, 29),
                     Dialog::SessionExpired => (60, 25),
                     Dialog::ConfirmTerminate(_) => (30, 12),
-                    Dialog::ScheduleAutoStop(_) => (30, 12),
-                    Dialog::Alert(_) => (25, 10),
+                    Dialog::ConfirmDetachInstance(_, _) => (30, 12),
+                    Dialog::ScheduleAutoStop(_) => (50, 20),
+                    Dialog::Alert { .. } => if self.alert_expanded { (50, 20) } else { (25, 10) },
                     Dialog::ConfigureAws => (50, 5 + self.available_profiles.len().max(1) as u16 + 1), // Header + Profiles + Footer
                     Dialog::Changelog => (70, 50),
+                    Dialog::CommandPalette => (50, 0),
+                    Dialog::InvokeLambda(_) => (40, 7),
+                    Dialog::Assistant => (60, 0),
+                    Dialog::AssistantError => (60, 15),
+                    Dialog::Ssh => (50, 0),
+                    Dialog::SshAddKey => (40, 0),
+                    Dialog::LaunchInstance => (45, 0),
                     Dialog::None => (0, 0),
                 };
-                
+
                 let chunk_height = h * percent_y / 100;
                 let available_height = chunk_height.saturating_sub(2);
                 let max_scroll = content_lines.saturating_sub(available_height);
 
-                if self.dialog == Dialog::Settings {
-                    if self.settings_selected_field != SettingsField::TestSound {
+                if self.dialog == Dialog::CommandPalette {
+                    let count = self.filtered_palette_commands().len();
+                    if count > 0 {
+                        self.command_palette_selected = (self.command_palette_selected + 1).min(count - 1);
+                    }
+                } else if self.dialog == Dialog::Assistant {
+                    if let Some(proposed) = &self.assistant_proposed {
+                        if !proposed.is_empty() {
+                            self.assistant_selected = (self.assistant_selected + 1).min(proposed.len() - 1);
+                        }
+                    }
+                } else if self.dialog == Dialog::Ssh {
+                    if !self.ssh_unlocking {
+                        let count = self.ssh_key_store.keys.len() + 1; // +1 for "Add new key" row
+                        self.ssh_selected = (self.ssh_selected + 1).min(count - 1);
+                    }
+                } else if self.dialog == Dialog::SshAddKey {
+                    self.ssh_add_field = self.ssh_add_field.next();
+                } else if self.dialog == Dialog::LaunchInstance {
+                    self.launch_field = self.launch_field.next();
+                } else if self.dialog == Dialog::Settings {
+                    if self.settings_value_edit.is_some() {
+                        // Typing a custom value - Up/Down don't change fields
+                    } else if self.settings_selected_field != SettingsField::TestSound {
                         self.navigate_settings_field(false);
                         self.ensure_dialog_selection_visible();
                     } else if self.dialog_scroll_offset < max_scroll {
@@ -147,6 +261,8 @@ impl App {
                     } else if self.dialog_scroll_offset < max_scroll {
                         self.dialog_scroll_offset += 1;
                     }
+                } else if matches!(self.dialog, Dialog::ScheduleAutoStop(_)) {
+                    self.navigate_auto_stop_field(false);
                 } else if self.dialog_scroll_offset < max_scroll {
                     self.dialog_scroll_offset += 1;
                 }
@@ -154,16 +270,59 @@ impl App {
             AppEvent::Enter => {
                 let current_dialog = self.dialog.clone();
                 match current_dialog {
+                    Dialog::CommandPalette => {
+                        self.execute_selected_palette_command().await?;
+                    }
+                    Dialog::InvokeLambda(name) => {
+                        self.dialog = Dialog::None;
+                        let payload = self.lambda_invoke_payload.clone();
+                        self.invoke_selected_lambda(&name, &payload).await?;
+                    }
                     Dialog::ConfirmTerminate(id) => {
                         self.dialog = Dialog::None;
                         self.terminate_instance(&id).await?;
                     }
-                    Dialog::ScheduleAutoStop(id) => {
+                    Dialog::ConfirmDetachInstance(group_name, instance_id) => {
                         self.dialog = Dialog::None;
-                        self.schedule_auto_stop(&id, Duration::from_secs(3600))?;
+                        self.detach_instance(&group_name, &instance_id).await?;
                     }
+                    Dialog::ScheduleAutoStop(id) => match self.auto_stop_mode {
+                        AutoStopMode::Duration => {
+                            if self.auto_stop_custom_selected() {
+                                match Self::parse_auto_stop_duration(&self.auto_stop_duration_input) {
+                                    Some(duration) => {
+                                        self.dialog = Dialog::None;
+                                        self.schedule_auto_stop(&id, duration, self.auto_stop_action)?;
+                                    }
+                                    None => {
+                                        self.auto_stop_duration_error =
+                                            Some("Enter a duration like 30m, 2h, or 1h30m".to_string());
+                                    }
+                                }
+                            } else if let Some((_, Some(duration))) = AUTO_STOP_PRESETS.get(self.auto_stop_preset_index) {
+                                let duration = *duration;
+                                self.dialog = Dialog::None;
+                                self.schedule_auto_stop(&id, duration, self.auto_stop_action)?;
+                            }
+                        }
+                        AutoStopMode::Recurring => {
+                            if self.auto_stop_days.is_empty() {
+                                self.auto_stop_duration_error = Some("Select at least one day".to_string());
+                            } else {
+                                let days = self.auto_stop_days.clone();
+                                let (hour, minute) = (self.auto_stop_hour, self.auto_stop_minute);
+                                let action = self.auto_stop_action;
+                                self.dialog = Dialog::None;
+                                self.schedule_recurring_auto_stop(&id, days, hour, minute, action)?;
+                            }
+                        }
+                    },
                     Dialog::Settings => {
-                        if self.settings_selected_field == SettingsField::TestSound {
+                        if self.settings_value_edit.is_some() {
+                            self.commit_settings_value_edit();
+                        } else if matches!(self.settings_selected_field, SettingsField::RefreshInterval | SettingsField::AlertThreshold | SettingsField::SessionRenewalThreshold | SettingsField::ProfileAlias) {
+                            self.start_settings_value_edit();
+                        } else if self.settings_selected_field == SettingsField::TestSound {
                             self.trigger_test_alert();
                         } else {
                             self.save_settings();
@@ -171,13 +330,53 @@ impl App {
                     }
                     Dialog::ConfigureAws | Dialog::SessionExpired => {
                          if !self.available_profiles.is_empty() {
-                             let profile = self.available_profiles[self.selected_profile_index].clone();
-                             self.activate_profile(&profile).await?;
+                             let profile = self.available_profiles[self.selected_profile_index].name.clone();
+                             self.open_or_switch_profile(&profile).await?;
                          }
                     }
-                    Dialog::Alert(_) | Dialog::Help | Dialog::Setup | Dialog::Changelog => {
+                    Dialog::Alert { detail, .. } => {
+                        if detail.is_some() {
+                            self.alert_expanded = !self.alert_expanded;
+                        } else {
+                            self.dialog = Dialog::None;
+                        }
+                    }
+                    Dialog::Help | Dialog::Setup | Dialog::Changelog => {
                         self.dialog = Dialog::None;
                     }
+                    Dialog::Assistant => {
+                        if self.assistant_busy {
+                            // Ignore Enter while a request is in flight
+                        } else if self.assistant_proposed.is_some() {
+                            self.execute_selected_assistant_action().await?;
+                        } else if !self.assistant_prompt.trim().is_empty() {
+                            self.submit_assistant_prompt();
+                        }
+                    }
+                    Dialog::AssistantError => {
+                        self.assistant_error_expanded = !self.assistant_error_expanded;
+                    }
+                    Dialog::Ssh => {
+                        if self.ssh_unlocking {
+                            self.unlock_and_connect_ssh().await?;
+                        } else if self.ssh_selected == self.ssh_key_store.keys.len() {
+                            self.open_ssh_add_key_dialog();
+                        } else {
+                            self.ssh_unlocking = true;
+                            self.ssh_passphrase_input.clear();
+                        }
+                    }
+                    Dialog::SshAddKey => {
+                        self.add_ssh_key()?;
+                    }
+                    Dialog::LaunchInstance => {
+                        if self.launch_ami_id.trim().is_empty() {
+                            self.launch_error = Some("AMI id is required".to_string());
+                        } else {
+                            self.dialog = Dialog::None;
+                            self.launch_instance().await?;
+                        }
+                    }
                     Dialog::None => {}
                 }
             }
@@ -188,16 +387,130 @@ impl App {
             // Settings dialog specific events
             AppEvent::ModifySettingValue(delta) => {
                 if self.dialog == Dialog::Settings {
-                    self.modify_current_setting(delta);
+                    if self.settings_value_edit.is_none() {
+                        self.modify_current_setting(delta);
+                    }
+                } else if self.dialog == Dialog::SshAddKey && self.ssh_add_field == SshAddField::KeyType {
+                    self.ssh_add_key_type = self.ssh_add_key_type.toggled();
+                } else if matches!(self.dialog, Dialog::ScheduleAutoStop(_)) && !self.auto_stop_custom_selected() {
+                    self.modify_auto_stop_field(delta);
+                } else if matches!(self.dialog, Dialog::InvokeLambda(_)) {
+                    self.lambda_invoke_type = self.lambda_invoke_type.cycled(delta);
+                } else if self.dialog == Dialog::LaunchInstance && self.launch_field == LaunchInstanceField::Count {
+                    self.launch_count = (self.launch_count as i32 + delta).max(1) as u32;
+                } else if self.dialog == Dialog::LaunchInstance && self.launch_field == LaunchInstanceField::Spot {
+                    self.launch_spot = !self.launch_spot;
+                }
+            }
+            AppEvent::NudgeSettingValue(steps) => {
+                if self.dialog == Dialog::Settings && self.settings_value_edit.is_none() {
+                    self.nudge_current_setting(steps);
                 }
             }
             AppEvent::CancelSettings => {
                 if self.dialog == Dialog::Settings {
-                    self.cancel_settings();
+                    if self.settings_value_edit.take().is_none() {
+                        self.cancel_settings();
+                    } else {
+                        self.settings_value_edit_error = None;
+                    }
+                } else if self.dialog == Dialog::Ssh && self.ssh_unlocking {
+                    self.ssh_unlocking = false;
+                    self.ssh_passphrase_input.clear();
                 } else {
                     self.dialog = Dialog::None;
                 }
             }
+            AppEvent::Char(c) => match self.dialog {
+                Dialog::CommandPalette => {
+                    self.command_palette_query.push(c);
+                    self.command_palette_selected = 0;
+                }
+                Dialog::InvokeLambda(_) => self.lambda_invoke_payload.push(c),
+                Dialog::ScheduleAutoStop(_) => {
+                    self.auto_stop_duration_input.push(c);
+                    self.auto_stop_duration_error = None;
+                }
+                Dialog::Settings => {
+                    if let Some(buf) = self.settings_value_edit.as_mut() {
+                        buf.push(c);
+                        self.settings_value_edit_error = None;
+                    }
+                }
+                Dialog::Assistant => {
+                    if !self.assistant_busy && self.assistant_proposed.is_none() {
+                        self.assistant_prompt.push(c);
+                    }
+                }
+                Dialog::Ssh => {
+                    if self.ssh_unlocking {
+                        self.ssh_passphrase_input.push(c);
+                    }
+                }
+                Dialog::SshAddKey => match self.ssh_add_field {
+                    SshAddField::Label => self.ssh_add_label_input.push(c),
+                    SshAddField::Path => self.ssh_add_path_input.push(c),
+                    SshAddField::KeyType => {}
+                },
+                Dialog::LaunchInstance => {
+                    match self.launch_field {
+                        LaunchInstanceField::AmiId => self.launch_ami_id.push(c),
+                        LaunchInstanceField::InstanceType => self.launch_instance_type.push(c),
+                        LaunchInstanceField::KeyName => self.launch_key_name.push(c),
+                        LaunchInstanceField::SecurityGroup => self.launch_security_group.push(c),
+                        LaunchInstanceField::Name => self.launch_name.push(c),
+                        LaunchInstanceField::Spot | LaunchInstanceField::Count => {}
+                    }
+                    self.launch_error = None;
+                }
+                _ => {}
+            },
+            AppEvent::Backspace => match self.dialog {
+                Dialog::CommandPalette => {
+                    self.command_palette_query.pop();
+                    self.command_palette_selected = 0;
+                }
+                Dialog::InvokeLambda(_) => {
+                    self.lambda_invoke_payload.pop();
+                }
+                Dialog::ScheduleAutoStop(_) => {
+                    self.auto_stop_duration_input.pop();
+                    self.auto_stop_duration_error = None;
+                }
+                Dialog::Settings => {
+                    if let Some(buf) = self.settings_value_edit.as_mut() {
+                        buf.pop();
+                        self.settings_value_edit_error = None;
+                    }
+                }
+                Dialog::Assistant => {
+                    if !self.assistant_busy && self.assistant_proposed.is_none() {
+                        self.assistant_prompt.pop();
+                    }
+                }
+                Dialog::Ssh => {
+                    if self.ssh_unlocking {
+                        self.ssh_passphrase_input.pop();
+                    }
+                }
+                Dialog::SshAddKey => match self.ssh_add_field {
+                    SshAddField::Label => { self.ssh_add_label_input.pop(); }
+                    SshAddField::Path => { self.ssh_add_path_input.pop(); }
+                    SshAddField::KeyType => {}
+                },
+                Dialog::LaunchInstance => {
+                    match self.launch_field {
+                        LaunchInstanceField::AmiId => { self.launch_ami_id.pop(); }
+                        LaunchInstanceField::InstanceType => { self.launch_instance_type.pop(); }
+                        LaunchInstanceField::KeyName => { self.launch_key_name.pop(); }
+                        LaunchInstanceField::SecurityGroup => { self.launch_security_group.pop(); }
+                        LaunchInstanceField::Name => { self.launch_name.pop(); }
+                        LaunchInstanceField::Spot | LaunchInstanceField::Count => {}
+                    }
+                    self.launch_error = None;
+                }
+                _ => {}
+            },
             AppEvent::SsoLogin => {
                 if matches!(self.dialog, Dialog::SessionExpired | Dialog::ConfigureAws | Dialog::Setup) {
                     self.login_with_sso().await?;
@@ -215,41 +528,137 @@ impl App {
     }
 
     /// Move selection up or down
+    /// Switch to the tab at `idx` (the same indices used by the `1`-`6` shortcuts and
+    /// a click on the tab strip), skipping the Logs tab when it's disabled in settings
+    fn navigate_to_tab(&mut self, idx: usize) {
+        let new_screen = match idx {
+            0 => Screen::Home,
+            1 => Screen::Ec2,
+            2 => Screen::Lambda,
+            3 => Screen::AutoScaling,
+            4 => Screen::About,
+            5 => Screen::Logs,
+            _ => self.current_screen,
+        };
+
+        // Skip Logs screen if disabled in settings
+        let new_screen = if new_screen == Screen::Logs && !self.settings.show_logs_panel {
+            self.current_screen
+        } else {
+            new_screen
+        };
+
+        if new_screen != self.current_screen {
+            self.current_screen = new_screen;
+            self.scroll_offset = 0;
+            self.log_manager.info(format!("Navigated to {:?} screen", new_screen));
+        }
+    }
+
+    /// Hit-test a left-click against toasts, the tab strip, and the EC2 table, using the
+    /// rects `render_toasts`/`render_tabs`/`render_ec2` stashed on `App` the last time
+    /// they drew. Toasts are checked first since they're drawn on top of everything
+    /// else. A click outside all of them (or on a screen that hasn't ever rendered them)
+    /// is a no-op.
+    fn handle_click(&mut self, x: u16, y: u16) {
+        for slot in self.toast_areas.get().into_iter().flatten() {
+            let (index, area) = slot;
+            if x >= area.x && x < area.x.saturating_add(area.width) && y >= area.y && y < area.y.saturating_add(area.height) {
+                self.dismiss_toast_at(index);
+                return;
+            }
+        }
+
+        let tab_area = self.tab_strip_area.get();
+        if tab_area.height > 0 && y >= tab_area.y && y < tab_area.y.saturating_add(tab_area.height) {
+            // Inside the block's border; mirror `Tabs`'s own layout (see
+            // `ui::tab_click_index`) instead of assuming an even split, since titles like
+            // "🏠 Home [1]" and "ℹ️ About [5]" aren't the same width.
+            let inner_x = tab_area.x.saturating_add(1);
+            let inner_width = tab_area.width.saturating_sub(2);
+            let titles = crate::ui::tab_titles(self.settings.show_logs_panel);
+            if let Some(idx) = crate::ui::tab_click_index(&titles, inner_x, inner_width, x) {
+                self.navigate_to_tab(idx);
+            }
+            return;
+        }
+
+        if self.current_screen == Screen::Ec2 {
+            let table_area = self.ec2_table_area.get();
+            if table_area.height > 0
+                && x >= table_area.x
+                && x < table_area.x.saturating_add(table_area.width)
+                && y >= table_area.y
+                && y < table_area.y.saturating_add(table_area.height)
+            {
+                let row_in_view = (y - table_area.y) as usize;
+                let pos = self.ec2_table_state.offset() + row_in_view;
+                let matches = self.ec2_search_matches();
+                if let Some(&idx) = matches.get(pos) {
+                    self.ec2_selected = idx;
+                }
+            }
+        }
+    }
+
     fn move_selection(&mut self, delta: i32) {
         match self.current_screen {
+            // Navigate within the live-search matches (all indices when the query is
+            // empty, so this is a no-op change from the unfiltered behavior)
             Screen::Ec2 => {
-                let len = self.ec2_instances.len();
-                if len > 0 {
-                    let new_idx = self.ec2_selected as i32 + delta;
-                    self.ec2_selected = new_idx.clamp(0, (len - 1) as i32) as usize;
+                let matches = self.ec2_search_matches();
+                if !matches.is_empty() {
+                    let pos = matches.iter().position(|&i| i == self.ec2_selected).unwrap_or(0);
+                    let new_pos = (pos as i32 + delta).clamp(0, matches.len() as i32 - 1) as usize;
+                    self.ec2_selected = matches[new_pos];
                 }
             }
             Screen::Lambda => {
-                let len = self.lambda_functions.len();
+                let matches = self.lambda_search_matches();
+                if !matches.is_empty() {
+                    let pos = matches.iter().position(|&i| i == self.lambda_selected).unwrap_or(0);
+                    let new_pos = (pos as i32 + delta).clamp(0, matches.len() as i32 - 1) as usize;
+                    self.lambda_selected = matches[new_pos];
+                }
+            }
+            Screen::AutoScaling => {
+                let len = self.asg_groups.len();
                 if len > 0 {
-                    let new_idx = self.lambda_selected as i32 + delta;
-                    self.lambda_selected = new_idx.clamp(0, (len - 1) as i32) as usize;
+                    let new_idx = self.asg_selected as i32 + delta;
+                    self.asg_selected = new_idx.clamp(0, (len - 1) as i32) as usize;
+                    self.asg_instance_selected = 0;
                 }
             }
-            Screen::Home | Screen::About | Screen::Logs => {
+            // The Logs screen's content height is the current filtered entry count, not
+            // a fixed estimate, so paging stays correct while a level/search filter is active
+            Screen::Logs => {
+                let available_height = self.window_size.1.saturating_sub(8);
+                let filtered_len = self.log_manager.filtered_entries(self.settings.log_level, &self.search_query).len() as u16;
+                let max_scroll = filtered_len.saturating_sub(available_height);
+
+                if delta > 0 {
+                    self.scroll_offset = (self.scroll_offset + 1).min(max_scroll);
+                } else {
+                    self.scroll_offset = self.scroll_offset.saturating_sub(1);
+                }
+            }
+            Screen::Home | Screen::About => {
                 let (w, h) = self.window_size;
                 // Estimate available height (minus headers, borders, footer)
                 // Tabs(3) + Status(3) + Borders(2) = 8.
                 let available_height = h.saturating_sub(8);
-                
+
                 let content_height: u16 = match self.current_screen {
                     // Home content height depends on width (wide vs narrow layout)
                     Screen::Home => if w >= 100 { 18 } else { 25 },
                     // About content height also depends on width (side-by-side vs stacked)
                     Screen::About => if w >= 100 { 30 } else { 58 },
-                    // Logs screen - scroll through log entries
-                    Screen::Logs => 50,
                     _ => 0,
                 };
-                
+
                 // Allow scrolling only if content exceeds available height
                 let max_scroll = content_height.saturating_sub(available_height);
-                
+
                 if delta > 0 {
                     self.scroll_offset = (self.scroll_offset + 1).min(max_scroll);
                 } else {
@@ -259,6 +668,30 @@ impl App {
         }
     }
 
+    /// After the live-search query changes, jump the EC2/Lambda selection onto the
+    /// first remaining match so the cursor never sits on a row the filter just hid
+    fn snap_selection_to_search(&mut self) {
+        match self.current_screen {
+            Screen::Ec2 => {
+                let matches = self.ec2_search_matches();
+                if !matches.contains(&self.ec2_selected) {
+                    if let Some(&first) = matches.first() {
+                        self.ec2_selected = first;
+                    }
+                }
+            }
+            Screen::Lambda => {
+                let matches = self.lambda_search_matches();
+                if !matches.contains(&self.lambda_selected) {
+                    if let Some(&first) = matches.first() {
+                        self.lambda_selected = first;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
     /// Handle Enter key based on current screen
     async fn handle_enter(&mut self) -> Result<()> {
         match self.current_screen {
@@ -275,6 +708,13 @@ impl App {
                     self.status_message = format!("Lambda invocation coming soon: {}", func.name);
                 }
             }
+            Screen::AutoScaling => {
+                // Cycle which instance within the selected group [d]/detach acts on
+                let len = self.asg_groups.get(self.asg_selected).map(|g| g.instance_ids.len()).unwrap_or(0);
+                if len > 0 {
+                    self.asg_instance_selected = (self.asg_instance_selected + 1) % len;
+                }
+            }
             Screen::About | Screen::Logs => {
                 // These screens have no interactive elements
             }
@@ -304,12 +744,21 @@ impl App {
             },
             Dialog::Settings => {
                 let idx = match self.settings_selected_field {
-                    SettingsField::RefreshInterval => 0,
-                    SettingsField::ShowLogsPanel => 1,
-                    SettingsField::LogLevel => 2,
-                    SettingsField::AlertThreshold => 3,
-                    SettingsField::SoundEnabled => 4,
-                    SettingsField::TestSound => 5,
+                    SettingsField::Profile => 0,
+                    SettingsField::ProfileAlias => 1,
+                    SettingsField::RefreshInterval => 2,
+                    SettingsField::ShowLogsPanel => 3,
+                    SettingsField::LogLevel => 4,
+                    SettingsField::AlertThreshold => 5,
+                    SettingsField::SessionRenewalThreshold => 6,
+                    SettingsField::SoundEnabled => 7,
+                    SettingsField::NotificationsEnabled => 8,
+                    SettingsField::StopOnExit => 9,
+                    SettingsField::FileLogging => 10,
+                    SettingsField::StderrLogging => 11,
+                    SettingsField::FileFormat => 12,
+                    SettingsField::Theme => 13,
+                    SettingsField::TestSound => 14,
                 };
                 top_padding + (idx * 2)
             },
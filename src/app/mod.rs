@@ -14,39 +14,70 @@ use crate::aws::AwsClient;
 use crate::config::AppConfig;
 use crate::event::poll_event;
 use crate::logger::LogManager;
-use crate::settings::{Settings, SettingsField};
+use crate::settings::{Settings, SettingsField, SettingsStore};
+use crate::theme;
 use crate::ui;
 
 // Re-export core types for external usage (like main.rs)
-pub use state::{App, Screen, Dialog, ToastType};
+pub use state::{App, Screen, Dialog, ToastType, SshAddField, AlertSeverity, AutoStopField, AutoStopMode, InputMode, LaunchInstanceField, Workspace, AUTO_STOP_DAY_ORDER};
 
 impl App {
-    /// Create a new application instance
-    pub async fn new() -> Result<Self> {
-        let config = AppConfig::default();
-        
-        // Initialize logger first
+    /// Create a new application instance. `tracing_rx` is the receiving end of the
+    /// `telemetry::log_bridge_layer` channel, and `tracing_filter_handle` the handle to
+    /// the reloadable `EnvFilter` gating it (and the rolling file layer), both wired up in
+    /// `main.rs` before the `tracing` registry is initialized, so `tracing_rx` can be
+    /// drained alongside `async_rx` and the filter reloaded by `toggle_verbose_tracing`
+    /// once `App` exists.
+    pub async fn new(
+        tracing_rx: std::sync::mpsc::Receiver<crate::logger::LogEntry>,
+        tracing_filter_handle: tracing_subscriber::reload::Handle<tracing_subscriber::EnvFilter, tracing_subscriber::Registry>,
+    ) -> Result<Self> {
+        let mut config = AppConfig::default();
+
+        // Initialize logger first, loading prior runs' history (if any) before the
+        // first entry of this run so `render_logs` has continuity across restarts
         let mut log_manager = LogManager::new();
+        if let Ok(config_dir) = Settings::get_config_dir() {
+            log_manager.load_history(&config_dir);
+        }
         log_manager.info("Application started".to_string());
         
-        // Load settings from file
-        let settings = match Settings::load() {
+        // Load the settings profile store (migrating a pre-profile settings.json in
+        // place, if that's what's on disk) and mirror the active profile into `settings`
+        let settings_profiles = match SettingsStore::load() {
             Ok(s) => {
                 log_manager.info("Settings loaded successfully".to_string());
                 s
             }
             Err(e) => {
                 log_manager.warning(format!("Failed to load settings, using defaults: {}", e));
-                Settings::default()
+                SettingsStore::default()
             }
         };
-        
-        // Load available profiles
-        let available_profiles = crate::aws::list_aws_profiles().unwrap_or_default();
+        let settings = settings_profiles.active_settings();
+        log_manager.configure_outputs(settings.log_outputs.clone());
+
+        // Resolve the Slack webhook URL (if configured) from whichever env var
+        // `slack_webhook_url_env` names, the same indirection `assistant_api_key_env`
+        // uses for the ops-assistant API key - secrets stay out of settings.json
+        if let Some(env_var) = settings.slack_webhook_url_env.as_deref().filter(|s| !s.is_empty()) {
+            config.alerts.slack_webhook_url = std::env::var(env_var).ok();
+        }
+
+        // Load available profiles, with a synthetic entry up front for "resolve
+        // credentials from the standard provider chain" (env vars, web identity, ECS
+        // container, or IMDS instance role) rather than a named profile - not a real
+        // `~/.aws` section, so `list_aws_profiles` itself stays file-only
+        let mut available_profiles = crate::aws::list_aws_profiles().unwrap_or_default();
+        available_profiles.insert(0, crate::aws::AwsProfileInfo {
+            name: crate::aws::DEFAULT_CREDENTIAL_CHAIN_PROFILE.to_string(),
+            region: config.aws_region.clone(),
+            sso: false,
+        });
         
         // Set default profile if configured and available
         if let Some(default_profile) = &settings.default_profile {
-            if available_profiles.contains(default_profile) {
+            if available_profiles.iter().any(|p| &p.name == default_profile) {
                 std::env::set_var("AWS_PROFILE", default_profile);
                 log_manager.info(format!("Using default profile: {}", default_profile));
             } else {
@@ -54,24 +85,131 @@ impl App {
             }
         }
         
-        // Initialize AWS client (now that AWS_PROFILE is set)
-        let aws_client = AwsClient::new(config.aws_region.as_deref()).await?;
+        // Initialize AWS client, pinned to the configured default profile (if any) rather
+        // than relying solely on whatever AWS_PROFILE happens to be set to. Prefer that
+        // profile's own resolved region (from `~/.aws/config`, mirroring what
+        // `App::activate_profile` does on a later switch) over the static config value,
+        // so a profile's declared region takes effect from the very first client built.
+        let initial_region = settings
+            .default_profile
+            .as_deref()
+            .and_then(|name| available_profiles.iter().find(|p| p.name == name))
+            .and_then(|p| p.region.clone())
+            .or_else(|| config.aws_region.clone());
+        let aws_client = AwsClient::new(settings.default_profile.as_deref(), initial_region.as_deref()).await?;
         
         let aws_configured = Self::check_aws_credentials().await;
-        let initial_dialog = if aws_configured {
-            Dialog::None
-        } else {
+
+        // Load the keybinding/theme overrides, reporting a malformed keymap.toml via
+        // the alert dialog (but still falling back to working defaults)
+        let (keymap, keymap_load_error) = crate::keymap::Keymap::load();
+        let mut theme = theme::theme_styles(settings.theme_palette);
+        theme::apply_accent_overrides(&mut theme, &keymap.resolve_theme_overrides());
+
+        let initial_dialog = if !aws_configured {
             Dialog::Setup
+        } else if let Some(err) = &keymap_load_error {
+            Dialog::error("Failed to load keymap.toml, using defaults", err.clone())
+        } else {
+            Dialog::None
         };
-        
+
         if !aws_configured {
             log_manager.warning("AWS credentials not configured".to_string());
         } else {
             log_manager.info("AWS credentials detected".to_string());
         }
-        
+        if let Some(err) = &keymap_load_error {
+            log_manager.warning(format!("Failed to load keymap.toml, using defaults: {}", err));
+        }
+        for conflict in keymap.conflicts() {
+            log_manager.warning(format!("keymap.toml: {}", conflict));
+        }
+
         let (async_tx, async_rx) = std::sync::mpsc::channel();
-        
+
+        // Hot-reload settings.json: re-parse on every write and hand the result back
+        // through the same notification channel as other background work
+        let reload_tx = async_tx.clone();
+        let settings_watcher = Settings::watch(move |result| {
+            let notification = match result {
+                Ok(s) => crate::app::state::AsyncNotification::SettingsReloaded(s),
+                Err(e) => crate::app::state::AsyncNotification::SettingsReloadFailed(e.to_string()),
+            };
+            let _ = reload_tx.send(notification);
+        })
+        .map_err(|e| log_manager.warning(format!("Failed to watch settings.json for changes: {}", e)))
+        .ok();
+
+        // Watch for SIGINT/SIGTERM (and windows ctrl-c) on a background task so `run()`
+        // can flush outstanding auto-stop schedules before the process actually exits,
+        // instead of silently dropping them
+        let shutdown_requested = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        {
+            let shutdown_requested = shutdown_requested.clone();
+            tokio::spawn(async move {
+                #[cfg(unix)]
+                {
+                    let mut sigterm = match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+                        Ok(s) => s,
+                        Err(_) => return,
+                    };
+                    tokio::select! {
+                        _ = tokio::signal::ctrl_c() => {}
+                        _ = sigterm.recv() => {}
+                    }
+                }
+                #[cfg(not(unix))]
+                {
+                    let _ = tokio::signal::ctrl_c().await;
+                }
+                shutdown_requested.store(true, std::sync::atomic::Ordering::SeqCst);
+            });
+        }
+
+        // Listen on the IPC control socket (see `crate::ipc`) so a second invocation of
+        // the binary (`aws-cloud-controller msg ...`) can drive this running instance.
+        // Best-effort: any existing socket file (e.g. a stale one from a crashed prior
+        // instance) is removed before the single bind attempt, and a listener that still
+        // can't be started (e.g. read-only runtime dir) only logs a warning - the TUI is
+        // fully usable without it.
+        #[cfg(unix)]
+        {
+            let ipc_tx = async_tx.clone();
+            let socket_path = crate::ipc::socket_path();
+            let _ = std::fs::remove_file(&socket_path);
+            match tokio::net::UnixListener::bind(&socket_path) {
+                Ok(listener) => {
+                    tokio::spawn(async move {
+                        loop {
+                            let Ok((stream, _)) = listener.accept().await else { continue };
+                            let ipc_tx = ipc_tx.clone();
+                            tokio::spawn(async move {
+                                use tokio::io::{AsyncBufReadExt, BufReader};
+                                let mut lines = BufReader::new(stream).lines();
+                                while let Ok(Some(line)) = lines.next_line().await {
+                                    if line.trim().is_empty() {
+                                        continue;
+                                    }
+                                    match crate::ipc::IpcCommand::parse(&line) {
+                                        Ok(cmd) => {
+                                            let _ = ipc_tx.send(crate::app::state::AsyncNotification::IpcCommand(cmd.into()));
+                                        }
+                                        Err(e) => {
+                                            tracing::warn!(operation = "ipc", error = %e, "Rejected malformed IPC command");
+                                        }
+                                    }
+                                }
+                            });
+                        }
+                    });
+                }
+                Err(e) => {
+                    log_manager.warning(format!("Failed to start IPC control socket at {}: {}", socket_path.display(), e));
+                }
+            }
+        }
+
         Ok(Self {
             config,
             aws_client,
@@ -80,15 +218,42 @@ impl App {
             aws_configured,
             status_message: if aws_configured { "Ready".to_string() } else { "AWS credentials not configured".to_string() },
             is_loading: false,
+            spinner_frame: 0,
+            ui_tick: 0,
             ec2_instances: Vec::new(),
             ec2_selected: 0,
             ec2_table_state: TableState::default(),
-            auto_stop_schedules: Vec::new(),
+            auto_stop_schedules: crate::app::state::load_auto_stop_schedules(),
+            ec2_metrics: std::collections::HashMap::new(),
+            rebooting_instances: std::collections::HashSet::new(),
+            instance_lifecycle: std::collections::HashMap::new(),
             lambda_functions: Vec::new(),
             lambda_selected: 0,
+            lambda_invoke_payload: String::new(),
+            lambda_invoke_type: crate::aws::LambdaInvocationType::RequestResponse,
+            lambda_last_response: None,
+            lambda_recent_logs: Vec::new(),
+            asg_groups: Vec::new(),
+            asg_selected: 0,
+            asg_instance_selected: 0,
+            auto_stop_mode: crate::app::state::AutoStopMode::Duration,
+            auto_stop_action: crate::app::state::ScheduleAction::Stop,
+            auto_stop_field: crate::app::state::AutoStopField::Mode,
+            auto_stop_preset_index: crate::app::actions::AUTO_STOP_DEFAULT_PRESET,
+            auto_stop_duration_input: String::new(),
+            auto_stop_duration_error: None,
+            auto_stop_days: std::collections::HashSet::new(),
+            auto_stop_hour: 18,
+            auto_stop_minute: 0,
             dialog: initial_dialog,
             pending_alerts: Vec::new(),
             last_alert_check: None,
+            slack_alerted_instances: std::collections::HashSet::new(),
+            credential_expiration: std::env::var("AWS_PROFILE")
+                .ok()
+                .and_then(|p| crate::aws::get_credential_expiration(&p)),
+            credential_expiry_alerted: false,
+            session_renewal_in_flight: false,
             last_refresh: None,
             auto_refresh_interval: settings.refresh_interval(),
             boost_refresh_until_stable: false,
@@ -99,16 +264,68 @@ impl App {
             settings,
             settings_selected_field: SettingsField::RefreshInterval,
             settings_draft: None,
+            settings_profiles,
+            settings_value_edit: None,
+            settings_value_edit_error: None,
             log_manager,
             async_tx,
             async_rx,
+            tracing_rx,
+            tracing_filter_handle,
+            verbose_tracing: false,
             available_profiles: available_profiles.clone(),
             selected_profile_index: if let Ok(current) = std::env::var("AWS_PROFILE") {
-                available_profiles.iter().position(|p| p == &current).unwrap_or(0)
+                available_profiles.iter().position(|p| p.name == current).unwrap_or(0)
             } else {
                 0
             },
             active_profile_name: std::env::var("AWS_PROFILE").ok().or(Some("default".to_string())),
+            workspaces: vec![Workspace::new(
+                std::env::var("AWS_PROFILE").unwrap_or_else(|_| "default".to_string()),
+                initial_region.clone(),
+            )],
+            active_workspace: 0,
+            theme,
+            keymap,
+            settings_watcher,
+            command_palette_query: String::new(),
+            command_palette_selected: 0,
+            input_mode: InputMode::Normal,
+            search_query: String::new(),
+            assistant_prompt: String::new(),
+            assistant_busy: false,
+            assistant_proposed: None,
+            assistant_selected: 0,
+            assistant_error: None,
+            assistant_error_expanded: false,
+            alert_expanded: false,
+            ssh_key_store: crate::ssh::SshKeyStore::load(),
+            ssh_selected: 0,
+            ssh_unlocking: false,
+            ssh_passphrase_input: String::new(),
+            ssh_target_instance_id: None,
+            ssh_agent: None,
+            ssh_add_label_input: String::new(),
+            ssh_add_path_input: String::new(),
+            ssh_add_key_type: crate::ssh::SshKeyType::Ed25519,
+            ssh_add_field: SshAddField::Label,
+            launch_field: crate::app::state::LaunchInstanceField::AmiId,
+            launch_ami_id: String::new(),
+            launch_instance_type: String::new(),
+            launch_key_name: String::new(),
+            launch_security_group: String::new(),
+            launch_name: String::new(),
+            launch_spot: false,
+            launch_count: 1,
+            launch_error: None,
+            shutdown_requested,
+            request_seq: std::sync::Arc::new(std::sync::atomic::AtomicU64::new(0)),
+            active_profile_task: None,
+            active_lambda_task: None,
+            active_asg_task: None,
+            tab_strip_area: std::cell::Cell::new(ratatui::layout::Rect::default()),
+            ec2_table_area: std::cell::Cell::new(ratatui::layout::Rect::default()),
+            toast_areas: std::cell::Cell::new([None, None, None]),
         })
     }
     
@@ -143,14 +360,37 @@ impl App {
         let tick_rate = Duration::from_millis(self.config.tick_rate_ms);
 
         loop {
+            // Advance render-loop counters used to animate the UI
+            self.ui_tick = self.ui_tick.wrapping_add(1);
+            if self.is_loading {
+                self.spinner_frame = self.spinner_frame.wrapping_add(1);
+            }
+
             // Render UI
             terminal.draw(|f| ui::render(f, self))?;
 
             // Handle events
-            if let Some(event) = poll_event(tick_rate)? {
+            let text_input_mode = matches!(
+                self.dialog,
+                Dialog::CommandPalette
+                    | Dialog::InvokeLambda(_)
+                    | Dialog::Assistant
+                    | Dialog::Ssh
+                    | Dialog::SshAddKey
+            ) || (self.dialog == Dialog::Settings && self.settings_value_edit.is_some())
+                || (matches!(self.dialog, Dialog::ScheduleAutoStop(_)) && self.auto_stop_custom_selected())
+                || (self.dialog == Dialog::LaunchInstance
+                    && !matches!(self.launch_field, crate::app::state::LaunchInstanceField::Count | crate::app::state::LaunchInstanceField::Spot))
+                || self.input_mode == InputMode::Search;
+            if let Some(event) = poll_event(tick_rate, text_input_mode, &self.keymap)? {
                 self.handle_event(event).await?;
             }
 
+            if self.shutdown_requested.load(std::sync::atomic::Ordering::SeqCst) {
+                self.log_manager.info("Shutdown signal received".to_string());
+                self.should_quit = true;
+            }
+
             // Sync table state with selection
             if self.current_screen == Screen::Ec2 {
                 if self.ec2_instances.is_empty() {
@@ -172,6 +412,7 @@ impl App {
             self.check_auto_refresh().await?;
 
             if self.should_quit {
+                self.shutdown().await?;
                 break;
             }
         }
@@ -1,11 +1,101 @@
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::time::Duration;
-use chrono::{DateTime, Utc};
+use anyhow::Context;
+use chrono::{DateTime, Utc, Weekday};
+use ratatui::style::Style;
 use ratatui::widgets::TableState;
+use serde::{Deserialize, Serialize};
 
-use crate::aws::{AwsClient, Ec2Instance, LambdaFunction};
+use crate::assistant::{AssistantError, ProposedAction};
+use crate::aws::{AutoScalingGroup, AwsClient, AwsProfileInfo, Ec2Instance, LambdaFunction, LambdaInvocationType};
 use crate::config::AppConfig;
+use crate::keymap::Keymap;
 use crate::logger::LogManager;
-use crate::settings::{Settings, SettingsField};
+use crate::settings::{Settings, SettingsField, SettingsStore};
+use crate::ssh::{SshAgentHandle, SshKeyStore, SshKeyType};
+use crate::theme::Styles;
+
+/// Maximum number of CloudWatch samples kept per instance metric series
+pub const MAX_METRIC_SAMPLES: usize = 30;
+
+/// Ring-buffered CloudWatch history (CPU/network) for one EC2 instance
+#[derive(Debug, Clone, Default)]
+pub struct MetricHistory {
+    pub cpu: VecDeque<(f64, f64)>,
+    pub network_in: VecDeque<(f64, f64)>,
+    pub network_out: VecDeque<(f64, f64)>,
+}
+
+impl MetricHistory {
+    /// Push a point into a bounded series, dropping the oldest sample if full
+    fn push(series: &mut VecDeque<(f64, f64)>, point: (f64, f64)) {
+        series.push_back(point);
+        if series.len() > MAX_METRIC_SAMPLES {
+            series.pop_front();
+        }
+    }
+
+    pub fn push_cpu(&mut self, point: (f64, f64)) {
+        Self::push(&mut self.cpu, point);
+    }
+
+    pub fn push_network_in(&mut self, point: (f64, f64)) {
+        Self::push(&mut self.network_in, point);
+    }
+
+    pub fn push_network_out(&mut self, point: (f64, f64)) {
+        Self::push(&mut self.network_out, point);
+    }
+}
+
+/// Snapshot of one signed-in account/region's data, so switching between a few saved
+/// profiles doesn't discard what was last loaded for the one left behind. `App` keeps a
+/// `Vec<Workspace>` plus an `active_workspace` index; the flat `ec2_instances`/
+/// `lambda_functions`/etc. fields on `App` always mirror whichever workspace is active,
+/// and are copied in and out of this struct by `App::switch_workspace` at the moment of
+/// a switch. A `Workspace` deliberately does NOT carry its own `AwsClient` or
+/// `LogManager` - the client is rebuilt per-profile by `activate_profile` exactly as it
+/// is today, and the log is a single shared timeline across every workspace - so
+/// switching still triggers a (fast, backgrounded) credential rebuild, but the
+/// previously-fetched instance/function lists reappear instantly while that happens.
+#[derive(Debug, Clone)]
+pub struct Workspace {
+    pub profile_name: String,
+    /// Region resolved when this workspace was opened (see `open_or_switch_profile`);
+    /// read back by `App::activate_profile` ahead of `~/.aws/config`'s own per-profile
+    /// region, so a workspace keeps pointing at the region it was opened against even if
+    /// the profile's config-file region changes later.
+    pub region: Option<String>,
+    pub ec2_instances: Vec<Ec2Instance>,
+    pub ec2_selected: usize,
+    pub ec2_metrics: HashMap<String, MetricHistory>,
+    pub lambda_functions: Vec<LambdaFunction>,
+    pub lambda_selected: usize,
+    pub asg_groups: Vec<AutoScalingGroup>,
+    pub asg_selected: usize,
+    pub asg_instance_selected: usize,
+    pub scroll_offset: u16,
+    pub last_refresh: Option<DateTime<Utc>>,
+}
+
+impl Workspace {
+    pub fn new(profile_name: String, region: Option<String>) -> Self {
+        Self {
+            profile_name,
+            region,
+            ec2_instances: Vec::new(),
+            ec2_selected: 0,
+            ec2_metrics: HashMap::new(),
+            lambda_functions: Vec::new(),
+            lambda_selected: 0,
+            asg_groups: Vec::new(),
+            asg_selected: 0,
+            asg_instance_selected: 0,
+            scroll_offset: 0,
+            last_refresh: None,
+        }
+    }
+}
 
 /// Current screen/tab
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
@@ -14,10 +104,166 @@ pub enum Screen {
     Home,
     Ec2,
     Lambda,
+    AutoScaling,
     Logs,
     About,
 }
 
+/// Modal input layer on top of the screen-based event handling. `Search` routes plain
+/// character keys into `search_query` instead of their normal single-key shortcut
+/// meaning, live-filtering the EC2/Lambda lists and the log screen by substring/fuzzy
+/// match; the command palette (`Dialog::CommandPalette`, opened with `:`) already plays
+/// the equivalent role for dispatching named actions, so there is no separate `Command`
+/// variant here.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum InputMode {
+    #[default]
+    Normal,
+    Search,
+}
+
+/// Which action a schedule rule performs once it fires. Kept separate from
+/// `AutoStopRule` so `Once`/`Recurring` continue to describe only the *timing*, while
+/// this describes the *effect*.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScheduleAction {
+    Stop,
+    Start,
+}
+
+impl ScheduleAction {
+    pub fn toggled(&self) -> Self {
+        match self {
+            Self::Stop => Self::Start,
+            Self::Start => Self::Stop,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Stop => "Stop",
+            Self::Start => "Start",
+        }
+    }
+}
+
+/// A single schedule rule attached to an instance: either a one-shot action at a fixed
+/// instant, or a recurring rule that fires every matching weekday at a fixed hour:minute
+/// (UTC). `action` says whether it stops or starts the instance.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum AutoStopRule {
+    Once(DateTime<Utc>, ScheduleAction),
+    Recurring {
+        days: HashSet<Weekday>,
+        hour: u32,
+        minute: u32,
+        action: ScheduleAction,
+        /// Guards a rule from re-firing on every tick within the minute it matched.
+        /// Not persisted - a reloaded rule is simply allowed to fire again.
+        #[serde(skip)]
+        last_fired: Option<DateTime<Utc>>,
+    },
+}
+
+/// Path to the persisted auto-stop schedule file, alongside `settings.json`
+fn auto_stop_schedules_path() -> anyhow::Result<std::path::PathBuf> {
+    Ok(Settings::get_config_dir()?.join("auto_stop_schedules.json"))
+}
+
+/// Load persisted auto-stop schedules, or an empty list if none were saved yet (or the
+/// file is missing/corrupt)
+pub fn load_auto_stop_schedules() -> Vec<(String, AutoStopRule)> {
+    auto_stop_schedules_path()
+        .ok()
+        .filter(|p| p.exists())
+        .and_then(|p| std::fs::read_to_string(p).ok())
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+/// Persist the current auto-stop schedules to disk, so quitting the app doesn't
+/// silently cancel every scheduled stop
+pub fn save_auto_stop_schedules(schedules: &[(String, AutoStopRule)]) -> anyhow::Result<()> {
+    let path = auto_stop_schedules_path()?;
+    let contents = serde_json::to_string_pretty(schedules).context("Failed to serialize auto-stop schedules")?;
+    std::fs::write(path, contents).context("Failed to write auto-stop schedules")?;
+    Ok(())
+}
+
+/// Per-instance auto-stop lifecycle: `Active` means no pending stop on this instance,
+/// `Draining` is the cancellable warning window after a schedule fires and before the
+/// stop is actually issued, and `Stopping` marks that the stop API call has been sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstanceLifecycleState {
+    Active,
+    Draining,
+    Stopping,
+}
+
+/// Tracks one instance's position in the auto-stop lifecycle: its current state, when
+/// that state was entered, and how long the entry is allowed to stand before
+/// `check_scheduled_actions` garbage-collects it. The TTL exists so a stale entry - e.g.
+/// an instance that was manually stopped or terminated mid-drain - doesn't linger
+/// forever in `App::instance_lifecycle`.
+#[derive(Debug, Clone)]
+pub struct InstanceLifecycle {
+    pub state: InstanceLifecycleState,
+    pub last_action_date: DateTime<Utc>,
+    pub ttl: chrono::Duration,
+}
+
+impl InstanceLifecycle {
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        now.signed_duration_since(self.last_action_date) >= self.ttl
+    }
+}
+
+/// Which kind of rule the `ScheduleAutoStop` dialog is currently building
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AutoStopMode {
+    Duration,
+    Recurring,
+}
+
+impl AutoStopMode {
+    pub fn toggled(&self) -> Self {
+        match self {
+            Self::Duration => Self::Recurring,
+            Self::Recurring => Self::Duration,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Duration => "Duration",
+            Self::Recurring => "Recurring",
+        }
+    }
+}
+
+/// Weekday order the `ScheduleAutoStop` dialog cycles its day toggles in
+pub const AUTO_STOP_DAY_ORDER: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
+/// Which field of the `ScheduleAutoStop` dialog ←/→ currently edits. Mirrors the
+/// `SettingsField` next()/prev() pattern used by the Settings dialog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AutoStopField {
+    Mode,
+    Action,
+    Preset,
+    Day(Weekday),
+    Hour,
+    Minute,
+}
+
 /// Dialog/modal state
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Dialog {
@@ -27,10 +273,108 @@ pub enum Dialog {
     Settings,                 // Settings configuration
     SessionExpired,           // AWS session/token expired
     ConfirmTerminate(String), // instance_id
-    ScheduleAutoStop(String), // instance_id  
-    Alert(String),            // message
+    ScheduleAutoStop(String), // instance_id
+    ConfirmDetachInstance(String, String), // group_name, instance_id
+    Alert { summary: String, detail: Option<String>, severity: AlertSeverity }, // message, optional expandable error detail, warning/error color
     ConfigureAws,             // AWS configuration/login instructions
     Changelog,                // View Changelog
+    CommandPalette,           // Fuzzy command palette
+    InvokeLambda(String),     // function_name - enter a JSON payload
+    Assistant,                // Natural-language ops assistant prompt + proposed actions
+    AssistantError,           // Expandable error view for a failed assistant request
+    Ssh,                      // Select/unlock an SSH key to connect to the selected instance
+    SshAddKey,                // Add a new SSH key to the key store
+    LaunchInstance,           // Launch new EC2 instance(s)
+}
+
+/// Severity of an `Alert` dialog, driving whether its summary renders red or yellow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlertSeverity {
+    Warning,
+    Error,
+}
+
+impl Dialog {
+    /// A dismiss-only warning alert with no expandable detail (e.g. "no public IP to
+    /// connect to")
+    pub fn warning(summary: impl Into<String>) -> Self {
+        Dialog::Alert { summary: summary.into(), detail: None, severity: AlertSeverity::Warning }
+    }
+
+    /// An error alert with an expandable detail section (e.g. an AWS SDK error chain)
+    pub fn error(summary: impl Into<String>, detail: impl Into<String>) -> Self {
+        Dialog::Alert { summary: summary.into(), detail: Some(detail.into()), severity: AlertSeverity::Error }
+    }
+}
+
+/// Focused field in the "Add SSH key" form
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SshAddField {
+    Label,
+    Path,
+    KeyType,
+}
+
+impl SshAddField {
+    /// Get the next field
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Label => Self::Path,
+            Self::Path => Self::KeyType,
+            Self::KeyType => Self::Label,
+        }
+    }
+
+    /// Get the previous field
+    pub fn prev(&self) -> Self {
+        match self {
+            Self::Label => Self::KeyType,
+            Self::Path => Self::Label,
+            Self::KeyType => Self::Path,
+        }
+    }
+}
+
+/// Focused field in the "Launch instance" form. Mirrors `SshAddField`'s next()/prev()
+/// cycling, with `Count` additionally editable via ←/→ (see `modify_auto_stop_field`'s
+/// sibling handling in `handle_dialog_event`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LaunchInstanceField {
+    AmiId,
+    InstanceType,
+    KeyName,
+    SecurityGroup,
+    Name,
+    Spot,
+    Count,
+}
+
+impl LaunchInstanceField {
+    /// Get the next field
+    pub fn next(&self) -> Self {
+        match self {
+            Self::AmiId => Self::InstanceType,
+            Self::InstanceType => Self::KeyName,
+            Self::KeyName => Self::SecurityGroup,
+            Self::SecurityGroup => Self::Name,
+            Self::Name => Self::Spot,
+            Self::Spot => Self::Count,
+            Self::Count => Self::AmiId,
+        }
+    }
+
+    /// Get the previous field
+    pub fn prev(&self) -> Self {
+        match self {
+            Self::AmiId => Self::Count,
+            Self::InstanceType => Self::AmiId,
+            Self::KeyName => Self::InstanceType,
+            Self::SecurityGroup => Self::KeyName,
+            Self::Name => Self::SecurityGroup,
+            Self::Spot => Self::Name,
+            Self::Count => Self::Spot,
+        }
+    }
 }
 
 /// Toast notification
@@ -39,24 +383,127 @@ pub struct Toast {
     pub message: String,
     pub toast_type: ToastType,
     pub created_at: DateTime<Utc>,
+    pub ttl: chrono::Duration,
+    /// Set while this toast is the topmost (most recently added, so most visible) one -
+    /// see `App::cleanup_old_toasts` - freezing its countdown so a long error can
+    /// actually be read before it disappears.
+    pub paused_since: Option<DateTime<Utc>>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-#[allow(dead_code)] // Info may be used in future
+impl Toast {
+    /// Fraction of `ttl` remaining, from `1.0` (just shown) down to `0.0` (expired).
+    /// While paused, this is frozen at whatever it was when the pause began rather than
+    /// keeping pace with `now`.
+    pub fn remaining_fraction(&self, now: DateTime<Utc>) -> f64 {
+        let elapsed = self.paused_since.unwrap_or(now) - self.created_at;
+        let ttl_ms = self.ttl.num_milliseconds().max(1) as f64;
+        let remaining_ms = (self.ttl - elapsed).num_milliseconds().max(0) as f64;
+        (remaining_ms / ttl_ms).clamp(0.0, 1.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ToastType {
     Success,
     Error,
     Info,
 }
 
+impl ToastType {
+    /// How long a toast of this type stays up before expiring on its own. Errors get the
+    /// most time, since they're the ones most worth actually reading.
+    pub fn default_ttl(&self) -> chrono::Duration {
+        match self {
+            ToastType::Success => chrono::Duration::seconds(4),
+            ToastType::Info => chrono::Duration::seconds(5),
+            ToastType::Error => chrono::Duration::seconds(9),
+        }
+    }
+}
+
+/// Why an SSO login or profile-activation request produced no usable session. `Error`
+/// carries whatever the AWS CLI/SDK reported; the other three are request-lifecycle
+/// outcomes unrelated to AWS itself - see `App::request_seq` and `App::next_request_id`.
+#[derive(Debug, Clone)]
+pub enum SsoFailureReason {
+    Error(String),
+    Timeout,
+    Outdated,
+    Cancelled,
+}
+
 #[derive(Debug)]
 pub enum AsyncNotification {
     SsoLoginSuccess(String, String), // Message, ProfileName
-    SsoLoginFailed(String),
+    SsoLoginFailed(SsoFailureReason),
     ProfileActivated(crate::aws::AwsClient, String), // Client, ProfileName
-    ProfileActivationFailed(String),
+    ProfileActivationFailed(SsoFailureReason),
     Ec2Refreshed(Result<Vec<Ec2Instance>, String>),
-    LambdaRefreshed(Result<Vec<LambdaFunction>, String>),
+    /// `usize` is the `active_workspace` index captured when the refresh was spawned, so
+    /// `check_async_notifications` can drop a result that lands after the user has since
+    /// switched to a different workspace instead of overwriting its data with a stale
+    /// account/region's functions. Ec2Refreshed doesn't need this: it shares
+    /// `active_profile_task` with workspace/profile switching, so a switch aborts any
+    /// in-flight EC2 refresh outright rather than letting it land late.
+    LambdaRefreshed(usize, Result<Vec<LambdaFunction>, String>),
+    AsgRefreshed(usize, Result<Vec<AutoScalingGroup>, String>),
+    /// Result of a start/stop/terminate/reboot issued against a single instance, posted
+    /// back from the background task `App::spawn_instance_action` runs so the render loop
+    /// never blocks on the EC2 API call itself
+    InstanceActionDone { id: String, action: InstanceAction, result: Result<(), String> },
+    AssistantProposed(Vec<ProposedAction>),
+    AssistantFailed(AssistantError),
+    SettingsReloaded(Settings),
+    SettingsReloadFailed(String),
+    ConnectSucceeded(String, String), // Method ("SSM" or "SSH"), InstanceId
+    ConnectFailed(String),
+    /// Proactive renewal (see `App::check_session_renewal`) re-authenticated `profile`
+    /// ahead of expiry; the client still needs to be rebuilt against the fresh session
+    SessionRenewed(String),
+    SessionRenewalFailed(String),
+    /// Result of `App::launch_instance`'s background task, which calls either
+    /// `AwsClient::run_instances` or `AwsClient::request_spot_instance` depending on the
+    /// dialog's spot/on-demand toggle
+    InstanceLaunchDone { spot: bool, result: Result<Vec<String>, String> },
+    /// A command received over the IPC control socket (see `crate::ipc`), decoded and
+    /// ready to be applied to the active workspace's EC2 selection (if targeted at a
+    /// specific instance id) and then dispatched through `handle_event` like any other
+    /// `AppEvent`
+    IpcCommand(crate::ipc::DecodedCommand),
+    /// Result of posting a long-running-instance alert to the configured Slack webhook
+    /// (see `App::check_alerts`/`App::send_slack_alert`)
+    SlackAlertSent { instance_id: String, result: Result<(), String> },
+}
+
+/// Which EC2 API call an `InstanceActionDone` notification reports the outcome of
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstanceAction {
+    Start,
+    Stop,
+    Terminate,
+    Reboot,
+}
+
+impl InstanceAction {
+    /// Present-tense verb used in status messages/toasts, e.g. "Starting i-0123..."
+    pub fn verb(self) -> &'static str {
+        match self {
+            InstanceAction::Start => "Starting",
+            InstanceAction::Stop => "Stopping",
+            InstanceAction::Terminate => "Terminating",
+            InstanceAction::Reboot => "Rebooting",
+        }
+    }
+
+    /// Past-tense verb used once the notification comes back, e.g. "Started i-0123"
+    pub fn past_verb(self) -> &'static str {
+        match self {
+            InstanceAction::Start => "Started",
+            InstanceAction::Stop => "Stopped",
+            InstanceAction::Terminate => "Terminated",
+            InstanceAction::Reboot => "Rebooted",
+        }
+    }
 }
 
 /// Application state
@@ -72,17 +519,52 @@ pub struct App {
     // Status
     pub status_message: String,
     pub is_loading: bool,
+    pub spinner_frame: usize,
+    /// Render-loop tick counter, used to animate things like a blinking text-input caret
+    pub ui_tick: u64,
     pub scroll_offset: u16,
     
     // EC2 State
     pub ec2_instances: Vec<Ec2Instance>,
     pub ec2_selected: usize,
     pub ec2_table_state: TableState,
-    pub auto_stop_schedules: Vec<(String, DateTime<Utc>)>, // (instance_id, stop_time)
-    
+    pub auto_stop_schedules: Vec<(String, AutoStopRule)>,
+    pub ec2_metrics: HashMap<String, MetricHistory>,
+    /// Instance IDs mid-reboot, so a restart of the controller (or an EC2 refresh that
+    /// briefly observes `stopping`/`stopped`) doesn't lose track of the fact that the
+    /// instance is expected to come back up on its own. Cleared once a refresh reports
+    /// the instance `running` again.
+    pub rebooting_instances: HashSet<String>,
+    /// Per-instance auto-stop state machine driven by `check_scheduled_actions`: an entry
+    /// here means the instance is `Draining` (pending stop, still cancellable) or
+    /// `Stopping` (stop already issued). Absent entirely means `Active` - no pending
+    /// auto-stop action. Only Stop rules pass through this - Start rules fire directly.
+    /// See `InstanceLifecycle` for the TTL-based garbage collection.
+    pub instance_lifecycle: HashMap<String, InstanceLifecycle>,
+
     // Lambda State
     pub lambda_functions: Vec<LambdaFunction>,
     pub lambda_selected: usize,
+    pub lambda_invoke_payload: String,
+    pub lambda_invoke_type: LambdaInvocationType,
+    pub lambda_last_response: Option<String>,
+    pub lambda_recent_logs: Vec<String>,
+
+    // Auto Scaling Group State
+    pub asg_groups: Vec<AutoScalingGroup>,
+    pub asg_selected: usize,
+    pub asg_instance_selected: usize,
+
+    // Auto-stop scheduling input
+    pub auto_stop_mode: AutoStopMode,
+    pub auto_stop_action: ScheduleAction,
+    pub auto_stop_field: AutoStopField,
+    pub auto_stop_preset_index: usize,
+    pub auto_stop_duration_input: String,
+    pub auto_stop_duration_error: Option<String>,
+    pub auto_stop_days: HashSet<Weekday>,
+    pub auto_stop_hour: u32,
+    pub auto_stop_minute: u32,
     
     // Dialogs
     pub dialog: Dialog,
@@ -90,6 +572,19 @@ pub struct App {
     // Alerts
     pub pending_alerts: Vec<String>,
     pub last_alert_check: Option<DateTime<Utc>>,
+    /// Instance IDs a Slack alert has already been sent for on this threshold crossing,
+    /// so a repeat `check_alerts` pass (every 30s) doesn't spam the webhook; cleared for
+    /// an instance once it stops or gets an auto-stop schedule, so a later crossing
+    /// alerts again. See `App::check_alerts`.
+    pub slack_alerted_instances: HashSet<String>,
+
+    // Credential expiration
+    pub credential_expiration: Option<DateTime<Utc>>,
+    pub credential_expiry_alerted: bool,
+    /// Set while a proactive session-renewal attempt (see `check_session_renewal`) is
+    /// running, so the `session_renewal_threshold` window doesn't spawn a second `aws sso login`
+    /// before the first one has reported back
+    pub session_renewal_in_flight: bool,
     
     // Auto-refresh
     pub last_refresh: Option<DateTime<Utc>>,
@@ -107,6 +602,13 @@ pub struct App {
     pub settings: Settings,
     pub settings_selected_field: SettingsField,
     pub settings_draft: Option<Settings>, // Draft while editing
+    /// Named settings profiles (e.g. dev/staging/prod); `settings` always mirrors the
+    /// currently-active one, kept in sync by `save_settings`/profile switching
+    pub settings_profiles: SettingsStore,
+    /// Raw text buffer while typing a custom value (e.g. "90s", "6h") into the
+    /// RefreshInterval/AlertThreshold fields; `None` when that field isn't being edited
+    pub settings_value_edit: Option<String>,
+    pub settings_value_edit_error: Option<String>,
     
     // Logging
     pub log_manager: LogManager,
@@ -115,8 +617,119 @@ pub struct App {
     pub async_tx: std::sync::mpsc::Sender<AsyncNotification>,
     pub async_rx: std::sync::mpsc::Receiver<AsyncNotification>,
 
+    /// Receiving end of the `tracing` bridge (see `telemetry::log_bridge_layer`), drained
+    /// into `log_manager` each tick alongside `async_rx`
+    pub tracing_rx: std::sync::mpsc::Receiver<crate::logger::LogEntry>,
+
+    /// Handle to the reloadable `EnvFilter` gating both the rolling file layer and
+    /// `tracing_rx`'s bridge layer (see `telemetry::build_env_filter`), so
+    /// `toggle_verbose_tracing` can swap its directive without restarting the process.
+    pub tracing_filter_handle: tracing_subscriber::reload::Handle<tracing_subscriber::EnvFilter, tracing_subscriber::Registry>,
+    /// Whether `toggle_verbose_tracing` has bumped the filter above its default (`info`)
+    /// to `debug` for chatty-but-filtered deps like `hyper`/`aws_smithy_runtime`
+    pub verbose_tracing: bool,
+
     // AWS Profiles
-    pub available_profiles: Vec<String>,
+    pub available_profiles: Vec<AwsProfileInfo>,
     pub selected_profile_index: usize,
     pub active_profile_name: Option<String>,
+
+    /// Saved per-profile/region snapshots, so an operator can flip between e.g. prod and
+    /// staging without losing what was last loaded for either. Always has at least one
+    /// entry (created alongside the initial `AwsClient` in `App::new`). The active entry
+    /// is a snapshot-in-waiting, not a live mirror - see `Workspace` and
+    /// `App::switch_workspace`.
+    pub workspaces: Vec<Workspace>,
+    pub active_workspace: usize,
+
+    // Theme
+    pub theme: BTreeMap<Styles, Style>,
+    pub keymap: Keymap,
+    /// Kept alive for the app's lifetime so `Settings::watch`'s OS watch stays active;
+    /// `None` if the watcher failed to start (e.g. the config dir isn't watchable)
+    #[allow(dead_code)]
+    pub settings_watcher: Option<notify::RecommendedWatcher>,
+
+    // Command Palette
+    pub command_palette_query: String,
+    pub command_palette_selected: usize,
+
+    // Live search (`/`), filtering the EC2/Lambda lists and the log screen in place
+    pub input_mode: InputMode,
+    pub search_query: String,
+
+    // Ops Assistant
+    pub assistant_prompt: String,
+    pub assistant_busy: bool,
+    pub assistant_proposed: Option<Vec<ProposedAction>>,
+    pub assistant_selected: usize,
+    pub assistant_error: Option<AssistantError>,
+    pub assistant_error_expanded: bool,
+
+    // Alert dialog
+    pub alert_expanded: bool,
+
+    // SSH key management / in-process agent
+    pub ssh_key_store: SshKeyStore,
+    pub ssh_selected: usize,
+    pub ssh_unlocking: bool,
+    pub ssh_passphrase_input: String,
+    pub ssh_target_instance_id: Option<String>,
+    pub ssh_agent: Option<SshAgentHandle>,
+    pub ssh_add_label_input: String,
+    pub ssh_add_path_input: String,
+    pub ssh_add_key_type: SshKeyType,
+    pub ssh_add_field: SshAddField,
+
+    // Launch instance input
+    pub launch_field: LaunchInstanceField,
+    pub launch_ami_id: String,
+    pub launch_instance_type: String,
+    pub launch_key_name: String,
+    pub launch_security_group: String,
+    pub launch_name: String,
+    pub launch_spot: bool,
+    pub launch_count: u32,
+    pub launch_error: Option<String>,
+
+    /// Set by the background SIGINT/SIGTERM (or windows ctrl-c) listener spawned in
+    /// `App::new`; polled once per tick in `run()` so a signal triggers `App::shutdown`
+    /// instead of the process exiting with schedules still only in memory
+    pub shutdown_requested: std::sync::Arc<std::sync::atomic::AtomicBool>,
+
+    /// Monotonically increasing id of the most recently started SSO-login / profile-
+    /// activation request. Shared with spawned tasks via `Arc`, so an older task can
+    /// tell - after the fact - that a newer request superseded it and report
+    /// `SsoFailureReason::Outdated` instead of applying a stale result. See
+    /// `App::next_request_id`.
+    pub request_seq: std::sync::Arc<std::sync::atomic::AtomicU64>,
+
+    /// Handle to the most recently spawned profile-activation/data-refresh task, if any
+    /// is still in flight. `request_seq` alone only detects a stale result after the old
+    /// task has already run to completion; aborting it outright via this handle means a
+    /// rapid profile/region change doesn't leave a now-pointless `AwsClient::new` or
+    /// `list_ec2_instances` call running in the background at all. See
+    /// `App::spawn_profile_task`.
+    pub active_profile_task: Option<futures::future::AbortHandle>,
+
+    /// Same purpose as `active_profile_task`, but for the Lambda screen's own
+    /// `refresh_data` task - kept separate so refreshing Lambda functions doesn't abort
+    /// an unrelated in-flight EC2 or Auto Scaling Group refresh, and vice versa.
+    pub active_lambda_task: Option<futures::future::AbortHandle>,
+    /// Same purpose as `active_lambda_task`, for the Auto Scaling screen's refresh task
+    pub active_asg_task: Option<futures::future::AbortHandle>,
+
+    /// Drawn screen-space rect of the tab strip, refreshed every frame by `render_tabs`
+    /// so a mouse click can be hit-tested against it without threading layout state
+    /// through every render function's signature. `Cell` because render functions take
+    /// `&App`, not `&mut App`.
+    pub tab_strip_area: std::cell::Cell<ratatui::layout::Rect>,
+    /// Drawn screen-space rect of the EC2 instance table's row area (i.e. excluding its
+    /// header), refreshed every frame by `render_ec2`. Paired with `ec2_table_state`'s
+    /// scroll offset to turn a click's row into an instance index.
+    pub ec2_table_area: std::cell::Cell<ratatui::layout::Rect>,
+    /// Screen-space rect of each of the (up to 3) toasts drawn last frame, paired with
+    /// its index into `toasts` so a click can dismiss the right one; refreshed every
+    /// frame by `render_toasts`. Slot 0 is topmost (most recently added).
+    pub toast_areas: std::cell::Cell<[Option<(usize, ratatui::layout::Rect)>; 3]>,
 }
@@ -0,0 +1,302 @@
+//! Natural-language ops assistant
+//!
+//! Serializes the EC2/Lambda state currently shown in the TUI as structured
+//! context, sends it together with a free-text user prompt to a configurable
+//! LLM backend, and parses the reply into a list of concrete `ProposedAction`s
+//! the user can review and confirm before anything actually runs.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::aws::{Ec2Instance, LambdaFunction};
+
+/// Which LLM backend the assistant talks to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AssistantProviderKind {
+    OpenAi,
+    Anthropic,
+    Ollama,
+}
+
+impl Default for AssistantProviderKind {
+    fn default() -> Self {
+        Self::OpenAi
+    }
+}
+
+impl AssistantProviderKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::OpenAi => "OpenAI",
+            Self::Anthropic => "Anthropic",
+            Self::Ollama => "Ollama",
+        }
+    }
+
+    /// Name of the environment variable the API key is read from by default
+    pub fn default_api_key_env(&self) -> &'static str {
+        match self {
+            Self::OpenAi => "OPENAI_API_KEY",
+            Self::Anthropic => "ANTHROPIC_API_KEY",
+            Self::Ollama => "",
+        }
+    }
+
+    fn default_endpoint(&self) -> &'static str {
+        match self {
+            Self::OpenAi => "https://api.openai.com/v1/chat/completions",
+            Self::Anthropic => "https://api.anthropic.com/v1/messages",
+            Self::Ollama => "http://localhost:11434/api/chat",
+        }
+    }
+}
+
+/// A concrete AWS action the assistant proposed. Nothing here has executed yet;
+/// the caller must route it through a confirm dialog (e.g. `Dialog::ConfirmTerminate`
+/// for terminations) before calling the matching `App` method.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum ProposedAction {
+    Start { instance_id: String },
+    Stop { instance_id: String },
+    Terminate { instance_id: String },
+    ScheduleAutoStop { instance_id: String, minutes: i64 },
+}
+
+impl ProposedAction {
+    /// One-line human-readable description shown in the confirmable list
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Start { instance_id } => format!("Start instance {}", instance_id),
+            Self::Stop { instance_id } => format!("Stop instance {}", instance_id),
+            Self::Terminate { instance_id } => format!("Terminate instance {} (irreversible)", instance_id),
+            Self::ScheduleAutoStop { instance_id, minutes } => {
+                format!("Schedule auto-stop for {} in {} minutes", instance_id, minutes)
+            }
+        }
+    }
+}
+
+/// Structured snapshot of the resources currently shown in the TUI, sent to
+/// the assistant as context alongside the user's prompt
+#[derive(Debug, Serialize)]
+pub struct ResourceContext {
+    pub ec2_instances: Vec<ContextInstance>,
+    pub lambda_functions: Vec<ContextFunction>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ContextInstance {
+    pub id: String,
+    pub name: String,
+    pub instance_type: String,
+    pub state: String,
+    pub launch_time: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ContextFunction {
+    pub name: String,
+    pub runtime: String,
+    pub memory_mb: i32,
+    pub timeout_secs: i32,
+    pub description: String,
+}
+
+impl ResourceContext {
+    pub fn capture(instances: &[Ec2Instance], functions: &[LambdaFunction]) -> Self {
+        Self {
+            ec2_instances: instances
+                .iter()
+                .map(|i| ContextInstance {
+                    id: i.id.clone(),
+                    name: i.name.clone(),
+                    instance_type: i.instance_type.clone(),
+                    state: i.state.clone(),
+                    launch_time: i.launch_time.map(|t| t.to_rfc3339()),
+                })
+                .collect(),
+            lambda_functions: functions
+                .iter()
+                .map(|f| ContextFunction {
+                    name: f.name.clone(),
+                    runtime: f.runtime.clone(),
+                    memory_mb: f.memory,
+                    timeout_secs: f.timeout,
+                    description: f.description.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// An error from the assistant backend, kept in two parts so the UI can show a
+/// short summary with the full HTTP/status detail available on demand
+#[derive(Debug, Clone)]
+pub struct AssistantError {
+    pub summary: String,
+    pub detail: String,
+}
+
+impl std::fmt::Display for AssistantError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.summary)
+    }
+}
+
+/// Builds the provider-specific request body and extracts the reply text from
+/// the provider-specific response shape
+pub trait AssistantProvider {
+    fn endpoint(&self, endpoint_override: Option<&str>) -> String;
+    fn headers(&self, api_key: Option<&str>) -> Vec<(String, String)>;
+    fn build_body(&self, model: &str, system_prompt: &str, user_prompt: &str) -> Value;
+    fn extract_reply(&self, body: &Value) -> Option<String>;
+}
+
+struct OpenAiCompatibleProvider {
+    kind: AssistantProviderKind,
+}
+
+impl AssistantProvider for OpenAiCompatibleProvider {
+    fn endpoint(&self, endpoint_override: Option<&str>) -> String {
+        endpoint_override.unwrap_or_else(|| self.kind.default_endpoint()).to_string()
+    }
+
+    fn headers(&self, api_key: Option<&str>) -> Vec<(String, String)> {
+        match api_key {
+            Some(key) if !key.is_empty() => vec![("Authorization".to_string(), format!("Bearer {}", key))],
+            _ => Vec::new(),
+        }
+    }
+
+    fn build_body(&self, model: &str, system_prompt: &str, user_prompt: &str) -> Value {
+        json!({
+            "model": model,
+            "messages": [
+                { "role": "system", "content": system_prompt },
+                { "role": "user", "content": user_prompt },
+            ],
+        })
+    }
+
+    fn extract_reply(&self, body: &Value) -> Option<String> {
+        body["choices"][0]["message"]["content"].as_str().map(|s| s.to_string())
+            .or_else(|| body["message"]["content"].as_str().map(|s| s.to_string())) // Ollama's /api/chat shape
+    }
+}
+
+struct AnthropicProvider;
+
+impl AssistantProvider for AnthropicProvider {
+    fn endpoint(&self, endpoint_override: Option<&str>) -> String {
+        endpoint_override.unwrap_or_else(|| AssistantProviderKind::Anthropic.default_endpoint()).to_string()
+    }
+
+    fn headers(&self, api_key: Option<&str>) -> Vec<(String, String)> {
+        let mut headers = vec![("anthropic-version".to_string(), "2023-06-01".to_string())];
+        if let Some(key) = api_key.filter(|k| !k.is_empty()) {
+            headers.push(("x-api-key".to_string(), key.to_string()));
+        }
+        headers
+    }
+
+    fn build_body(&self, model: &str, system_prompt: &str, user_prompt: &str) -> Value {
+        json!({
+            "model": model,
+            "max_tokens": 1024,
+            "system": system_prompt,
+            "messages": [{ "role": "user", "content": user_prompt }],
+        })
+    }
+
+    fn extract_reply(&self, body: &Value) -> Option<String> {
+        body["content"][0]["text"].as_str().map(|s| s.to_string())
+    }
+}
+
+fn provider_for(kind: AssistantProviderKind) -> Box<dyn AssistantProvider + Send + Sync> {
+    match kind {
+        AssistantProviderKind::Anthropic => Box::new(AnthropicProvider),
+        kind => Box::new(OpenAiCompatibleProvider { kind }),
+    }
+}
+
+const SYSTEM_PROMPT: &str = r#"You are an ops assistant for an AWS EC2/Lambda TUI. \
+You will be given the current resource state as JSON context and a user request. \
+Reply with ONLY a JSON array of actions, each one of: \
+{"action":"start","instance_id":"..."}, {"action":"stop","instance_id":"..."}, \
+{"action":"terminate","instance_id":"..."}, \
+{"action":"schedule_auto_stop","instance_id":"...","minutes":N}. \
+Return an empty array if no action applies. Do not include any other text."#;
+
+/// Talks to a configured LLM backend to turn a natural-language ops request
+/// into a list of `ProposedAction`s
+pub struct AssistantClient {
+    provider: Box<dyn AssistantProvider + Send + Sync>,
+    endpoint_override: Option<String>,
+    model: String,
+    api_key: Option<String>,
+    http: reqwest::Client,
+}
+
+impl AssistantClient {
+    pub fn new(kind: AssistantProviderKind, endpoint_override: Option<String>, model: String, api_key: Option<String>) -> Self {
+        Self {
+            provider: provider_for(kind),
+            endpoint_override,
+            model,
+            api_key,
+            http: reqwest::Client::new(),
+        }
+    }
+
+    /// Send the prompt plus resource context to the backend and parse the
+    /// reply into a list of proposed actions
+    pub async fn propose_actions(&self, user_prompt: &str, context: &ResourceContext) -> Result<Vec<ProposedAction>, AssistantError> {
+        let context_json = serde_json::to_string(context).map_err(|e| AssistantError {
+            summary: "Failed to serialize resource context".to_string(),
+            detail: e.to_string(),
+        })?;
+        let full_prompt = format!("Context:\n{}\n\nRequest: {}", context_json, user_prompt);
+        let body = self.provider.build_body(&self.model, SYSTEM_PROMPT, &full_prompt);
+
+        let mut request = self.http.post(self.provider.endpoint(self.endpoint_override.as_deref()));
+        for (name, value) in self.provider.headers(self.api_key.as_deref()) {
+            request = request.header(name, value);
+        }
+
+        let response = request.json(&body).send().await.map_err(|e| AssistantError {
+            summary: format!("Request to {} failed", self.provider.endpoint(self.endpoint_override.as_deref())),
+            detail: e.to_string(),
+        })?;
+
+        let status = response.status();
+        let text = response.text().await.map_err(|e| AssistantError {
+            summary: "Failed to read response body".to_string(),
+            detail: e.to_string(),
+        })?;
+
+        if !status.is_success() {
+            return Err(AssistantError {
+                summary: format!("Assistant backend returned HTTP {}", status),
+                detail: text,
+            });
+        }
+
+        let parsed: Value = serde_json::from_str(&text).map_err(|e| AssistantError {
+            summary: "Failed to parse assistant response as JSON".to_string(),
+            detail: format!("{}\n\nRaw response:\n{}", e, text),
+        })?;
+
+        let reply = self.provider.extract_reply(&parsed).ok_or_else(|| AssistantError {
+            summary: "Assistant response did not contain a reply".to_string(),
+            detail: text.clone(),
+        })?;
+
+        serde_json::from_str(reply.trim()).map_err(|e| AssistantError {
+            summary: "Assistant reply was not a valid action list".to_string(),
+            detail: format!("{}\n\nReply:\n{}", e, reply),
+        })
+    }
+}
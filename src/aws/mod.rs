@@ -1,10 +1,30 @@
 //! AWS SDK wrapper for EC2 and Lambda operations
 
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
 use aws_config::meta::region::RegionProviderChain;
+use aws_sdk_autoscaling::Client as AutoScalingClient;
+use aws_sdk_cloudwatch::types::{Dimension, Statistic};
+use aws_sdk_cloudwatch::Client as CloudWatchClient;
+use aws_sdk_cloudwatchlogs::types::OrderBy;
+use aws_sdk_cloudwatchlogs::Client as CloudWatchLogsClient;
 use aws_sdk_ec2::Client as Ec2Client;
 use aws_sdk_lambda::Client as LambdaClient;
 use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sha1::{Digest, Sha1};
+use std::collections::HashMap;
+
+/// Max instance ids submitted per start/stop/terminate call before chunking into another batch
+const EC2_BATCH_LIMIT: usize = 20;
+
+/// Max instance ids submitted per attach/detach-instances Auto Scaling call
+const ASG_BATCH_LIMIT: usize = 20;
+
+/// How long to poll a spot instance request for fulfillment before giving up
+const SPOT_FULFILLMENT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(120);
+
+/// Delay between `describe_spot_instance_requests` polls while waiting on fulfillment
+const SPOT_FULFILLMENT_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(3);
 
 /// Represents an EC2 instance with relevant metadata
 #[derive(Debug, Clone)]
@@ -19,51 +39,257 @@ pub struct Ec2Instance {
     pub launch_time: Option<DateTime<Utc>>,
     #[allow(dead_code)] // Managed by scheduler
     pub auto_stop_scheduled: Option<DateTime<Utc>>,
+    /// Remote SSH user from this instance's `SshUser` tag, overriding
+    /// `Settings::ssh_default_user` for connections to it (e.g. a fleet where most
+    /// instances are `ec2-user` but a handful of Ubuntu boxes need `ubuntu`)
+    pub ssh_user_tag: Option<String>,
+}
+
+impl Ec2Instance {
+    /// The effective SSH username for connecting to this instance: `ssh_user_tag` if it
+    /// looks like a plausible username, otherwise `default`. Setting the `SshUser` tag
+    /// only requires `ec2:CreateTags`, a far weaker permission than SSH access to the
+    /// box, so it's validated rather than trusted outright - an unsanitized value flows
+    /// into a terminal command (`ssh::launch_terminal_command`) and an unvalidated one
+    /// could otherwise break out of it on any operator's machine that connects here.
+    pub fn ssh_user<'a>(&'a self, default: &'a str) -> &'a str {
+        match &self.ssh_user_tag {
+            Some(user) if is_valid_ssh_user(user) => user,
+            _ => default,
+        }
+    }
+}
+
+/// `^[A-Za-z0-9_-]{1,32}$` - conservative enough to cover every default cloud-image
+/// login name (`ec2-user`, `ubuntu`, `admin`, ...) while rejecting anything a shell or
+/// AppleScript could interpret as more than one opaque argument.
+fn is_valid_ssh_user(user: &str) -> bool {
+    !user.is_empty() && user.len() <= 32 && user.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'-')
+}
+
+/// Parameters for launching new EC2 instance(s) from the `LaunchInstance` dialog.
+/// `key_name`/`security_group`/`name` are optional (an empty string omits them from
+/// the request) since not every account requires a key pair or a non-default group,
+/// and a name tag is a convenience, not a requirement.
+#[derive(Debug, Clone)]
+pub struct LaunchInstanceParams {
+    pub ami_id: String,
+    pub instance_type: String,
+    pub key_name: String,
+    pub security_group: String,
+    pub name: String,
+    pub count: i32,
 }
 
 /// Represents a Lambda function
 #[derive(Debug, Clone)]
 pub struct LambdaFunction {
     pub name: String,
-    #[allow(dead_code)] // Reserved for detailed view
     pub runtime: String,
-    #[allow(dead_code)] // Reserved for detailed view
     pub memory: i32,
-    #[allow(dead_code)] // Reserved for detailed view
+    pub timeout: i32,
     pub last_modified: String,
-    #[allow(dead_code)] // Reserved for detailed view
     pub description: String,
 }
 
+/// How a Lambda invocation should be dispatched
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LambdaInvocationType {
+    /// Wait for the function to run and return its response
+    RequestResponse,
+    /// Fire-and-forget; the function runs asynchronously
+    Event,
+    /// Validate parameters and permissions without actually running the function
+    DryRun,
+}
+
+impl LambdaInvocationType {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::RequestResponse => "RequestResponse",
+            Self::Event => "Event",
+            Self::DryRun => "DryRun",
+        }
+    }
+
+    pub fn cycled(&self, delta: i32) -> Self {
+        let order = [Self::RequestResponse, Self::Event, Self::DryRun];
+        let current = order.iter().position(|t| t == self).unwrap_or(0) as i32;
+        let len = order.len() as i32;
+        let next = (current + delta).rem_euclid(len) as usize;
+        order[next]
+    }
+
+    fn as_sdk(&self) -> aws_sdk_lambda::types::InvocationType {
+        match self {
+            Self::RequestResponse => aws_sdk_lambda::types::InvocationType::RequestResponse,
+            Self::Event => aws_sdk_lambda::types::InvocationType::Event,
+            Self::DryRun => aws_sdk_lambda::types::InvocationType::DryRun,
+        }
+    }
+}
+
+/// Structured outcome of a single Lambda invocation
+#[derive(Debug, Clone)]
+pub struct LambdaInvokeResult {
+    pub status_code: i32,
+    /// Set when the function itself faulted (unhandled exception, etc.)
+    pub function_error: Option<String>,
+    pub payload: String,
+    /// Base64-decoded tail of the execution log, present only when requested and
+    /// returned (only for `RequestResponse` invocations)
+    pub log_tail: Option<String>,
+}
+
+/// Represents an Auto Scaling Group and its current capacity/membership
+#[derive(Debug, Clone)]
+pub struct AutoScalingGroup {
+    pub name: String,
+    pub desired_capacity: i32,
+    pub min_size: i32,
+    pub max_size: i32,
+    pub instance_ids: Vec<String>,
+    /// Number of member instances currently reporting "Healthy"
+    pub healthy_count: usize,
+}
+
+/// A single CloudWatch metric sample
+#[derive(Debug, Clone, Copy)]
+pub struct MetricPoint {
+    pub timestamp: DateTime<Utc>,
+    pub value: f64,
+}
+
+/// CPU/network CloudWatch history for one EC2 instance
+#[derive(Debug, Clone, Default)]
+pub struct InstanceMetrics {
+    pub cpu: Vec<MetricPoint>,
+    pub network_in: Vec<MetricPoint>,
+    pub network_out: Vec<MetricPoint>,
+}
+
+/// Sentinel `AwsProfileInfo::name` the profile picker uses for "don't pin to a named
+/// profile - resolve credentials from the standard provider chain instead" (environment
+/// variables, web identity, ECS container, or IMDS instance role). Not a real section in
+/// `~/.aws/credentials`/`~/.aws/config`, so it's prepended to the list by the caller
+/// rather than returned from `list_aws_profiles`.
+pub const DEFAULT_CREDENTIAL_CHAIN_PROFILE: &str = "(environment / instance role)";
+
+/// Which provider in the credential chain actually resolved the active session's
+/// credentials, so the UI can tell a user on a bastion or inside a pod where their
+/// access is coming from instead of just assuming it's a named SSO profile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CredentialSource {
+    /// A named profile from `~/.aws/credentials`/`~/.aws/config` (`AWS_PROFILE` or
+    /// `--profile`), static keys or SSO alike
+    NamedProfile,
+    /// Static `AWS_ACCESS_KEY_ID`/`AWS_SECRET_ACCESS_KEY` env vars, no profile involved
+    Environment,
+    /// `sts:AssumeRoleWithWebIdentity` via `AWS_ROLE_ARN`/`AWS_WEB_IDENTITY_TOKEN_FILE`
+    /// (e.g. an EKS pod's IRSA-mounted OIDC token)
+    WebIdentity,
+    /// The ECS task's container credentials endpoint (`AWS_CONTAINER_CREDENTIALS_RELATIVE_URI`)
+    EcsContainer,
+    /// IMDSv2 instance profile role - the chain's last resort, so this is also the
+    /// default assumption when nothing more specific matched
+    InstanceProfile,
+}
+
+impl CredentialSource {
+    /// Work out which provider will resolve credentials for a given profile selection,
+    /// by checking the same environment variables the default credential chain itself
+    /// consults, in the chain's own precedence order
+    pub fn detect(profile_override: Option<&str>) -> Self {
+        if profile_override.is_some() {
+            return Self::NamedProfile;
+        }
+        if std::env::var("AWS_ACCESS_KEY_ID").is_ok() && std::env::var("AWS_SECRET_ACCESS_KEY").is_ok() {
+            return Self::Environment;
+        }
+        if std::env::var("AWS_ROLE_ARN").is_ok() && std::env::var("AWS_WEB_IDENTITY_TOKEN_FILE").is_ok() {
+            return Self::WebIdentity;
+        }
+        if std::env::var("AWS_CONTAINER_CREDENTIALS_RELATIVE_URI").is_ok() || std::env::var("AWS_CONTAINER_CREDENTIALS_FULL_URI").is_ok() {
+            return Self::EcsContainer;
+        }
+        Self::InstanceProfile
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::NamedProfile => "Named Profile",
+            Self::Environment => "Environment",
+            Self::WebIdentity => "Web Identity",
+            Self::EcsContainer => "ECS Container",
+            Self::InstanceProfile => "Instance Role",
+        }
+    }
+
+    /// Short guidance for the `SessionExpired` dialog's quick-fix hint - "run `aws sso
+    /// login`" is meaningless advice when the expired credentials didn't come from an
+    /// SSO profile, so the hint branches on where they actually came from
+    pub fn refresh_hint(&self) -> &'static str {
+        match self {
+            Self::NamedProfile => "Select your profile above, press 'l' to launch browser login, then 'r' to retry",
+            Self::Environment => "Export fresh AWS_ACCESS_KEY_ID/AWS_SECRET_ACCESS_KEY values, then press 'r' to retry",
+            Self::WebIdentity => "The mounted web-identity token has expired or been rotated out - once it's replaced, press 'r' to retry",
+            Self::EcsContainer => "The ECS task's container credentials should auto-renew shortly - press 'r' to retry",
+            Self::InstanceProfile => "The instance profile role should auto-renew shortly - press 'r' to retry",
+        }
+    }
+}
+
 /// AWS Client wrapper
 #[derive(Debug, Clone)]
 pub struct AwsClient {
     ec2: Ec2Client,
     lambda: LambdaClient,
+    cloudwatch: CloudWatchClient,
+    logs: CloudWatchLogsClient,
+    autoscaling: AutoScalingClient,
     pub region: String,
+    pub credential_source: CredentialSource,
 }
 
 impl AwsClient {
-    /// Create a new AWS client using the default credential chain
-    pub async fn new(region_override: Option<&str>) -> Result<Self> {
+    /// Create a new AWS client using the default credential chain, optionally pinned to a
+    /// specific named profile (from `list_aws_profiles`) rather than whatever `AWS_PROFILE`
+    /// happens to be set to in the process environment. When `profile_override` is `None`,
+    /// credentials come from whichever non-profile provider in the standard chain - the
+    /// environment, IMDS instance role, a web-identity token, or the ECS container
+    /// endpoint - actually resolves them; see `CredentialSource::detect`.
+    pub async fn new(profile_override: Option<&str>, region_override: Option<&str>) -> Result<Self> {
         let region_provider = RegionProviderChain::first_try(region_override.map(|r| aws_config::Region::new(r.to_string())))
             .or_default_provider()
             .or_else("us-east-1");
 
-        let config = aws_config::from_env()
-            .region(region_provider)
-            .load()
-            .await;
+        let mut config_loader = aws_config::from_env().region(region_provider);
+        if let Some(profile) = profile_override {
+            config_loader = config_loader.profile_name(profile);
+        }
+        let config = config_loader.load().await;
 
         let region = config.region().map(|r| r.to_string()).unwrap_or_else(|| "us-east-1".to_string());
 
         Ok(Self {
             ec2: Ec2Client::new(&config),
             lambda: LambdaClient::new(&config),
+            cloudwatch: CloudWatchClient::new(&config),
+            logs: CloudWatchLogsClient::new(&config),
+            autoscaling: AutoScalingClient::new(&config),
             region,
+            credential_source: CredentialSource::detect(profile_override),
         })
     }
 
+    /// Rebuild the EC2/Lambda/CloudWatch clients in place against a (possibly new) profile
+    /// and region, so the TUI can switch accounts without restarting
+    #[allow(dead_code)] // Profile switching currently rebuilds off-thread and swaps the whole client; kept for direct callers
+    pub async fn rebuild(&mut self, profile_override: Option<&str>, region_override: Option<&str>) -> Result<()> {
+        *self = Self::new(profile_override, region_override).await?;
+        Ok(())
+    }
+
     // ─────────────────────────────────────────────────────────────────────────
     // EC2 Operations
     // ─────────────────────────────────────────────────────────────────────────
@@ -105,6 +331,13 @@ impl AwsClient {
                 let public_ip = instance.public_ip_address().map(|s| s.to_string());
                 let private_ip = instance.private_ip_address().map(|s| s.to_string());
 
+                let ssh_user_tag = instance
+                    .tags()
+                    .iter()
+                    .find(|t| t.key() == Some("SshUser"))
+                    .and_then(|t| t.value())
+                    .map(|v| v.to_string());
+
                 let launch_time = instance
                     .launch_time()
                     .and_then(|t| DateTime::from_timestamp(t.secs(), t.subsec_nanos()));
@@ -118,6 +351,7 @@ impl AwsClient {
                     private_ip,
                     launch_time,
                     auto_stop_scheduled: None, // Will be managed by scheduler
+                    ssh_user_tag,
                 });
             }
         }
@@ -127,37 +361,382 @@ impl AwsClient {
 
     /// Start an EC2 instance
     pub async fn start_instance(&self, instance_id: &str) -> Result<()> {
+        Self::first_failure(self.start_instances(&[instance_id.to_string()]).await?)
+    }
+
+    /// Stop an EC2 instance
+    pub async fn stop_instance(&self, instance_id: &str) -> Result<()> {
+        Self::first_failure(self.stop_instances(&[instance_id.to_string()]).await?)
+    }
+
+    /// Terminate an EC2 instance
+    pub async fn terminate_instance(&self, instance_id: &str) -> Result<()> {
+        Self::first_failure(self.terminate_instances(&[instance_id.to_string()]).await?)
+    }
+
+    /// Reboot an EC2 instance via the dedicated RebootInstances API, rather than issuing
+    /// a stop followed by a start. This lets EC2 handle the transition as a single
+    /// operation and keeps the instance's `running` state (and its public/private IPs)
+    /// intact throughout, instead of briefly tearing it down.
+    pub async fn reboot_instance(&self, instance_id: &str) -> Result<()> {
         self.ec2
-            .start_instances()
+            .reboot_instances()
             .instance_ids(instance_id)
             .send()
             .await
-            .map_err(|e| anyhow::anyhow!("Failed to start instance {}: {:?}", instance_id, e))?;
+            .map(|_| ())
+            .context("failed to reboot instance")
+    }
+
+    /// Start a batch of EC2 instances, chunked under the EC2 API's per-call instance cap.
+    /// Returns a per-instance success/failure map so a partial batch failure doesn't read
+    /// as one opaque error for instances that actually started fine
+    pub async fn start_instances(&self, ids: &[String]) -> Result<HashMap<String, Result<(), String>>> {
+        let mut results = HashMap::new();
+        for chunk in ids.chunks(EC2_BATCH_LIMIT) {
+            let outcome = self
+                .ec2
+                .start_instances()
+                .set_instance_ids(Some(chunk.to_vec()))
+                .send()
+                .await
+                .map(|_| ())
+                .map_err(|e| format!("{:?}", e));
+            for id in chunk {
+                results.insert(id.clone(), outcome.clone());
+            }
+        }
+        Ok(results)
+    }
+
+    /// Stop a batch of EC2 instances, chunked under the EC2 API's per-call instance cap.
+    /// Returns a per-instance success/failure map; see [`AwsClient::start_instances`]
+    pub async fn stop_instances(&self, ids: &[String]) -> Result<HashMap<String, Result<(), String>>> {
+        let mut results = HashMap::new();
+        for chunk in ids.chunks(EC2_BATCH_LIMIT) {
+            let outcome = self
+                .ec2
+                .stop_instances()
+                .set_instance_ids(Some(chunk.to_vec()))
+                .send()
+                .await
+                .map(|_| ())
+                .map_err(|e| format!("{:?}", e));
+            for id in chunk {
+                results.insert(id.clone(), outcome.clone());
+            }
+        }
+        Ok(results)
+    }
+
+    /// Terminate a batch of EC2 instances, chunked under the EC2 API's per-call instance cap.
+    /// Returns a per-instance success/failure map; see [`AwsClient::start_instances`]
+    pub async fn terminate_instances(&self, ids: &[String]) -> Result<HashMap<String, Result<(), String>>> {
+        let mut results = HashMap::new();
+        for chunk in ids.chunks(EC2_BATCH_LIMIT) {
+            let outcome = self
+                .ec2
+                .terminate_instances()
+                .set_instance_ids(Some(chunk.to_vec()))
+                .send()
+                .await
+                .map(|_| ())
+                .map_err(|e| format!("{:?}", e));
+            for id in chunk {
+                results.insert(id.clone(), outcome.clone());
+            }
+        }
+        Ok(results)
+    }
+
+    /// Collapse a per-instance result map back into a single `Result` for the singular
+    /// start/stop/terminate wrappers, which only ever submit one instance id
+    fn first_failure(results: HashMap<String, Result<(), String>>) -> Result<()> {
+        for result in results.into_values() {
+            result.map_err(|e| anyhow::anyhow!(e))?;
+        }
         Ok(())
     }
 
-    /// Stop an EC2 instance
-    pub async fn stop_instance(&self, instance_id: &str) -> Result<()> {
+    /// Launch new EC2 instance(s) from the `LaunchInstance` dialog's drafted parameters,
+    /// returning the id(s) AWS assigned them
+    pub async fn run_instances(&self, params: &LaunchInstanceParams) -> Result<Vec<String>> {
+        let mut request = self
+            .ec2
+            .run_instances()
+            .image_id(&params.ami_id)
+            .instance_type(aws_sdk_ec2::types::InstanceType::from(params.instance_type.as_str()))
+            .min_count(params.count)
+            .max_count(params.count);
+
+        if !params.key_name.is_empty() {
+            request = request.key_name(&params.key_name);
+        }
+        if !params.security_group.is_empty() {
+            request = request.security_groups(&params.security_group);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to launch EC2 instance(s): {:?}", e))?;
+
+        let ids: Vec<String> = response
+            .instances()
+            .iter()
+            .filter_map(|i| i.instance_id().map(String::from))
+            .collect();
+
+        if !params.name.is_empty() && !ids.is_empty() {
+            self.tag_instances(&ids, &params.name).await?;
+        }
+
+        Ok(ids)
+    }
+
+    /// Submit a one-time spot instance request for the `LaunchInstance` dialog's drafted
+    /// parameters, poll it until AWS either fulfills it with a running instance or it
+    /// fails/times out, then tag the resulting instance(s) the same way `run_instances`
+    /// does. No max price is set on the request, so AWS caps it at the on-demand price
+    /// for the instance type - the dialog doesn't expose a separate price field.
+    pub async fn request_spot_instance(&self, params: &LaunchInstanceParams) -> Result<Vec<String>> {
+        let mut launch_spec = aws_sdk_ec2::types::RequestSpotLaunchSpecification::builder()
+            .image_id(&params.ami_id)
+            .instance_type(aws_sdk_ec2::types::InstanceType::from(params.instance_type.as_str()));
+
+        if !params.key_name.is_empty() {
+            launch_spec = launch_spec.key_name(&params.key_name);
+        }
+        if !params.security_group.is_empty() {
+            launch_spec = launch_spec.security_groups(&params.security_group);
+        }
+
+        let response = self
+            .ec2
+            .request_spot_instances()
+            .instance_count(params.count)
+            .r#type(aws_sdk_ec2::types::SpotInstanceType::OneTime)
+            .launch_specification(launch_spec.build())
+            .send()
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to submit spot instance request: {:?}", e))?;
+
+        let request_ids: Vec<String> = response
+            .spot_instance_requests()
+            .iter()
+            .filter_map(|r| r.spot_instance_request_id().map(String::from))
+            .collect();
+        if request_ids.is_empty() {
+            return Err(anyhow!("Spot instance request returned no request id"));
+        }
+
+        let ids = self.wait_for_spot_fulfillment(&request_ids).await?;
+
+        if !params.name.is_empty() && !ids.is_empty() {
+            self.tag_instances(&ids, &params.name).await?;
+        }
+
+        Ok(ids)
+    }
+
+    /// Poll `describe_spot_instance_requests` until every request in `request_ids` has
+    /// either been fulfilled with an instance or failed/been cancelled, or
+    /// `SPOT_FULFILLMENT_TIMEOUT` elapses.
+    async fn wait_for_spot_fulfillment(&self, request_ids: &[String]) -> Result<Vec<String>> {
+        let deadline = std::time::Instant::now() + SPOT_FULFILLMENT_TIMEOUT;
+
+        loop {
+            let response = self
+                .ec2
+                .describe_spot_instance_requests()
+                .set_spot_instance_request_ids(Some(request_ids.to_vec()))
+                .send()
+                .await
+                .map_err(|e| anyhow::anyhow!("Failed to check spot instance request status: {:?}", e))?;
+
+            let requests = response.spot_instance_requests();
+            let failed = requests.iter().find(|r| {
+                matches!(
+                    r.state(),
+                    Some(aws_sdk_ec2::types::SpotInstanceState::Failed | aws_sdk_ec2::types::SpotInstanceState::Cancelled)
+                )
+            });
+            if let Some(failed) = failed {
+                let reason = failed.fault().and_then(|f| f.message()).unwrap_or("spot request failed or was cancelled");
+                return Err(anyhow!("Spot instance request not fulfilled: {}", reason));
+            }
+
+            let ids: Vec<String> = requests.iter().filter_map(|r| r.instance_id().map(String::from)).collect();
+            if ids.len() == request_ids.len() {
+                return Ok(ids);
+            }
+
+            if std::time::Instant::now() >= deadline {
+                // The request is still open on AWS's side and could fulfill later,
+                // launching an instance the user never confirmed - cancel it rather than
+                // leaving it outstanding. Best-effort: the timeout is the error that
+                // matters to the caller, so a failed cancellation doesn't mask it.
+                let _ = self.cancel_spot_instance_requests(request_ids).await;
+                return Err(anyhow!("Timed out waiting for spot instance request to be fulfilled; cancelled the outstanding request"));
+            }
+            tokio::time::sleep(SPOT_FULFILLMENT_POLL_INTERVAL).await;
+        }
+    }
+
+    /// Cancel one or more open spot instance requests, e.g. after
+    /// `wait_for_spot_fulfillment` gives up waiting on them
+    async fn cancel_spot_instance_requests(&self, request_ids: &[String]) -> Result<()> {
         self.ec2
-            .stop_instances()
-            .instance_ids(instance_id)
+            .cancel_spot_instance_requests()
+            .set_spot_instance_request_ids(Some(request_ids.to_vec()))
             .send()
             .await
-            .map_err(|e| anyhow::anyhow!("Failed to stop instance {}: {:?}", instance_id, e))?;
+            .map_err(|e| anyhow::anyhow!("Failed to cancel spot instance request(s): {:?}", e))?;
         Ok(())
     }
 
-    /// Terminate an EC2 instance
-    pub async fn terminate_instance(&self, instance_id: &str) -> Result<()> {
+    /// Apply a "Name" tag to one or more just-launched instances
+    async fn tag_instances(&self, instance_ids: &[String], name: &str) -> Result<()> {
         self.ec2
-            .terminate_instances()
-            .instance_ids(instance_id)
+            .create_tags()
+            .set_resources(Some(instance_ids.to_vec()))
+            .tags(aws_sdk_ec2::types::Tag::builder().key("Name").value(name).build())
+            .send()
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to tag instance(s): {:?}", e))?;
+        Ok(())
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // Auto Scaling Operations
+    // ─────────────────────────────────────────────────────────────────────────
+
+    /// List all Auto Scaling Groups
+    pub async fn list_auto_scaling_groups(&self) -> Result<Vec<AutoScalingGroup>> {
+        let response = self
+            .autoscaling
+            .describe_auto_scaling_groups()
+            .send()
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to describe Auto Scaling Groups: {:?}", e))?;
+
+        let groups = response
+            .auto_scaling_groups()
+            .iter()
+            .map(|g| {
+                let instance_ids: Vec<String> = g
+                    .instances()
+                    .iter()
+                    .filter_map(|i| i.instance_id().map(|id| id.to_string()))
+                    .collect();
+                let healthy_count = g
+                    .instances()
+                    .iter()
+                    .filter(|i| i.health_status() == Some("Healthy"))
+                    .count();
+
+                AutoScalingGroup {
+                    name: g.auto_scaling_group_name().unwrap_or("N/A").to_string(),
+                    desired_capacity: g.desired_capacity().unwrap_or(0),
+                    min_size: g.min_size().unwrap_or(0),
+                    max_size: g.max_size().unwrap_or(0),
+                    instance_ids,
+                    healthy_count,
+                }
+            })
+            .collect();
+
+        Ok(groups)
+    }
+
+    /// Set an Auto Scaling Group's desired capacity (e.g. scale to zero for cost savings)
+    pub async fn set_desired_capacity(&self, group_name: &str, desired_capacity: i32) -> Result<()> {
+        self.autoscaling
+            .update_auto_scaling_group()
+            .auto_scaling_group_name(group_name)
+            .desired_capacity(desired_capacity)
             .send()
             .await
-            .map_err(|e| anyhow::anyhow!("Failed to terminate instance {}: {:?}", instance_id, e))?;
+            .map_err(|e| anyhow::anyhow!("Failed to set desired capacity for {}: {:?}", group_name, e))?;
+        Ok(())
+    }
+
+    /// Attach instances to an Auto Scaling Group, chunked under the API's per-call instance cap
+    pub async fn attach_instances(&self, group_name: &str, ids: &[String]) -> Result<()> {
+        for chunk in ids.chunks(ASG_BATCH_LIMIT) {
+            self.autoscaling
+                .attach_instances()
+                .auto_scaling_group_name(group_name)
+                .set_instance_ids(Some(chunk.to_vec()))
+                .send()
+                .await
+                .map_err(|e| anyhow::anyhow!("Failed to attach instances to {}: {:?}", group_name, e))?;
+        }
+        Ok(())
+    }
+
+    /// Detach instances from an Auto Scaling Group, chunked under the API's per-call instance cap.
+    /// `decrement_desired_capacity` mirrors the console's "also decrement desired capacity" checkbox
+    pub async fn detach_instances(&self, group_name: &str, ids: &[String], decrement_desired_capacity: bool) -> Result<()> {
+        for chunk in ids.chunks(ASG_BATCH_LIMIT) {
+            self.autoscaling
+                .detach_instances()
+                .auto_scaling_group_name(group_name)
+                .set_instance_ids(Some(chunk.to_vec()))
+                .should_decrement_desired_capacity(decrement_desired_capacity)
+                .send()
+                .await
+                .map_err(|e| anyhow::anyhow!("Failed to detach instances from {}: {:?}", group_name, e))?;
+        }
         Ok(())
     }
 
+    // ─────────────────────────────────────────────────────────────────────────
+    // CloudWatch Operations
+    // ─────────────────────────────────────────────────────────────────────────
+
+    /// Fetch a single metric's recent datapoints for an instance (last 30 minutes, 1-minute period)
+    async fn get_metric_datapoints(&self, instance_id: &str, metric_name: &str) -> Result<Vec<MetricPoint>> {
+        let end = Utc::now();
+        let start = end - chrono::Duration::minutes(30);
+
+        let response = self
+            .cloudwatch
+            .get_metric_statistics()
+            .namespace("AWS/EC2")
+            .metric_name(metric_name)
+            .dimensions(Dimension::builder().name("InstanceId").value(instance_id).build())
+            .start_time(aws_sdk_cloudwatch::primitives::DateTime::from_secs(start.timestamp()))
+            .end_time(aws_sdk_cloudwatch::primitives::DateTime::from_secs(end.timestamp()))
+            .period(60)
+            .statistics(Statistic::Average)
+            .send()
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to fetch {} for {}: {:?}", metric_name, instance_id, e))?;
+
+        let mut points: Vec<MetricPoint> = response
+            .datapoints()
+            .iter()
+            .filter_map(|dp| {
+                let timestamp = dp.timestamp().and_then(|t| DateTime::from_timestamp(t.secs(), t.subsec_nanos()))?;
+                let value = dp.average()?;
+                Some(MetricPoint { timestamp, value })
+            })
+            .collect();
+        points.sort_by_key(|p| p.timestamp);
+
+        Ok(points)
+    }
+
+    /// Fetch CPU and network CloudWatch metrics for an instance
+    pub async fn get_instance_metrics(&self, instance_id: &str) -> Result<InstanceMetrics> {
+        Ok(InstanceMetrics {
+            cpu: self.get_metric_datapoints(instance_id, "CPUUtilization").await?,
+            network_in: self.get_metric_datapoints(instance_id, "NetworkIn").await?,
+            network_out: self.get_metric_datapoints(instance_id, "NetworkOut").await?,
+        })
+    }
+
     // ─────────────────────────────────────────────────────────────────────────
     // Lambda Operations
     // ─────────────────────────────────────────────────────────────────────────
@@ -177,6 +756,7 @@ impl AwsClient {
                 name: f.function_name().unwrap_or("N/A").to_string(),
                 runtime: f.runtime().map(|r| r.as_str()).unwrap_or("N/A").to_string(),
                 memory: f.memory_size().unwrap_or(0),
+                timeout: f.timeout().unwrap_or(0),
                 last_modified: f.last_modified().unwrap_or("N/A").to_string(),
                 description: f.description().unwrap_or("").to_string(),
             })
@@ -185,12 +765,28 @@ impl AwsClient {
         Ok(functions)
     }
 
-    /// Invoke a Lambda function
-    #[allow(dead_code)] // Reserved for future Lambda invocation feature
-    pub async fn invoke_lambda(&self, function_name: &str) -> Result<String> {
-        let response = self.lambda
+    /// Invoke a Lambda function with a JSON payload under the given invocation type,
+    /// requesting the tail of the execution log for synchronous invocations
+    pub async fn invoke_lambda(
+        &self,
+        function_name: &str,
+        payload: &str,
+        invocation_type: LambdaInvocationType,
+    ) -> Result<LambdaInvokeResult> {
+        let blob = aws_smithy_types::Blob::new(payload.as_bytes().to_vec());
+
+        let mut request = self.lambda
             .invoke()
             .function_name(function_name)
+            .invocation_type(invocation_type.as_sdk())
+            .payload(blob);
+
+        // The log tail is only ever populated for synchronous invocations
+        if invocation_type == LambdaInvocationType::RequestResponse {
+            request = request.log_type(aws_sdk_lambda::types::LogType::Tail);
+        }
+
+        let response = request
             .send()
             .await
             .context(format!("Failed to invoke Lambda function {}", function_name))?;
@@ -199,31 +795,321 @@ impl AwsClient {
             .map(|p| String::from_utf8_lossy(p.as_ref()).to_string())
             .unwrap_or_else(|| "No response payload".to_string());
 
-        Ok(payload)
+        let log_tail = response.log_result()
+            .and_then(|encoded| aws_smithy_types::base64::decode(encoded).ok())
+            .map(|bytes| String::from_utf8_lossy(&bytes).to_string());
+
+        Ok(LambdaInvokeResult {
+            status_code: response.status_code(),
+            function_error: response.function_error().map(String::from),
+            payload,
+            log_tail,
+        })
     }
+
+    /// Fetch the tail of the most recent CloudWatch Logs stream for a Lambda function
+    pub async fn get_lambda_logs(&self, function_name: &str, limit: i32) -> Result<Vec<String>> {
+        let log_group = format!("/aws/lambda/{}", function_name);
+
+        let streams = self.logs
+            .describe_log_streams()
+            .log_group_name(&log_group)
+            .order_by(OrderBy::LastEventTime)
+            .descending(true)
+            .limit(1)
+            .send()
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to list log streams for {}: {:?}", function_name, e))?;
+
+        let Some(stream_name) = streams.log_streams().first().and_then(|s| s.log_stream_name()) else {
+            return Ok(Vec::new());
+        };
+
+        let events = self.logs
+            .get_log_events()
+            .log_group_name(&log_group)
+            .log_stream_name(stream_name)
+            .limit(limit)
+            .send()
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to fetch log events for {}: {:?}", function_name, e))?;
+
+        Ok(events.events().iter().filter_map(|e| e.message().map(|m| m.to_string())).collect())
+    }
+}
+
+/// A discovered AWS CLI profile, merged from `~/.aws/credentials` and `~/.aws/config`
+#[derive(Debug, Clone)]
+pub struct AwsProfileInfo {
+    pub name: String,
+    /// Region resolved from the profile's `[profile <name>]` (or `[default]`) section in
+    /// `~/.aws/config`, falling back to `AWS_DEFAULT_REGION` when the profile doesn't set one;
+    /// `AWS_REGION`, when set, overrides it outright (see `list_aws_profiles`)
+    pub region: Option<String>,
+    /// Whether the profile's `~/.aws/config` section has `sso_start_url` or `sso_session`,
+    /// so profile-picker dialogs can badge SSO profiles apart from static-key ones
+    pub sso: bool,
+}
+
+/// A profile section parsed out of an AWS CLI INI file, before merging credentials/config
+struct ParsedIniProfile {
+    name: String,
+    region: Option<String>,
+    sso: bool,
+}
+
+/// Parse profile section headers and the `region`/`sso_start_url`/`sso_session` keys out of
+/// an AWS CLI INI file.
+///
+/// `credentials` sections are bare profile names (`[work]`); `config` sections are prefixed
+/// with `profile ` (`[profile work]`), except `[default]` which is unprefixed in both files.
+fn parse_ini_profiles(content: &str, is_config_file: bool) -> Vec<ParsedIniProfile> {
+    let mut profiles: Vec<ParsedIniProfile> = Vec::new();
+    let mut current: Option<String> = None;
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.starts_with('[') && line.ends_with(']') {
+            let header = &line[1..line.len() - 1];
+            let name = if header == "default" {
+                "default".to_string()
+            } else if is_config_file {
+                header.strip_prefix("profile ").map(str::trim).unwrap_or(header).to_string()
+            } else {
+                header.to_string()
+            };
+            profiles.push(ParsedIniProfile { name: name.clone(), region: None, sso: false });
+            current = Some(name);
+        } else if let Some((key, value)) = line.split_once('=') {
+            let key = key.trim();
+            if let Some(name) = &current {
+                if let Some(entry) = profiles.iter_mut().rev().find(|p| &p.name == name) {
+                    match key {
+                        "region" => entry.region = Some(value.trim().to_string()),
+                        "sso_start_url" | "sso_session" => entry.sso = true,
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+
+    profiles
 }
 
-/// List available AWS profiles from ~/.aws/config
-pub fn list_aws_profiles() -> Result<Vec<String>> {
+/// List available AWS profiles from `~/.aws/credentials` and `~/.aws/config`, merging the
+/// two and resolving each profile's region (`AWS_REGION` overrides every profile
+/// outright; `AWS_DEFAULT_REGION` only fills in profiles that don't set one) and SSO status
+pub fn list_aws_profiles() -> Result<Vec<AwsProfileInfo>> {
     let home = dirs::home_dir().context("Could not find home directory")?;
-    let config_path = home.join(".aws").join("config");
-    
-    if !config_path.exists() {
-        return Ok(Vec::new());
-    }
-    
-    let content = std::fs::read_to_string(config_path)?;
-    let mut profiles = Vec::new();
-    
+    let aws_dir = home.join(".aws");
+    let region_override = std::env::var("AWS_REGION").ok();
+    let default_region = std::env::var("AWS_DEFAULT_REGION").ok();
+
+    let credentials_path = std::env::var("AWS_SHARED_CREDENTIALS_FILE")
+        .map(std::path::PathBuf::from)
+        .unwrap_or_else(|_| aws_dir.join("credentials"));
+    let config_path = std::env::var("AWS_CONFIG_FILE")
+        .map(std::path::PathBuf::from)
+        .unwrap_or_else(|_| aws_dir.join("config"));
+
+    let mut merged: Vec<ParsedIniProfile> = Vec::new();
+
+    for (path, is_config_file) in [(credentials_path, false), (config_path, true)] {
+        if !path.exists() {
+            continue;
+        }
+        let content = std::fs::read_to_string(&path)?;
+        for parsed in parse_ini_profiles(&content, is_config_file) {
+            match merged.iter_mut().find(|p| p.name == parsed.name) {
+                Some(entry) => {
+                    entry.region = entry.region.take().or(parsed.region);
+                    entry.sso = entry.sso || parsed.sso;
+                }
+                None => merged.push(parsed),
+            }
+        }
+    }
+
+    Ok(merged
+        .into_iter()
+        .map(|p| AwsProfileInfo {
+            name: p.name,
+            region: region_override.clone().or(p.region).or_else(|| default_region.clone()),
+            sso: p.sso,
+        })
+        .collect())
+}
+
+/// Find the value of the first matching key inside a named INI section (`[section]`)
+fn ini_value_in_section(content: &str, section: &str, keys: &[&str]) -> Option<String> {
+    let mut in_section = false;
     for line in content.lines() {
         let line = line.trim();
-        if line.starts_with("[profile ") && line.ends_with(']') {
-            let profile_name = line.trim_start_matches("[profile ").trim_end_matches(']');
-            profiles.push(profile_name.to_string());
-        } else if line == "[default]" {
-            profiles.push("default".to_string());
+        if line.starts_with('[') && line.ends_with(']') {
+            in_section = &line[1..line.len() - 1] == section;
+            continue;
+        }
+        if in_section {
+            if let Some((key, value)) = line.split_once('=') {
+                if keys.contains(&key.trim()) {
+                    return Some(value.trim().to_string());
+                }
+            }
         }
     }
-    
-    Ok(profiles)
+    None
+}
+
+/// The `sso_session` name or, failing that, the legacy `sso_start_url` configured for a
+/// profile's `[profile <name>]` (or `[default]`) section in `~/.aws/config` - whichever
+/// one the AWS CLI keys that profile's SSO token cache entry off of. `None` if the
+/// profile isn't SSO-based or the config file can't be read.
+fn sso_cache_key_for_profile(profile_name: &str) -> Option<String> {
+    let home = dirs::home_dir()?;
+    let content = std::fs::read_to_string(home.join(".aws").join("config")).ok()?;
+    let section = if profile_name == "default" { "default".to_string() } else { format!("profile {}", profile_name) };
+    ini_value_in_section(&content, &section, &["sso_session"]).or_else(|| ini_value_in_section(&content, &section, &["sso_start_url"]))
+}
+
+/// The SSO token cache file a session name/start URL resolves to: the AWS CLI names each
+/// cache entry after the SHA1 hex digest of that key
+fn sso_cache_file_for_key(cache_dir: &std::path::Path, key: &str) -> std::path::PathBuf {
+    let mut hasher = Sha1::new();
+    hasher.update(key.as_bytes());
+    let hex: String = hasher.finalize().iter().map(|b| format!("{:02x}", b)).collect();
+    cache_dir.join(format!("{}.json", hex))
+}
+
+/// Parse an SSO token cache file's `expiresAt` field
+fn parse_sso_cache_file(path: &std::path::Path) -> Option<DateTime<Utc>> {
+    let content = std::fs::read_to_string(path).ok()?;
+    let value = serde_json::from_str::<Value>(&content).ok()?;
+    let expires_at = value.get("expiresAt").and_then(|v| v.as_str())?;
+    DateTime::parse_from_rfc3339(expires_at).ok().map(|d| d.with_timezone(&Utc))
+}
+
+/// Resolve the active profile's temporary-credential expiration, checking the
+/// `aws_session_expiration`/`x_security_token_expires` keys in `~/.aws/credentials`
+/// first, then the SSO token cache entry for that profile's `sso_session`/
+/// `sso_start_url` under `~/.aws/sso/cache/*.json` (the same filename-hashing scheme
+/// `aws sso login` and Starship's AWS module use), falling back to the soonest-expiring
+/// cache entry of any kind if the profile's own cache key can't be determined
+pub fn get_credential_expiration(profile_name: &str) -> Option<DateTime<Utc>> {
+    let home = dirs::home_dir()?;
+
+    if let Ok(content) = std::fs::read_to_string(home.join(".aws").join("credentials")) {
+        if let Some(raw) = ini_value_in_section(
+            &content,
+            profile_name,
+            &["aws_session_expiration", "x_security_token_expires"],
+        ) {
+            if let Ok(parsed) = DateTime::parse_from_rfc3339(&raw) {
+                return Some(parsed.with_timezone(&Utc));
+            }
+        }
+    }
+
+    let cache_dir = home.join(".aws").join("sso").join("cache");
+
+    if let Some(key) = sso_cache_key_for_profile(profile_name) {
+        if let Some(expiry) = parse_sso_cache_file(&sso_cache_file_for_key(&cache_dir, &key)) {
+            return Some(expiry);
+        }
+    }
+
+    let entries = std::fs::read_dir(&cache_dir).ok()?;
+    let mut earliest: Option<DateTime<Utc>> = None;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("json") {
+            continue;
+        }
+        let Some(parsed) = parse_sso_cache_file(&path) else { continue };
+        earliest = Some(earliest.map_or(parsed, |e: DateTime<Utc>| e.min(parsed)));
+    }
+    earliest
+}
+
+/// Render a signed duration the way starship's `render_time` does: break the delta into
+/// days/hours/minutes/seconds, drop zero-valued leading units, and join the two largest
+/// non-zero units (e.g. `1h3m`, `42m`, `5s`).
+fn humanize_duration(delta: chrono::Duration) -> String {
+    let total_seconds = delta.num_seconds().abs();
+    let units = [
+        (total_seconds / 86_400, "d"),
+        ((total_seconds % 86_400) / 3_600, "h"),
+        ((total_seconds % 3_600) / 60, "m"),
+        (total_seconds % 60, "s"),
+    ];
+
+    let rendered: String = units
+        .iter()
+        .filter(|(value, _)| *value != 0)
+        .take(2)
+        .map(|(value, unit)| format!("{}{}", value, unit))
+        .collect();
+
+    if rendered.is_empty() { "0s".to_string() } else { rendered }
+}
+
+/// Compact relative-time string for a credential expiration ("expires in 42m", "expired 5m
+/// ago"), paired with whether it's already expired so callers can pick an urgent color.
+pub fn format_expiry(expiration: DateTime<Utc>) -> (String, bool) {
+    let remaining = expiration.signed_duration_since(Utc::now());
+    let is_expired = remaining.num_seconds() <= 0;
+    let text = if is_expired {
+        format!("expired {} ago", humanize_duration(remaining))
+    } else {
+        format!("expires in {}", humanize_duration(remaining))
+    };
+    (text, is_expired)
+}
+
+/// Rough on-demand us-east-1 hourly rate (USD) for the instance types this tool's users
+/// actually launch, for the "estimated cost" line in a long-running-instance alert. Not
+/// meant to match a real bill - AWS pricing varies by region and changes over time - just
+/// to give a ballpark. Unrecognized instance types (anything outside the common
+/// general-purpose/compute/memory families) return `None` rather than guess.
+pub fn estimate_hourly_cost_usd(instance_type: &str) -> Option<f64> {
+    let rate = match instance_type {
+        "t2.micro" | "t3.micro" | "t3a.micro" => 0.0104,
+        "t2.small" | "t3.small" | "t3a.small" => 0.0208,
+        "t2.medium" | "t3.medium" | "t3a.medium" => 0.0416,
+        "t2.large" | "t3.large" | "t3a.large" => 0.0832,
+        "m5.large" | "m6i.large" => 0.096,
+        "m5.xlarge" | "m6i.xlarge" => 0.192,
+        "m5.2xlarge" | "m6i.2xlarge" => 0.384,
+        "c5.large" | "c6i.large" => 0.085,
+        "c5.xlarge" | "c6i.xlarge" => 0.17,
+        "r5.large" | "r6i.large" => 0.126,
+        "r5.xlarge" | "r6i.xlarge" => 0.252,
+        _ => return None,
+    };
+    Some(rate)
+}
+
+/// Run `aws sso login` (optionally scoped to `profile`) to completion, blocking the
+/// calling thread. Shared by `App::login_with_sso` (which runs this on a background
+/// task so the TUI stays responsive) and the headless CLI's `login` subcommand (which
+/// has nothing else to stay responsive to, so it just calls this directly).
+pub fn run_sso_login(profile: Option<&str>) -> Result<()> {
+    let mut cmd = std::process::Command::new("aws");
+    cmd.arg("sso").arg("login");
+    if let Some(p) = profile {
+        cmd.arg("--profile").arg(p);
+    }
+
+    let output = cmd.output().context("Failed to run 'aws sso login'")?;
+    if output.status.success() {
+        return Ok(());
+    }
+
+    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+    let err_msg = if stderr.trim().is_empty() {
+        String::from_utf8_lossy(&output.stdout).to_string()
+    } else {
+        stderr
+    };
+    Err(anyhow!(err_msg))
 }
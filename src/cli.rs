@@ -0,0 +1,206 @@
+//! Headless CLI entry point: `clap`-derived subcommands that drive the same
+//! `AwsClient` calls, profile resolution, and auto-stop schedule persistence as the TUI,
+//! so scripts and CI can exercise the same behavior without an interactive terminal.
+
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use clap::{Parser, Subcommand};
+use serde::Serialize;
+
+use crate::app::state::{load_auto_stop_schedules, save_auto_stop_schedules, AutoStopRule, ScheduleAction};
+use crate::aws::{self, AwsClient, Ec2Instance};
+use crate::settings::parse_duration_secs;
+
+/// AWS Cloud Controller
+#[derive(Debug, Parser)]
+#[command(name = "aws-cloud-controller", about = "Terminal UI for managing AWS resources")]
+pub struct Cli {
+    /// AWS profile to use for this invocation, overriding the default/active one
+    #[arg(long, global = true)]
+    pub profile: Option<String>,
+    /// Emit machine-readable JSON instead of a plain table
+    #[arg(long, global = true)]
+    pub json: bool,
+    /// Render the interactive TUI inline in the current scrollback, using a fixed
+    /// viewport of this many rows, instead of taking over the whole screen with the
+    /// alternate screen buffer. Ignored if a subcommand is given.
+    #[arg(long, value_name = "ROWS")]
+    pub inline: Option<u16>,
+    /// Headless subcommand. Omit entirely to launch the interactive TUI instead.
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// List resources of a given kind
+    List {
+        #[command(subcommand)]
+        resource: ListResource,
+    },
+    /// Stop a running EC2 instance
+    Stop { id: String },
+    /// Reboot an EC2 instance in place
+    Reboot { id: String },
+    /// Schedule an EC2 instance to stop after a duration has elapsed
+    ScheduleStop {
+        id: String,
+        /// Duration until the stop fires, e.g. "1h", "30m", "90s"
+        #[arg(long = "in")]
+        r#in: String,
+    },
+    /// Trigger an AWS SSO login
+    Login,
+    /// Send a command to a running TUI instance over its IPC control socket (see
+    /// `crate::ipc`), e.g. `aws-cloud-controller msg stop i-0abc...`
+    Msg {
+        #[command(subcommand)]
+        command: MsgCommand,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum MsgCommand {
+    /// Start an EC2 instance
+    Start { id: String },
+    /// Stop an EC2 instance
+    Stop { id: String },
+    /// Terminate an EC2 instance (still goes through the TUI's confirmation dialog)
+    Terminate { id: String },
+    /// Refresh whichever resource list is on screen
+    Refresh,
+    /// Switch screens: home, ec2, lambda, asg, about, or logs
+    Navigate { screen: String },
+}
+
+impl From<MsgCommand> for crate::ipc::IpcCommand {
+    fn from(cmd: MsgCommand) -> Self {
+        match cmd {
+            MsgCommand::Start { id } => crate::ipc::IpcCommand::Start { id },
+            MsgCommand::Stop { id } => crate::ipc::IpcCommand::Stop { id },
+            MsgCommand::Terminate { id } => crate::ipc::IpcCommand::Terminate { id },
+            MsgCommand::Refresh => crate::ipc::IpcCommand::Refresh,
+            MsgCommand::Navigate { screen } => crate::ipc::IpcCommand::Navigate { screen },
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ListResource {
+    /// List EC2 instances
+    Ec2,
+}
+
+#[derive(Serialize)]
+struct Ec2InstanceJson<'a> {
+    id: &'a str,
+    name: &'a str,
+    instance_type: &'a str,
+    state: &'a str,
+    public_ip: Option<&'a str>,
+}
+
+/// Resolve which profile a headless command should run against: the `--profile`
+/// override if given, otherwise whatever `AWS_PROFILE` already has set (mirroring
+/// `App::new`'s precedence, minus the `settings.default_profile` fallback - a
+/// non-interactive invocation is expected to say explicitly which profile it wants).
+fn resolve_profile(cli: &Cli) -> Option<String> {
+    cli.profile.clone().or_else(|| std::env::var("AWS_PROFILE").ok())
+}
+
+/// Print a session-expiry warning to stderr if the resolved profile's credentials are
+/// expired or about to be, reusing the exact same check the TUI's credential-expiry
+/// alert is built on.
+fn warn_if_session_expiring(profile: Option<&str>) {
+    let Some(profile) = profile else { return };
+    let Some(expiration) = aws::get_credential_expiration(profile) else { return };
+    let (text, is_expired) = aws::format_expiry(expiration);
+    let remaining = expiration.signed_duration_since(Utc::now());
+    if is_expired || remaining <= chrono::Duration::minutes(5) {
+        eprintln!("warning: profile '{}' session {} - run 'login' to refresh", profile, text);
+    }
+}
+
+/// Run a parsed CLI invocation to completion. Returns `Ok(true)` if a subcommand was
+/// handled (the caller should exit without launching the TUI), `Ok(false)` if no
+/// subcommand was given (the caller should fall through to the interactive TUI).
+pub async fn run(cli: Cli) -> Result<bool> {
+    let Some(command) = cli.command else {
+        return Ok(false);
+    };
+
+    let profile = resolve_profile(&cli);
+    warn_if_session_expiring(profile.as_deref());
+
+    match command {
+        Command::List { resource: ListResource::Ec2 } => {
+            let client = AwsClient::new(profile.as_deref(), None).await?;
+            let instances = client.list_ec2_instances().await?;
+            print_ec2_instances(&instances, cli.json);
+        }
+        Command::Stop { id } => {
+            let client = AwsClient::new(profile.as_deref(), None).await?;
+            client.stop_instance(&id).await?;
+            println!("Stopped {}", id);
+        }
+        Command::Reboot { id } => {
+            let client = AwsClient::new(profile.as_deref(), None).await?;
+            client.reboot_instance(&id).await?;
+            println!("Rebooted {}", id);
+        }
+        Command::ScheduleStop { id, r#in } => {
+            let secs = parse_duration_secs(&r#in).ok_or_else(|| anyhow!("invalid duration: '{}' (try e.g. \"1h\", \"30m\", \"90s\")", r#in))?;
+            let stop_time = Utc::now() + chrono::Duration::seconds(secs as i64);
+
+            let mut schedules = load_auto_stop_schedules();
+            schedules.retain(|(existing_id, _)| existing_id != &id);
+            schedules.push((id.clone(), AutoStopRule::Once(stop_time, ScheduleAction::Stop)));
+            save_auto_stop_schedules(&schedules)?;
+
+            println!("Scheduled stop for {} at {} UTC", id, stop_time.format("%Y-%m-%d %H:%M:%S"));
+        }
+        Command::Login => {
+            aws::run_sso_login(profile.as_deref())?;
+            println!("SSO login successful{}", profile.as_deref().map(|p| format!(" (profile: {})", p)).unwrap_or_default());
+        }
+        Command::Msg { command } => {
+            let ipc_command: crate::ipc::IpcCommand = command.into();
+            crate::ipc::send_command(&ipc_command).await?;
+            println!("Sent: {:?}", ipc_command);
+        }
+    }
+
+    Ok(true)
+}
+
+/// Print EC2 instances as either a plain table or one JSON object per line, matching
+/// the columns the TUI's EC2 screen shows.
+fn print_ec2_instances(instances: &[Ec2Instance], json: bool) {
+    if json {
+        for instance in instances {
+            let row = Ec2InstanceJson {
+                id: &instance.id,
+                name: &instance.name,
+                instance_type: &instance.instance_type,
+                state: &instance.state,
+                public_ip: instance.public_ip.as_deref(),
+            };
+            if let Ok(line) = serde_json::to_string(&row) {
+                println!("{}", line);
+            }
+        }
+        return;
+    }
+
+    println!("{:<20} {:<24} {:<12} {:<12} {:<16}", "ID", "NAME", "TYPE", "STATE", "PUBLIC IP");
+    for instance in instances {
+        println!(
+            "{:<20} {:<24} {:<12} {:<12} {:<16}",
+            instance.id,
+            instance.name,
+            instance.instance_type,
+            instance.state,
+            instance.public_ip.as_deref().unwrap_or("-"),
+        );
+    }
+}
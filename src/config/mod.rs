@@ -8,8 +8,9 @@ pub struct AlertConfig {
     pub alert_threshold: Duration,
     /// Whether sound alerts are enabled
     pub sound_enabled: bool,
-    /// Slack webhook URL (for future implementation)
-    #[allow(dead_code)] // Planned feature
+    /// Slack incoming-webhook URL to post long-running-instance alerts to, resolved at
+    /// startup from whichever env var `Settings::slack_webhook_url_env` names. `None`
+    /// makes `App::check_alerts`'s Slack dispatch a no-op.
     pub slack_webhook_url: Option<String>,
 }
 
@@ -3,7 +3,9 @@
 use std::time::Duration;
 
 use anyhow::Result;
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEventKind};
+
+use crate::keymap::Keymap;
 
 /// Application events
 #[derive(Debug, Clone, Copy)]
@@ -34,6 +36,8 @@ pub enum AppEvent {
     OpenSettings,
     /// Modify setting value (delta: +1 or -1)
     ModifySettingValue(i32),
+    /// Fine-grained nudge of a numeric setting value (in step units, +1 or -1)
+    NudgeSettingValue(i32),
     /// Cancel settings dialog
     CancelSettings,
     /// Resize event (width, height)
@@ -42,75 +46,137 @@ pub enum AppEvent {
     ConfigureAws,
     /// Trigger SSO Login
     SsoLogin,
+    /// Open the fuzzy command palette
+    OpenCommandPalette,
+    /// Open the natural-language ops assistant panel
+    OpenAssistant,
+    /// Open the SSH key picker for the selected instance
+    OpenSsh,
+    /// Invoke the selected Lambda function (opens the payload dialog)
+    Invoke,
+    /// Open the "Launch instance" dialog
+    OpenLaunchInstance,
+    /// One-keystroke connect (SSM, falling back to SSH) to the selected instance
+    ConnectInstance,
+    /// Reboot the selected EC2 instance in place
+    Reboot,
+    /// Scale the selected Auto Scaling Group's desired capacity to zero
+    ScaleToZero,
+    /// Detach the selected instance from its Auto Scaling Group (with confirmation)
+    DetachInstance,
+    /// A printable character typed into a text input (e.g. command palette query)
+    Char(char),
+    /// Backspace while typing into a text input
+    Backspace,
+    /// Left mouse button pressed at the given (column, row) screen coordinate
+    ClickAt(u16, u16),
+    /// Scroll-wheel tick; positive scrolls down, negative scrolls up
+    Scroll(i32),
+    /// Switch to the next open workspace (saved profile+region), wrapping around
+    NextWorkspace,
+    /// Switch to the previous open workspace, wrapping around
+    PrevWorkspace,
+    /// Enter live-search input mode, filtering the current list/log screen as you type
+    EnterSearch,
+    /// Export the currently-filtered log entries to a timestamped file
+    ExportLogs,
+    /// Cycle the minimum log level shown on the Logs screen
+    CycleLogLevel,
+    /// Dismiss the topmost toast notification
+    DismissToast,
+    /// Toggle verbose (debug-level) AWS/hyper tracing on or off without restarting
+    ToggleVerboseTracing,
+    /// Cancel the selected instance's pending auto-stop drain, if it's currently in one
+    CancelDrain,
     /// No action
     None,
 }
 
 /// Poll for keyboard events with timeout
-pub fn poll_event(timeout: Duration) -> Result<Option<AppEvent>> {
+///
+/// `text_input_mode` routes plain character keys to `AppEvent::Char` instead of
+/// their normal single-key-shortcut meaning, for dialogs with a free-text field
+/// (e.g. the command palette).
+pub fn poll_event(timeout: Duration, text_input_mode: bool, keymap: &Keymap) -> Result<Option<AppEvent>> {
     if event::poll(timeout)? {
         match event::read()? {
             Event::Key(key) => {
                 if key.kind == KeyEventKind::Press {
-                    return Ok(Some(map_key_event(key)));
+                    return Ok(Some(map_key_event(key, text_input_mode, keymap)));
                 }
             }
             Event::Resize(w, h) => {
                 return Ok(Some(AppEvent::Resize(w, h)));
             }
+            Event::Mouse(mouse) => {
+                return Ok(match mouse.kind {
+                    MouseEventKind::Down(MouseButton::Left) => Some(AppEvent::ClickAt(mouse.column, mouse.row)),
+                    MouseEventKind::ScrollDown => Some(AppEvent::Scroll(1)),
+                    MouseEventKind::ScrollUp => Some(AppEvent::Scroll(-1)),
+                    _ => None,
+                });
+            }
             _ => {}
         }
     }
     Ok(None)
 }
 
-/// Map key events to application events
-fn map_key_event(key: KeyEvent) -> AppEvent {
+/// Map key events to application events. Structural keys (arrows, Enter, Esc, the
+/// number-row tab switches) are fixed; everything else is resolved against the active
+/// `Keymap`, so a `keymap.toml` binding-set or override changes what a letter key does.
+fn map_key_event(key: KeyEvent, text_input_mode: bool, keymap: &Keymap) -> AppEvent {
+    if text_input_mode {
+        return match (key.modifiers, key.code) {
+            (_, KeyCode::Esc) => AppEvent::CancelSettings,
+            (_, KeyCode::Enter) => AppEvent::Enter,
+            (_, KeyCode::Up) => AppEvent::Up,
+            (_, KeyCode::Down) => AppEvent::Down,
+            (_, KeyCode::Backspace) => AppEvent::Backspace,
+            (KeyModifiers::CONTROL, KeyCode::Char('c')) => AppEvent::Quit,
+            (_, KeyCode::Char(c)) => AppEvent::Char(c),
+            _ => AppEvent::None,
+        };
+    }
+
     match (key.modifiers, key.code) {
-        // Quit
-        (_, KeyCode::Char('q')) => AppEvent::Quit,
-        (KeyModifiers::CONTROL, KeyCode::Char('c')) => AppEvent::Quit,
-        
+        (KeyModifiers::CONTROL, KeyCode::Char('c')) => return AppEvent::Quit,
+
         // Tab navigation
-        (_, KeyCode::Char('1')) => AppEvent::NavigateTab(0),
-        (_, KeyCode::Char('2')) => AppEvent::NavigateTab(1),
-        (_, KeyCode::Char('3')) => AppEvent::NavigateTab(2),
-        (_, KeyCode::Char('4')) => AppEvent::NavigateTab(3),
-        
-        // List navigation
-        (_, KeyCode::Up) | (_, KeyCode::Char('k')) => AppEvent::Up,
-        (_, KeyCode::Down) | (_, KeyCode::Char('j')) => AppEvent::Down,
-        (_, KeyCode::Enter) => AppEvent::Enter,
-        
-        // EC2 actions
-        (_, KeyCode::Char('s')) => AppEvent::Start,
-        (_, KeyCode::Char('x')) => AppEvent::Stop,
-        (_, KeyCode::Char('t')) => AppEvent::Terminate,
-        (_, KeyCode::Char('r')) => AppEvent::Refresh,
-        (_, KeyCode::Char('a')) => AppEvent::Schedule,
-        
-        // Help
-        (_, KeyCode::Char('?')) => AppEvent::ShowHelp,
-        (_, KeyCode::Char('h')) => AppEvent::ShowHelp,
-        
-        // Settings
-        (_, KeyCode::Char(',')) => AppEvent::OpenSettings,
-        
-        // Tab 5 - Logs
-        (_, KeyCode::Char('5')) => AppEvent::NavigateTab(4),
-        
+        (_, KeyCode::Char('1')) => return AppEvent::NavigateTab(0),
+        (_, KeyCode::Char('2')) => return AppEvent::NavigateTab(1),
+        (_, KeyCode::Char('3')) => return AppEvent::NavigateTab(2),
+        (_, KeyCode::Char('4')) => return AppEvent::NavigateTab(3),
+        (_, KeyCode::Char('5')) => return AppEvent::NavigateTab(4),
+        (_, KeyCode::Char('6')) => return AppEvent::NavigateTab(5),
+
+        // List navigation (arrow keys always work, regardless of binding set)
+        (_, KeyCode::Up) => return AppEvent::Up,
+        (_, KeyCode::Down) => return AppEvent::Down,
+        (_, KeyCode::Enter) => return AppEvent::Enter,
+
+        // Workspace cycling (fixed, like the number-row tab switches above)
+        (_, KeyCode::Char('[')) => return AppEvent::PrevWorkspace,
+        (_, KeyCode::Char(']')) => return AppEvent::NextWorkspace,
+
+        // Live search (fixed, vi/broot-style, so it works the same regardless of binding set)
+        (_, KeyCode::Char('/')) => return AppEvent::EnterSearch,
+
+        // Fine-grained nudge (Shift+Left/Right) - must be checked before the plain
+        // Left/Right arms below, which would otherwise swallow them
+        (KeyModifiers::SHIFT, KeyCode::Left) => return AppEvent::NudgeSettingValue(-1),
+        (KeyModifiers::SHIFT, KeyCode::Right) => return AppEvent::NudgeSettingValue(1),
+
         // Settings value modification (Left/Right or -/+)
-        (_, KeyCode::Left) | (_, KeyCode::Char('-')) => AppEvent::ModifySettingValue(-1),
-        (_, KeyCode::Right) | (_, KeyCode::Char('+')) | (_, KeyCode::Char('=')) => AppEvent::ModifySettingValue(1),
-        
-        // AWS Config
-        (_, KeyCode::Char('c')) => AppEvent::ConfigureAws,
-        (_, KeyCode::Char('l')) => AppEvent::SsoLogin,
-        
+        (_, KeyCode::Left) | (_, KeyCode::Char('-')) => return AppEvent::ModifySettingValue(-1),
+        (_, KeyCode::Right) | (_, KeyCode::Char('+')) | (_, KeyCode::Char('=')) => return AppEvent::ModifySettingValue(1),
+
         // Escape - cancel settings or close dialogs
-        (_, KeyCode::Esc) => AppEvent::CancelSettings,
-        
-        _ => AppEvent::None,
+        (_, KeyCode::Esc) => return AppEvent::CancelSettings,
+
+        _ => {}
     }
+
+    keymap.resolve(key.modifiers, key.code).unwrap_or(AppEvent::None)
 }
 
@@ -0,0 +1,79 @@
+//! Small subsequence-based fuzzy matcher used by the command palette
+//!
+//! Scores candidates by how closely their characters match a query as a
+//! subsequence, favoring contiguous runs and matches at the start of a word.
+
+/// Result of matching a query against one candidate string
+#[derive(Debug, Clone)]
+pub struct FuzzyMatch {
+    /// Higher is a better match
+    pub score: i32,
+    /// Char indices (into `candidate.chars()`) that matched the query
+    pub matched_indices: Vec<usize>,
+}
+
+/// Try to match `query` as a fuzzy subsequence of `candidate`
+///
+/// Returns `None` if any query character has no remaining match in `candidate`.
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<FuzzyMatch> {
+    if query.is_empty() {
+        return Some(FuzzyMatch { score: 0, matched_indices: Vec::new() });
+    }
+
+    let query_chars: Vec<char> = query.to_lowercase().chars().collect();
+    let candidate_chars: Vec<char> = candidate.to_lowercase().chars().collect();
+
+    let mut matched_indices = Vec::with_capacity(query_chars.len());
+    let mut score = 0i32;
+    let mut candidate_idx = 0;
+    let mut prev_matched_idx: Option<usize> = None;
+
+    for &qc in &query_chars {
+        let mut found = None;
+        while candidate_idx < candidate_chars.len() {
+            if candidate_chars[candidate_idx] == qc {
+                found = Some(candidate_idx);
+                break;
+            }
+            candidate_idx += 1;
+        }
+
+        let idx = found?;
+
+        // Contiguous-run bonus: consecutive matched characters score higher
+        if let Some(prev) = prev_matched_idx {
+            if idx == prev + 1 {
+                score += 15;
+            }
+        }
+
+        // Start-of-word bonus: matching right after a space/underscore/hyphen, or at index 0
+        if idx == 0 || matches!(candidate_chars.get(idx - 1), Some(' ') | Some('_') | Some('-')) {
+            score += 10;
+        }
+
+        score += 1;
+        matched_indices.push(idx);
+        prev_matched_idx = Some(idx);
+        candidate_idx = idx + 1;
+    }
+
+    // Prefer shorter candidates among equal matches (tighter match)
+    score -= (candidate_chars.len() as i32) / 4;
+
+    Some(FuzzyMatch { score, matched_indices })
+}
+
+/// Filter and rank `candidates` against `query`, best match first
+///
+/// Returns `(original_index, FuzzyMatch)` pairs, sorted by descending score.
+pub fn fuzzy_filter(query: &str, candidates: &[&str]) -> Vec<(usize, FuzzyMatch)> {
+    let mut results: Vec<(usize, FuzzyMatch)> = candidates
+        .iter()
+        .enumerate()
+        .filter_map(|(i, candidate)| fuzzy_match(query, candidate).map(|m| (i, m)))
+        .collect();
+
+    results.sort_by(|a, b| b.1.score.cmp(&a.1.score));
+    results
+}
@@ -0,0 +1,106 @@
+//! IPC control socket, modeled on Alacritty's `--socket`/`msg` design: a running
+//! instance listens on a Unix socket (path overridable via `AWS_CC_SOCKET`) for a
+//! small newline-delimited JSON protocol, and a second invocation of the binary can
+//! connect to it with `aws-cloud-controller msg <command>` to drive the live TUI
+//! without touching the keyboard - handy for cron jobs and external scripts.
+
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::event::AppEvent;
+
+/// Path of the control socket. `AWS_CC_SOCKET` takes precedence (also what a `msg`
+/// invocation should export/pass if more than one instance is running); otherwise a
+/// fixed path under the runtime dir (falling back to the system temp dir), so a `msg`
+/// invocation in a fresh shell can find it without inheriting any environment from the
+/// TUI process.
+pub fn socket_path() -> PathBuf {
+    if let Ok(path) = std::env::var("AWS_CC_SOCKET") {
+        return PathBuf::from(path);
+    }
+    dirs::runtime_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("aws-cloud-controller.sock")
+}
+
+/// One line of the control protocol. Each connection sends exactly one command as a
+/// single JSON line and then closes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "cmd", rename_all = "snake_case")]
+pub enum IpcCommand {
+    /// Start the EC2 instance with this id
+    Start { id: String },
+    /// Stop the EC2 instance with this id
+    Stop { id: String },
+    /// Terminate the EC2 instance with this id (still routed through the same
+    /// confirmation dialog a keyboard-driven terminate would open)
+    Terminate { id: String },
+    /// Refresh whichever resource list is on screen
+    Refresh,
+    /// Switch the TUI to a named screen: "home", "ec2", "lambda", "asg"/"autoscaling",
+    /// "about", or "logs"
+    Navigate { screen: String },
+}
+
+impl IpcCommand {
+    /// Parse one line of the protocol (a single JSON object)
+    pub fn parse(line: &str) -> Result<Self> {
+        serde_json::from_str(line.trim()).map_err(|e| anyhow!("malformed IPC command: {}", e))
+    }
+
+    /// The screen index `AppEvent::NavigateTab` expects, for `Navigate` commands
+    pub fn screen_index(name: &str) -> Option<usize> {
+        match name {
+            "home" => Some(0),
+            "ec2" => Some(1),
+            "lambda" => Some(2),
+            "asg" | "autoscaling" => Some(3),
+            "about" => Some(4),
+            "logs" => Some(5),
+            _ => None,
+        }
+    }
+}
+
+/// The two things an `IpcCommand` decodes into: optionally, an instance id that must
+/// first become the selected EC2 instance (since `AppEvent::Start`/`Stop`/`Terminate`
+/// act on whatever's currently selected, not on an explicit id), and the `AppEvent`
+/// itself to feed into `App::handle_event`.
+#[derive(Debug)]
+pub struct DecodedCommand {
+    pub target_instance_id: Option<String>,
+    pub event: AppEvent,
+}
+
+impl From<IpcCommand> for DecodedCommand {
+    fn from(cmd: IpcCommand) -> Self {
+        match cmd {
+            IpcCommand::Start { id } => DecodedCommand { target_instance_id: Some(id), event: AppEvent::Start },
+            IpcCommand::Stop { id } => DecodedCommand { target_instance_id: Some(id), event: AppEvent::Stop },
+            IpcCommand::Terminate { id } => DecodedCommand { target_instance_id: Some(id), event: AppEvent::Terminate },
+            IpcCommand::Refresh => DecodedCommand { target_instance_id: None, event: AppEvent::Refresh },
+            IpcCommand::Navigate { screen } => DecodedCommand {
+                target_instance_id: None,
+                event: AppEvent::NavigateTab(IpcCommand::screen_index(&screen).unwrap_or(1)),
+            },
+        }
+    }
+}
+
+/// Connect to a running instance's control socket and send one command, as used by the
+/// `msg` CLI subcommand.
+pub async fn send_command(cmd: &IpcCommand) -> Result<()> {
+    use tokio::io::AsyncWriteExt;
+
+    let path = socket_path();
+    let mut stream = tokio::net::UnixStream::connect(&path)
+        .await
+        .map_err(|e| anyhow!("couldn't connect to {}: {} (is the TUI running?)", path.display(), e))?;
+    let line = serde_json::to_string(cmd)?;
+    stream.write_all(line.as_bytes()).await?;
+    stream.write_all(b"\n").await?;
+    stream.shutdown().await?;
+    Ok(())
+}
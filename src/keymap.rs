@@ -0,0 +1,474 @@
+//! Config-driven keybindings and accent-color overrides, loaded from `keymap.toml`
+//!
+//! The single-key shortcuts shown in the help overlay (`s`/`x`/`t`/`?`/...) and a
+//! handful of UI accent colors are normally built from `BindingSet::Default`. Dropping
+//! a `keymap.toml` into the settings directory lets a user name a built-in binding set
+//! (`vi`, `emacs`, which resolve to the same chords as `default`) and/or override
+//! individual bindings and colors. A
+//! missing file is silent (defaults apply); a malformed one falls back to defaults too,
+//! but the parse error is carried back so the caller can surface it to the user.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Context, Result};
+use crossterm::event::{KeyCode, KeyModifiers};
+use serde::Deserialize;
+
+use crate::event::AppEvent;
+use crate::settings::Settings;
+use crate::theme::parse_hex_color;
+
+/// Built-in binding sets a user can pick between. All three resolve to the same default
+/// chords for every rebindable [`Action`] (see `Action::default_chords`); only
+/// `[keybindings]` overrides, layered on top, change what a key does
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BindingSet {
+    #[default]
+    Default,
+    Vi,
+    Emacs,
+}
+
+/// A single-key shortcut that can be rebound via `keymap.toml`. Structural keys
+/// (arrows, Enter, Esc, the number-row tab switches) are not included here; they stay
+/// fixed so every binding set agrees on basic navigation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Action {
+    Quit,
+    Up,
+    Down,
+    Start,
+    Stop,
+    Terminate,
+    Refresh,
+    Schedule,
+    ShowHelp,
+    OpenSettings,
+    ConfigureAws,
+    SsoLogin,
+    OpenCommandPalette,
+    OpenAssistant,
+    OpenSsh,
+    Invoke,
+    ScaleToZero,
+    DetachInstance,
+    LaunchInstance,
+    ConnectInstance,
+    RebootInstance,
+    ExportLogs,
+    CycleLogLevel,
+    DismissToast,
+    ToggleVerboseTracing,
+    CancelDrain,
+}
+
+impl Action {
+    /// All rebindable actions, in the priority order `dedupe` and `resolve` use when two
+    /// actions claim the same chord (the help overlay lists them by `GROUPS` instead)
+    pub const ALL: &'static [Action] = &[
+        Action::ShowHelp,
+        Action::OpenCommandPalette,
+        Action::OpenAssistant,
+        Action::OpenSettings,
+        Action::Start,
+        Action::Stop,
+        Action::Terminate,
+        Action::Schedule,
+        Action::Refresh,
+        Action::OpenSsh,
+        Action::Invoke,
+        Action::ScaleToZero,
+        Action::DetachInstance,
+        Action::LaunchInstance,
+        Action::ConnectInstance,
+        Action::RebootInstance,
+        Action::ExportLogs,
+        Action::CycleLogLevel,
+        Action::DismissToast,
+        Action::ToggleVerboseTracing,
+        Action::CancelDrain,
+        Action::ConfigureAws,
+        Action::SsoLogin,
+        Action::Up,
+        Action::Down,
+        Action::Quit,
+    ];
+
+    /// The `keybindings` table key this action is configured under in `keymap.toml`
+    fn config_key(&self) -> &'static str {
+        match self {
+            Action::Quit => "quit",
+            Action::Up => "up",
+            Action::Down => "down",
+            Action::Start => "start",
+            Action::Stop => "stop",
+            Action::Terminate => "terminate",
+            Action::Refresh => "refresh",
+            Action::Schedule => "schedule_auto_stop",
+            Action::ShowHelp => "show_help",
+            Action::OpenSettings => "open_settings",
+            Action::ConfigureAws => "configure_aws",
+            Action::SsoLogin => "sso_login",
+            Action::OpenCommandPalette => "command_palette",
+            Action::OpenAssistant => "ops_assistant",
+            Action::OpenSsh => "ssh_connect",
+            Action::Invoke => "invoke_lambda",
+            Action::ScaleToZero => "scale_to_zero",
+            Action::DetachInstance => "detach_instance",
+            Action::LaunchInstance => "launch_instance",
+            Action::ConnectInstance => "connect_instance",
+            Action::RebootInstance => "reboot_instance",
+            Action::ExportLogs => "export_logs",
+            Action::CycleLogLevel => "cycle_log_level",
+            Action::DismissToast => "dismiss_toast",
+            Action::ToggleVerboseTracing => "toggle_verbose_tracing",
+            Action::CancelDrain => "cancel_drain",
+        }
+    }
+
+    /// Human-readable label for the help overlay
+    pub fn label(&self) -> &'static str {
+        match self {
+            Action::Quit => "Quit application",
+            Action::Up => "Navigate up",
+            Action::Down => "Navigate down",
+            Action::Start => "Start instance",
+            Action::Stop => "Stop instance",
+            Action::Terminate => "Terminate instance",
+            Action::Refresh => "Refresh data",
+            Action::Schedule => "Schedule auto-stop (1 hour)",
+            Action::ShowHelp => "Show this help",
+            Action::OpenSettings => "Open settings",
+            Action::ConfigureAws => "Open AWS configuration",
+            Action::SsoLogin => "Trigger SSO login",
+            Action::OpenCommandPalette => "Open command palette",
+            Action::OpenAssistant => "Open ops assistant",
+            Action::OpenSsh => "SSH into selected instance",
+            Action::Invoke => "Invoke selected function",
+            Action::ScaleToZero => "Scale selected Auto Scaling Group to zero",
+            Action::DetachInstance => "Detach selected instance from its Auto Scaling Group",
+            Action::LaunchInstance => "Launch a new EC2 instance",
+            Action::ConnectInstance => "Connect to selected instance (SSM, falling back to SSH)",
+            Action::RebootInstance => "Reboot selected instance",
+            Action::ExportLogs => "Export the currently-filtered logs to a file",
+            Action::CycleLogLevel => "Cycle the minimum log level shown",
+            Action::DismissToast => "Dismiss the most recent toast notification",
+            Action::ToggleVerboseTracing => "Toggle verbose (debug-level) AWS tracing",
+            Action::CancelDrain => "Cancel a pending auto-stop drain on the selected instance",
+        }
+    }
+
+    fn to_app_event(self) -> AppEvent {
+        match self {
+            Action::Quit => AppEvent::Quit,
+            Action::Up => AppEvent::Up,
+            Action::Down => AppEvent::Down,
+            Action::Start => AppEvent::Start,
+            Action::Stop => AppEvent::Stop,
+            Action::Terminate => AppEvent::Terminate,
+            Action::Refresh => AppEvent::Refresh,
+            Action::Schedule => AppEvent::Schedule,
+            Action::ShowHelp => AppEvent::ShowHelp,
+            Action::OpenSettings => AppEvent::OpenSettings,
+            Action::ConfigureAws => AppEvent::ConfigureAws,
+            Action::SsoLogin => AppEvent::SsoLogin,
+            Action::OpenCommandPalette => AppEvent::OpenCommandPalette,
+            Action::OpenAssistant => AppEvent::OpenAssistant,
+            Action::OpenSsh => AppEvent::OpenSsh,
+            Action::Invoke => AppEvent::Invoke,
+            Action::ScaleToZero => AppEvent::ScaleToZero,
+            Action::DetachInstance => AppEvent::DetachInstance,
+            Action::LaunchInstance => AppEvent::OpenLaunchInstance,
+            Action::ConnectInstance => AppEvent::ConnectInstance,
+            Action::RebootInstance => AppEvent::Reboot,
+            Action::ExportLogs => AppEvent::ExportLogs,
+            Action::CycleLogLevel => AppEvent::CycleLogLevel,
+            Action::DismissToast => AppEvent::DismissToast,
+            Action::ToggleVerboseTracing => AppEvent::ToggleVerboseTracing,
+            Action::CancelDrain => AppEvent::CancelDrain,
+        }
+    }
+
+    /// Sections and their actions, in the order the help overlay renders them
+    pub const GROUPS: &'static [(&'static str, &'static [Action])] = &[
+        ("Navigation", &[Action::Up, Action::Down]),
+        ("EC2 Controls", &[Action::Start, Action::Stop, Action::Terminate, Action::RebootInstance, Action::Schedule, Action::CancelDrain, Action::OpenSsh, Action::LaunchInstance, Action::ConnectInstance]),
+        ("Lambda Controls", &[Action::Invoke]),
+        ("Auto Scaling Controls", &[Action::ScaleToZero, Action::DetachInstance]),
+        ("Logs", &[Action::ExportLogs, Action::CycleLogLevel, Action::ToggleVerboseTracing]),
+        (
+            "General",
+            &[
+                Action::ShowHelp,
+                Action::OpenCommandPalette,
+                Action::OpenAssistant,
+                Action::OpenSettings,
+                Action::DismissToast,
+                Action::ConfigureAws,
+                Action::SsoLogin,
+                Action::Quit,
+            ],
+        ),
+    ];
+
+    /// This binding set's default chords for the action, as display-ready strings
+    /// (e.g. `"ctrl+a"`) in the same syntax accepted in `keymap.toml`
+    fn default_chords(&self, set: BindingSet) -> &'static [&'static str] {
+        match (self, set) {
+            (Action::Quit, _) => &["q"],
+            // `ctrl+p`/`ctrl+n` would be the traditional Emacs up/down chords, but
+            // `Action::ALL` processes `OpenCommandPalette` (which claims `ctrl+p`
+            // unconditionally) before `Up`/`Down` are ever reached, so `dedupe` would
+            // silently drop them on every startup. Rather than advertise a binding that
+            // can never actually fire, `k`/`j` stay the only Emacs chords here.
+            (Action::Up, _) => &["k"],
+            (Action::Down, _) => &["j"],
+            (Action::Start, _) => &["s"],
+            (Action::Stop, _) => &["x"],
+            (Action::Terminate, _) => &["t"],
+            (Action::Refresh, _) => &["r"],
+            (Action::Schedule, _) => &["a"],
+            (Action::ShowHelp, _) => &["?", "h"],
+            (Action::OpenSettings, _) => &[","],
+            (Action::ConfigureAws, _) => &["c"],
+            (Action::SsoLogin, _) => &["l"],
+            (Action::OpenCommandPalette, _) => &[":", "ctrl+p"],
+            (Action::OpenAssistant, _) => &["ctrl+a"],
+            (Action::OpenSsh, _) => &["o"],
+            (Action::Invoke, _) => &["i"],
+            (Action::ScaleToZero, _) => &["z"],
+            (Action::DetachInstance, _) => &["d"],
+            (Action::LaunchInstance, _) => &["n"],
+            (Action::ConnectInstance, _) => &["g"],
+            (Action::RebootInstance, _) => &["b"],
+            (Action::ExportLogs, _) => &["e"],
+            (Action::CycleLogLevel, _) => &["v"],
+            (Action::DismissToast, _) => &["u"],
+            (Action::ToggleVerboseTracing, _) => &["w"],
+            (Action::CancelDrain, _) => &["p"],
+        }
+    }
+}
+
+/// A parsed, ready-to-match key chord
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Chord {
+    modifiers: KeyModifiers,
+    code: KeyCode,
+}
+
+impl Chord {
+    /// Parse `"ctrl+a"`, `"?"`, `"esc"`, etc. into a chord
+    fn parse(spec: &str) -> Result<Self> {
+        let mut modifiers = KeyModifiers::NONE;
+        let mut code = None;
+        for part in spec.split('+') {
+            match part.trim().to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => modifiers |= KeyModifiers::CONTROL,
+                "shift" => modifiers |= KeyModifiers::SHIFT,
+                "alt" => modifiers |= KeyModifiers::ALT,
+                "esc" | "escape" => code = Some(KeyCode::Esc),
+                "enter" | "return" => code = Some(KeyCode::Enter),
+                "tab" => code = Some(KeyCode::Tab),
+                "space" => code = Some(KeyCode::Char(' ')),
+                other if other.chars().count() == 1 => code = Some(KeyCode::Char(other.chars().next().unwrap())),
+                other => return Err(anyhow!("Unknown key '{}' in binding '{}'", other, spec)),
+            }
+        }
+        let code = code.ok_or_else(|| anyhow!("Binding '{}' has no key", spec))?;
+        Ok(Self { modifiers, code })
+    }
+}
+
+/// Accent colors a `[theme]` table in `keymap.toml` can override; anything left unset
+/// keeps whatever `theme::theme_styles` already picked for the active light/dark variant
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ThemeOverrides {
+    pub status: Option<String>,
+    pub accent: Option<String>,
+    pub warning: Option<String>,
+    pub error: Option<String>,
+}
+
+/// On-disk `keymap.toml` shape
+#[derive(Debug, Clone, Default, Deserialize)]
+struct KeymapFile {
+    #[serde(default)]
+    binding_set: BindingSet,
+    #[serde(default)]
+    keybindings: HashMap<String, Vec<String>>,
+    #[serde(default)]
+    theme: ThemeOverrides,
+}
+
+/// Resolved keybindings for the running session, plus any accent-color overrides
+#[derive(Debug, Clone, Default)]
+pub struct Keymap {
+    binding_set: BindingSet,
+    bindings: HashMap<Action, Vec<Chord>>,
+    pub theme_overrides: ThemeOverrides,
+    /// Human-readable notes about chords that were dropped because another action
+    /// (earlier in `Action::ALL`) already claimed them. Populated by `dedupe` and
+    /// surfaced by `App::new` the same way a malformed `keymap.toml` is.
+    conflicts: Vec<String>,
+}
+
+impl Keymap {
+    /// Assign each action its requested chords in `Action::ALL` order, dropping (and
+    /// recording) any chord a previous action in that order already claimed. Shared by
+    /// `from_set` (over binding-set defaults) and `load` (over defaults plus
+    /// `keymap.toml` overrides) so a collision is caught regardless of which layer
+    /// introduced it, rather than left for `resolve`'s first-match scan to silently
+    /// paper over.
+    fn dedupe(requested: Vec<(Action, Vec<Chord>)>) -> (HashMap<Action, Vec<Chord>>, Vec<String>) {
+        let mut bindings = HashMap::new();
+        let mut claimed: HashMap<Chord, Action> = HashMap::new();
+        let mut conflicts = Vec::new();
+        for (action, chords) in requested {
+            let mut kept = Vec::new();
+            for chord in chords {
+                match claimed.get(&chord) {
+                    Some(&other) if other != action => conflicts.push(format!(
+                        "'{}' is already bound to {:?}; ignoring duplicate binding to {:?}",
+                        describe_chord(&chord),
+                        other,
+                        action
+                    )),
+                    _ => {
+                        claimed.insert(chord, action);
+                        kept.push(chord);
+                    }
+                }
+            }
+            bindings.insert(action, kept);
+        }
+        (bindings, conflicts)
+    }
+
+    fn from_set(set: BindingSet) -> Self {
+        let requested = Action::ALL
+            .iter()
+            .map(|&action| (action, action.default_chords(set).iter().filter_map(|spec| Chord::parse(spec).ok()).collect()))
+            .collect();
+        let (bindings, conflicts) = Self::dedupe(requested);
+        Self { binding_set: set, bindings, theme_overrides: ThemeOverrides::default(), conflicts }
+    }
+
+    fn config_path() -> Result<PathBuf> {
+        Ok(Settings::get_config_dir()?.join("keymap.toml"))
+    }
+
+    /// Load `keymap.toml`, falling back to `BindingSet::Default` if it's missing, and
+    /// to `BindingSet::Default` plus an error message if it's malformed
+    pub fn load() -> (Self, Option<String>) {
+        let path = match Self::config_path() {
+            Ok(p) => p,
+            Err(_) => return (Self::from_set(BindingSet::Default), None),
+        };
+
+        if !path.exists() {
+            return (Self::from_set(BindingSet::Default), None);
+        }
+
+        let file: KeymapFile = match std::fs::read_to_string(&path)
+            .context("Failed to read keymap.toml")
+            .and_then(|s| toml::from_str(&s).context("Failed to parse keymap.toml"))
+        {
+            Ok(f) => f,
+            Err(e) => return (Self::from_set(BindingSet::Default), Some(e.to_string())),
+        };
+
+        let requested = Action::ALL
+            .iter()
+            .map(|&action| {
+                let chords = match file.keybindings.get(action.config_key()) {
+                    Some(specs) => {
+                        let parsed: Vec<Chord> = specs.iter().filter_map(|spec| Chord::parse(spec).ok()).collect();
+                        if parsed.is_empty() {
+                            action.default_chords(file.binding_set).iter().filter_map(|spec| Chord::parse(spec).ok()).collect()
+                        } else {
+                            parsed
+                        }
+                    }
+                    None => action.default_chords(file.binding_set).iter().filter_map(|spec| Chord::parse(spec).ok()).collect(),
+                };
+                (action, chords)
+            })
+            .collect();
+        let (bindings, conflicts) = Self::dedupe(requested);
+
+        let keymap = Self { binding_set: file.binding_set, bindings, theme_overrides: file.theme, conflicts };
+        (keymap, None)
+    }
+
+    /// Chord conflicts dropped while resolving binding-set defaults and `keymap.toml`
+    /// overrides, in `Action::ALL` order. Empty when every action's chords are unique.
+    pub fn conflicts(&self) -> &[String] {
+        &self.conflicts
+    }
+
+    /// Resolve a key press to the configured action, if any
+    pub fn resolve(&self, modifiers: KeyModifiers, code: KeyCode) -> Option<AppEvent> {
+        Action::ALL
+            .iter()
+            .find(|action| self.bindings.get(action).is_some_and(|chords| chords.iter().any(|c| c.modifiers == modifiers && c.code == code)))
+            .map(|action| action.to_app_event())
+    }
+
+    /// Currently active binding set (`default`, `vi`, or `emacs`)
+    pub fn binding_set(&self) -> BindingSet {
+        self.binding_set
+    }
+
+    /// The key hints for an action, joined for display in the help overlay (e.g. `"?/h"`)
+    pub fn hint_for(&self, action: Action) -> String {
+        self.bindings
+            .get(&action)
+            .map(|chords| chords.iter().map(describe_chord).collect::<Vec<_>>().join("/"))
+            .unwrap_or_default()
+    }
+
+    /// Resolve the theme overrides into ratatui colors, for whichever `Styles` slots
+    /// the caller wants to patch
+    pub fn resolve_theme_overrides(&self) -> Vec<(&'static str, ratatui::style::Color)> {
+        let mut out = Vec::new();
+        for (name, value) in [
+            ("status", &self.theme_overrides.status),
+            ("accent", &self.theme_overrides.accent),
+            ("warning", &self.theme_overrides.warning),
+            ("error", &self.theme_overrides.error),
+        ] {
+            if let Some(hex) = value {
+                if let Some(color) = parse_hex_color(hex) {
+                    out.push((name, color));
+                }
+            }
+        }
+        out
+    }
+}
+
+/// Render a chord the way it'd appear in `keymap.toml` / the help overlay
+fn describe_chord(chord: &Chord) -> String {
+    let mut parts = Vec::new();
+    if chord.modifiers.contains(KeyModifiers::CONTROL) {
+        parts.push("Ctrl".to_string());
+    }
+    if chord.modifiers.contains(KeyModifiers::ALT) {
+        parts.push("Alt".to_string());
+    }
+    if chord.modifiers.contains(KeyModifiers::SHIFT) {
+        parts.push("Shift".to_string());
+    }
+    parts.push(match chord.code {
+        KeyCode::Char(' ') => "Space".to_string(),
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::Esc => "Esc".to_string(),
+        KeyCode::Enter => "Enter".to_string(),
+        KeyCode::Tab => "Tab".to_string(),
+        other => format!("{:?}", other),
+    });
+    parts.join("+")
+}
@@ -1,11 +1,25 @@
 //! Logging system for tracking user actions and application events
 
+use std::collections::HashSet;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 /// Maximum number of log entries to keep in memory
 const MAX_LOG_ENTRIES: usize = 1000;
 
+/// On-disk JSONL history file name, under the config dir
+const HISTORY_FILE_NAME: &str = "history.jsonl";
+
+/// Entries older than this are dropped from the history file on load
+const MAX_HISTORY_AGE_DAYS: i64 = 30;
+
+/// The history file is trimmed to its most recent entries once it exceeds this size
+const MAX_HISTORY_BYTES: u64 = 2 * 1024 * 1024;
+
 /// Log entry level/severity
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LogLevel {
@@ -16,6 +30,43 @@ pub enum LogLevel {
     Error,    // Errors that affect functionality
 }
 
+impl LogLevel {
+    /// Coarse severity ranking used to compare against a `min_level` threshold; `Success`
+    /// ranks alongside `Info` since it isn't something worth alerting on
+    fn severity(self) -> u8 {
+        match self {
+            LogLevel::Debug => 0,
+            LogLevel::Info | LogLevel::Success => 1,
+            LogLevel::Warning => 2,
+            LogLevel::Error => 3,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            LogLevel::Debug => "DEBUG",
+            LogLevel::Info => "INFO",
+            LogLevel::Success => "SUCCESS",
+            LogLevel::Warning => "WARN",
+            LogLevel::Error => "ERROR",
+        }
+    }
+}
+
+/// Where log entries are mirrored to, in addition to the in-memory ring buffer always
+/// shown in the Logs tab
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum LogOutput {
+    /// Plain or ANSI-colored lines on stdout
+    Stdout { colored: bool },
+    /// A session log file under the config directory, rotated to `<path>.1` once it
+    /// exceeds `max_size` bytes
+    File { path: PathBuf, max_size: u64 },
+    /// Entries at or above `min_level` mirrored to stderr, deduplicated so the same
+    /// message isn't repeated across refresh cycles
+    Stderr { min_level: LogLevel },
+}
+
 /// A single log entry
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LogEntry {
@@ -39,6 +90,15 @@ impl LogEntry {
 pub struct LogManager {
     entries: Vec<LogEntry>,
     scroll_offset: usize,
+    outputs: Vec<LogOutput>,
+    file_sink: Option<File>,
+    /// Messages already mirrored to stderr, so a warning repeated across refresh cycles
+    /// (e.g. "failed to fetch CloudWatch metrics") only surfaces there once
+    stderr_seen: HashSet<String>,
+    /// Rolling JSONL history (distinct from the user-configurable `LogOutput::File`
+    /// plain-text sink above), appended to on every entry so `render_logs` has context
+    /// across restarts. `None` until `load_history` successfully opens it.
+    history_sink: Option<File>,
 }
 
 impl Default for LogManager {
@@ -48,23 +108,154 @@ impl Default for LogManager {
 }
 
 impl LogManager {
-    /// Create a new log manager
+    /// Create a new log manager with no configured sinks (in-memory ring buffer only)
     pub fn new() -> Self {
         Self {
             entries: Vec::new(),
             scroll_offset: 0,
+            outputs: Vec::new(),
+            file_sink: None,
+            stderr_seen: HashSet::new(),
+            history_sink: None,
         }
     }
-    
-    /// Add a log entry
+
+    /// Load `<config_dir>/history.jsonl` into `entries` (dropping anything older than
+    /// `MAX_HISTORY_AGE_DAYS`, and trimming the file itself if it's grown past
+    /// `MAX_HISTORY_BYTES`), then leave it open for appending. A failure to read or open
+    /// the file is logged in-memory rather than propagated, same as `configure_outputs`.
+    pub fn load_history(&mut self, config_dir: &std::path::Path) {
+        let path = config_dir.join(HISTORY_FILE_NAME);
+        let cutoff = Utc::now() - chrono::Duration::days(MAX_HISTORY_AGE_DAYS);
+
+        let needs_size_trim = fs::metadata(&path).map(|m| m.len() > MAX_HISTORY_BYTES).unwrap_or(false);
+
+        let (mut loaded, mut trimmed) = match fs::read_to_string(&path) {
+            Ok(contents) => {
+                let parsed: Vec<LogEntry> = contents.lines().filter_map(|line| serde_json::from_str::<LogEntry>(line).ok()).collect();
+                let before = parsed.len();
+                let kept: Vec<LogEntry> = parsed.into_iter().filter(|e| e.timestamp >= cutoff).collect();
+                let trimmed = kept.len() != before;
+                (kept, trimmed)
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => (Vec::new(), false),
+            Err(e) => {
+                self.log(LogLevel::Warning, format!("Failed to read log history {}: {}", path.display(), e));
+                (Vec::new(), false)
+            }
+        };
+
+        // Cap by count too, in case a huge number of entries were logged recently
+        if loaded.len() > MAX_LOG_ENTRIES {
+            loaded.drain(0..(loaded.len() - MAX_LOG_ENTRIES));
+            trimmed = true;
+        }
+
+        if trimmed || needs_size_trim {
+            if let Err(e) = Self::rewrite_history(&path, &loaded) {
+                self.log(LogLevel::Warning, format!("Failed to trim log history {}: {}", path.display(), e));
+            }
+        }
+
+        match OpenOptions::new().create(true).append(true).open(&path) {
+            Ok(file) => self.history_sink = Some(file),
+            Err(e) => self.log(LogLevel::Warning, format!("Failed to open log history {}: {}", path.display(), e)),
+        }
+
+        self.entries = loaded;
+    }
+
+    /// Overwrite the history file with exactly `entries`, one JSON object per line
+    fn rewrite_history(path: &PathBuf, entries: &[LogEntry]) -> std::io::Result<()> {
+        let mut file = File::create(path)?;
+        for entry in entries {
+            if let Ok(line) = serde_json::to_string(entry) {
+                writeln!(file, "{}", line)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Apply the sinks from `Settings::log_outputs`, opening (and rotating, if needed)
+    /// the session log file. Failures to open the file are logged in-memory rather than
+    /// propagated, since logging setup shouldn't be able to crash startup.
+    pub fn configure_outputs(&mut self, outputs: Vec<LogOutput>) {
+        self.file_sink = None;
+        for output in &outputs {
+            if let LogOutput::File { path, max_size } = output {
+                match Self::open_rotated(path, *max_size) {
+                    Ok(file) => self.file_sink = Some(file),
+                    Err(e) => self.log(LogLevel::Warning, format!("Failed to open log file {}: {}", path.display(), e)),
+                }
+            }
+        }
+        self.outputs = outputs;
+    }
+
+    /// Open `path` for appending, first rotating it to `<path>.1` if it's grown past
+    /// `max_size` bytes
+    fn open_rotated(path: &PathBuf, max_size: u64) -> std::io::Result<File> {
+        if let Ok(metadata) = fs::metadata(path) {
+            if metadata.len() > max_size {
+                let mut backup = path.clone().into_os_string();
+                backup.push(".1");
+                fs::rename(path, PathBuf::from(backup))?;
+            }
+        }
+        OpenOptions::new().create(true).append(true).open(path)
+    }
+
+    /// Add a log entry, mirroring it to any configured sinks
     pub fn log(&mut self, level: LogLevel, message: String) {
-        self.entries.push(LogEntry::new(level, message));
-        
+        self.mirror_to_sinks(level, &message);
+        let entry = LogEntry::new(level, message);
+
+        if let Some(file) = self.history_sink.as_mut() {
+            if let Ok(line) = serde_json::to_string(&entry) {
+                let _ = writeln!(file, "{}", line);
+            }
+        }
+
+        self.entries.push(entry);
+
         // Keep only the most recent entries
         if self.entries.len() > MAX_LOG_ENTRIES {
             self.entries.drain(0..(self.entries.len() - MAX_LOG_ENTRIES));
         }
     }
+
+    fn mirror_to_sinks(&mut self, level: LogLevel, message: &str) {
+        let line = format!("{} [{}] {}", Utc::now().to_rfc3339(), level.as_str(), message);
+
+        for output in &self.outputs {
+            match output {
+                LogOutput::Stdout { colored } => {
+                    if *colored {
+                        let code = match level {
+                            LogLevel::Debug => "90",
+                            LogLevel::Info => "36",
+                            LogLevel::Success => "32",
+                            LogLevel::Warning => "33",
+                            LogLevel::Error => "31",
+                        };
+                        println!("\x1b[{}m{}\x1b[0m", code, line);
+                    } else {
+                        println!("{}", line);
+                    }
+                }
+                LogOutput::Stderr { min_level } => {
+                    if level.severity() >= min_level.severity() && self.stderr_seen.insert(message.to_string()) {
+                        eprintln!("{}", line);
+                    }
+                }
+                LogOutput::File { .. } => {
+                    if let Some(file) = self.file_sink.as_mut() {
+                        let _ = writeln!(file, "{}", line);
+                    }
+                }
+            }
+        }
+    }
     
     /// Convenience method for debug/verbose logs
     #[allow(dead_code)]
@@ -96,7 +287,24 @@ impl LogManager {
     pub fn entries(&self) -> &[LogEntry] {
         &self.entries
     }
-    
+
+    /// Entries at or above `min_level` whose message matches `query`: a `regex::Regex` if
+    /// `query` compiles as one, the same fuzzy subsequence match the `/` live-search uses
+    /// elsewhere otherwise - so a plain word filters like normal search and a pattern
+    /// like `i-0[a-f0-9]+` also works. An empty `query` matches everything.
+    pub fn filtered_entries(&self, min_level: LogLevel, query: &str) -> Vec<&LogEntry> {
+        let at_or_above_level = self.entries.iter().filter(move |e| e.level.severity() >= min_level.severity());
+
+        if query.is_empty() {
+            return at_or_above_level.collect();
+        }
+
+        match regex::Regex::new(&format!("(?i){}", query)) {
+            Ok(re) => at_or_above_level.filter(|e| re.is_match(&e.message)).collect(),
+            Err(_) => at_or_above_level.filter(|e| crate::fuzzy::fuzzy_match(query, &e.message).is_some()).collect(),
+        }
+    }
+
     /// Get scroll offset for UI
     pub fn scroll_offset(&self) -> usize {
         self.scroll_offset
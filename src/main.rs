@@ -3,33 +3,92 @@
 //! A terminal-based interface for EC2 instance management and Lambda function control.
 
 mod app;
+mod assistant;
 mod aws;
+mod cli;
 mod config;
 mod event;
+mod fuzzy;
+mod ipc;
+mod keymap;
 mod logger;
 mod settings;
+mod ssh;
+mod telemetry;
+mod theme;
 mod tui;
 mod ui;
 
 use anyhow::Result;
 use app::App;
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+use clap::Parser;
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, Layer};
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    let args = cli::Cli::parse();
+    let inline_rows = args.inline;
+    if cli::run(args).await? {
+        return Ok(());
+    }
+
     // Initialize logging to file (avoid terminal output)
     let file_appender = tracing_subscriber::fmt::layer()
         .with_ansi(false)
         .with_writer(std::io::sink);
-    
+
+    // Reloadable slot for the optional OTLP layer. It starts empty since whether an
+    // endpoint is configured isn't known until `App::new()` has loaded settings.
+    let (otlp_layer, otlp_handle) = tracing_subscriber::reload::Layer::new(None::<telemetry::BoxedLayer>);
+
+    // Reloadable `EnvFilter` (RUST_LOG, defaulting to `telemetry::DEFAULT_FILTER_DIRECTIVE`)
+    // shared by the rolling file layer and the in-app log bridge below, so
+    // `App::toggle_verbose_tracing` can raise both to `debug` at once without a restart.
+    let (log_filter, log_filter_handle) = tracing_subscriber::reload::Layer::new(telemetry::build_env_filter());
+
+    // Forward every tracing event into the in-app log panel instead of letting
+    // `file_appender` silently swallow it; `App` drains `tracing_rx` once it exists.
+    let (log_bridge_layer, tracing_rx) = telemetry::log_bridge_layer();
+
+    // The real on-disk record of this run: every span/event, surviving a crash and a
+    // restart. A failure here (e.g. an unwritable data dir) shouldn't block startup.
+    let rolling_layer = match telemetry::rolling_file_layer(log_filter.clone()) {
+        Ok(layer) => Some(layer),
+        Err(e) => {
+            eprintln!("warning: failed to set up rolling log file: {}", e);
+            None
+        }
+    };
+
     tracing_subscriber::registry()
         .with(file_appender)
+        .with(otlp_layer)
+        .with(log_bridge_layer.with_filter(log_filter))
+        .with(rolling_layer)
         .init();
 
     // Initialize and run the application
-    let mut app = App::new().await?;
-    let mut terminal = tui::init()?;
-    
+    let mut app = App::new(tracing_rx, log_filter_handle).await?;
+
+    if let Some(endpoint) = app.settings.otlp_endpoint.clone() {
+        match telemetry::otlp_layer(&endpoint) {
+            Ok(layer) if otlp_handle.reload(Some(layer)).is_ok() => {
+                app.log_manager.info(format!("Exporting spans to OTLP collector at {}", endpoint));
+            }
+            Ok(_) => {
+                app.log_manager.warning("Failed to install OTLP tracing layer".to_string());
+            }
+            Err(e) => {
+                app.log_manager.warning(format!("Failed to configure OTLP exporter: {}", e));
+            }
+        }
+    }
+
+    let mut terminal = match inline_rows {
+        Some(rows) => tui::init_with_options(ratatui::Viewport::Inline(rows))?,
+        None => tui::init()?,
+    };
+
     // Set initial window size
     if let Ok(size) = terminal.size() {
         app.window_size = (size.width, size.height);
@@ -1,12 +1,15 @@
 //! Settings management with persistent storage
 
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 use std::time::Duration;
 
-use crate::logger::LogLevel;
+use crate::assistant::AssistantProviderKind;
+use crate::logger::{LogLevel, LogOutput};
+use crate::theme::{StatusBarColors, ThemePalette};
 
 /// Application settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,14 +23,216 @@ pub struct Settings {
     pub log_level: LogLevel,
     /// Alert threshold in seconds for long-running instances
     pub alert_threshold_secs: u64,
+    /// How far ahead of actual credential expiry (in seconds) `check_session_renewal`
+    /// pre-emptively re-runs `aws sso login`, so a long-lived session never hits a
+    /// mid-operation `ExpiredToken` failure
+    #[serde(default = "default_session_renewal_threshold_secs")]
+    pub session_renewal_threshold_secs: u64,
     /// Whether sound alerts are enabled
     pub sound_enabled: bool,
+    /// AWS profile to activate on startup, if it's available
+    #[serde(default)]
+    pub default_profile: Option<String>,
+    /// Friendly display names for profiles, e.g. long SSO account-role names
+    #[serde(default)]
+    pub profile_aliases: HashMap<String, String>,
+    /// Which LLM backend the natural-language ops assistant talks to
+    #[serde(default)]
+    pub assistant_provider: AssistantProviderKind,
+    /// Override the provider's default HTTP endpoint (e.g. a local Ollama URL)
+    #[serde(default)]
+    pub assistant_endpoint: Option<String>,
+    /// Model name to request, e.g. "gpt-4o-mini" or "claude-3-5-sonnet-latest"
+    #[serde(default = "default_assistant_model")]
+    pub assistant_model: String,
+    /// Name of the environment variable to read the assistant's API key from
+    #[serde(default)]
+    pub assistant_api_key_env: Option<String>,
+    /// Where log entries are mirrored to, in addition to the in-TUI Logs tab
+    #[serde(default = "default_log_outputs")]
+    pub log_outputs: Vec<LogOutput>,
+    /// Private key path for the SSH fallback used by `connect_to_selected_instance`
+    /// when SSM Session Manager isn't available. `None` lets `ssh` fall back to its
+    /// own default identity files.
+    #[serde(default)]
+    pub ssh_key_path: Option<String>,
+    /// Default remote user for `o`/`g` SSH connections, overridden per-instance by a
+    /// `SshUser` tag (see `Ec2Instance::ssh_user_tag`)
+    #[serde(default = "default_ssh_user")]
+    pub ssh_default_user: String,
+    /// Whether cost/idle alerts also fire a native OS desktop notification
+    #[serde(default = "default_true")]
+    pub notifications_enabled: bool,
+    /// Whether graceful shutdown also stops any instance whose auto-stop schedule has
+    /// already elapsed, instead of just saving it for the next launch. Off by default,
+    /// since stopping instances on quit is a more disruptive default than preserving them.
+    #[serde(default)]
+    pub stop_on_exit: bool,
+    /// OTLP collector endpoint (e.g. "http://localhost:4317") that instrumentation spans
+    /// are exported to. `None` leaves tracing on the existing in-app log panel sink.
+    #[serde(default)]
+    pub otlp_endpoint: Option<String>,
+    /// Name of the environment variable to read the Slack incoming-webhook URL from, for
+    /// long-running-instance alerts. Like `assistant_api_key_env`, the URL itself never
+    /// touches settings.json - only the name of where to find it.
+    #[serde(default)]
+    pub slack_webhook_url_env: Option<String>,
+    /// Color palette for the whole TUI - Dark, Light, or High Contrast
+    #[serde(default)]
+    pub theme_palette: ThemePalette,
+    /// Named color slots (`accent`/`alert`/`border`/`keycap_bg`/`timer`) for the status
+    /// bar and its control-hint key caps, independent of `theme_palette` - edit
+    /// `settings.json`/`settings.toml` by hand to restyle just the status bar.
+    #[serde(default)]
+    pub status_bar_colors: StatusBarColors,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_assistant_model() -> String {
+    "gpt-4o-mini".to_string()
+}
+
+fn default_ssh_user() -> String {
+    "ec2-user".to_string()
 }
 
 fn default_log_level() -> LogLevel {
     LogLevel::Info
 }
 
+fn default_session_renewal_threshold_secs() -> u64 {
+    300 // 5 minutes
+}
+
+/// Allowed range for `refresh_interval_secs`: anything from a 5s tight poll loop up to
+/// a 1h lull
+const REFRESH_INTERVAL_BOUNDS: std::ops::RangeInclusive<u64> = 5..=3600;
+/// Step size for the fine-grained +/- nudge keys on the refresh interval field
+const REFRESH_INTERVAL_STEP_SECS: u64 = 5;
+
+/// Allowed range for `alert_threshold_secs`: from 1 minute up to a full day
+const ALERT_THRESHOLD_BOUNDS: std::ops::RangeInclusive<u64> = 60..=86400;
+/// Step size for the fine-grained +/- nudge keys on the alert threshold field
+const ALERT_THRESHOLD_STEP_SECS: u64 = 60;
+
+/// Allowed range for `session_renewal_threshold_secs`: from 1 minute up to a full hour
+const SESSION_RENEWAL_THRESHOLD_BOUNDS: std::ops::RangeInclusive<u64> = 60..=3600;
+/// Step size for the fine-grained +/- nudge keys on the session renewal threshold field
+const SESSION_RENEWAL_THRESHOLD_STEP_SECS: u64 = 60;
+
+/// Move `current` by `steps * step_secs` (can be negative), clamped to `bounds`
+fn nudge_secs(current: u64, steps: i64, step_secs: u64, bounds: std::ops::RangeInclusive<u64>) -> u64 {
+    let delta = steps * step_secs as i64;
+    let nudged = (current as i64 + delta).max(0) as u64;
+    nudged.clamp(*bounds.start(), *bounds.end())
+}
+
+/// Render a raw seconds count the same way `Settings::format_refresh_interval` does,
+/// for use in bounds-validation error messages
+fn format_duration_secs(secs: u64) -> String {
+    if secs < 60 {
+        format!("{}s", secs)
+    } else if secs < 3600 {
+        format!("{}m", secs / 60)
+    } else {
+        format!("{}h", secs / 3600)
+    }
+}
+
+/// Parse a duration string like `10s`, `90s`, `5m`, `6h`, or a combination like
+/// `1h30m`, into whole seconds. A bare number with no suffix is treated as seconds.
+pub fn parse_duration_secs(input: &str) -> Option<u64> {
+    let input = input.trim().to_lowercase();
+    if input.is_empty() {
+        return None;
+    }
+
+    if let Ok(secs) = input.parse::<u64>() {
+        return Some(secs);
+    }
+
+    let mut total_secs: u64 = 0;
+    let mut digits = String::new();
+    let mut saw_unit = false;
+
+    for ch in input.chars() {
+        if ch.is_ascii_digit() {
+            digits.push(ch);
+        } else {
+            let value: u64 = digits.parse().ok()?;
+            digits.clear();
+            total_secs += match ch {
+                'h' => value * 3600,
+                'm' => value * 60,
+                's' => value,
+                _ => return None,
+            };
+            saw_unit = true;
+        }
+    }
+
+    if !digits.is_empty() || !saw_unit {
+        return None;
+    }
+
+    Some(total_secs)
+}
+
+/// Default log file path: `<config dir>/session.log`, same directory as `settings.json`
+fn default_log_file_path() -> PathBuf {
+    dirs::config_dir()
+        .map(|d| d.join("aws-cloud-controller"))
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("session.log")
+}
+
+fn default_log_outputs() -> Vec<LogOutput> {
+    vec![
+        LogOutput::File { path: default_log_file_path(), max_size: 5 * 1024 * 1024 },
+        LogOutput::Stderr { min_level: LogLevel::Warning },
+    ]
+}
+
+/// On-disk serialization format for the settings store
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SettingsFormat {
+    Json,
+    Toml,
+}
+
+impl Default for SettingsFormat {
+    fn default() -> Self {
+        Self::Json
+    }
+}
+
+impl SettingsFormat {
+    fn file_name(self) -> &'static str {
+        match self {
+            SettingsFormat::Json => "settings.json",
+            SettingsFormat::Toml => "settings.toml",
+        }
+    }
+
+    /// Human-readable label for the settings dialog
+    pub fn label(self) -> &'static str {
+        match self {
+            SettingsFormat::Json => "JSON",
+            SettingsFormat::Toml => "TOML",
+        }
+    }
+
+    fn other(self) -> Self {
+        match self {
+            SettingsFormat::Json => SettingsFormat::Toml,
+            SettingsFormat::Toml => SettingsFormat::Json,
+        }
+    }
+}
+
 impl Default for Settings {
     fn default() -> Self {
         Self {
@@ -35,7 +240,23 @@ impl Default for Settings {
             show_logs_panel: false,        // Hidden by default, enable via settings
             log_level: LogLevel::Info,     // Show Info and above by default
             alert_threshold_secs: 3600,    // 1 hour
+            session_renewal_threshold_secs: default_session_renewal_threshold_secs(),
             sound_enabled: true,
+            default_profile: None,
+            profile_aliases: HashMap::new(),
+            assistant_provider: AssistantProviderKind::default(),
+            assistant_endpoint: None,
+            assistant_model: default_assistant_model(),
+            assistant_api_key_env: None,
+            log_outputs: default_log_outputs(),
+            ssh_key_path: None,
+            ssh_default_user: default_ssh_user(),
+            notifications_enabled: true,
+            stop_on_exit: false,
+            otlp_endpoint: None,
+            slack_webhook_url_env: None,
+            theme_palette: ThemePalette::default(),
+            status_bar_colors: StatusBarColors::default(),
         }
     }
 }
@@ -56,55 +277,97 @@ impl Settings {
         Ok(config_dir)
     }
     
-    /// Get the settings file path
+    /// Get the settings file path, preferring `settings.toml` if it's present on disk
     fn get_settings_path() -> Result<PathBuf> {
-        Ok(Self::get_config_dir()?.join("settings.json"))
+        Self::get_settings_path_for(Self::resolve_settings_format()?)
     }
-    
-    /// Load settings from file, or return defaults if file doesn't exist
-    pub fn load() -> Result<Self> {
-        let path = Self::get_settings_path()?;
-        
-        if !path.exists() {
-            // Create default settings file
-            let default_settings = Self::default();
-            default_settings.save()?;
-            return Ok(default_settings);
+
+    /// Path for a specific persisted format, independent of what's actually on disk
+    fn get_settings_path_for(format: SettingsFormat) -> Result<PathBuf> {
+        Ok(Self::get_config_dir()?.join(format.file_name()))
+    }
+
+    /// Which format to read/write: `settings.toml` wins if it exists, otherwise
+    /// `settings.json` (including the common case where neither exists yet)
+    fn resolve_settings_format() -> Result<SettingsFormat> {
+        let dir = Self::get_config_dir()?;
+        if dir.join(SettingsFormat::Toml.file_name()).exists() {
+            Ok(SettingsFormat::Toml)
+        } else {
+            Ok(SettingsFormat::Json)
         }
-        
-        let contents = fs::read_to_string(&path)
-            .context("Failed to read settings file")?;
-        
-        let settings: Settings = serde_json::from_str(&contents)
-            .context("Failed to parse settings file")?;
-        
-        Ok(settings)
     }
-    
-    /// Save settings to file
-    pub fn save(&self) -> Result<()> {
+
+    /// Watch the active settings file (`settings.toml` if present, else `settings.json`)
+    /// for changes and re-parse it whenever it's written, so an
+    /// editor change is picked up without restarting the app. Rapid successive writes
+    /// (an editor's "save" is often several) are coalesced into a single reload by
+    /// waiting for ~200ms of quiet before re-reading the file. `on_reload` is called
+    /// with the freshly-parsed settings, or the parse error if the file is malformed
+    /// (the caller should keep using the previous settings in that case).
+    ///
+    /// Returns the watcher, which must be kept alive for as long as watching should
+    /// continue; dropping it stops the underlying OS watch.
+    pub fn watch<F>(mut on_reload: F) -> Result<notify::RecommendedWatcher>
+    where
+        F: FnMut(Result<Settings>) + Send + 'static,
+    {
         let path = Self::get_settings_path()?;
-        
-        let contents = serde_json::to_string_pretty(self)
-            .context("Failed to serialize settings")?;
-        
-        fs::write(&path, contents)
-            .context("Failed to write settings file")?;
-        
-        Ok(())
+        let (raw_tx, raw_rx) = std::sync::mpsc::channel::<notify::Result<notify::Event>>();
+
+        let mut watcher = notify::recommended_watcher(move |event| {
+            let _ = raw_tx.send(event);
+        })
+        .context("Failed to create settings file watcher")?;
+        notify::Watcher::watch(&mut watcher, &path, notify::RecursiveMode::NonRecursive)
+            .context("Failed to watch settings.json")?;
+
+        std::thread::spawn(move || {
+            while let Ok(event) = raw_rx.recv() {
+                if !matches!(event, Ok(ref e) if e.kind.is_modify() || e.kind.is_create()) {
+                    continue;
+                }
+                // Debounce: swallow anything else that arrives within the window so one
+                // editor save (often several filesystem events) triggers one reload
+                while raw_rx.recv_timeout(Duration::from_millis(200)).is_ok() {}
+
+                on_reload(Settings::load());
+            }
+        });
+
+        Ok(watcher)
     }
     
+    /// Load the active settings profile from file, or return defaults if the file
+    /// doesn't exist. A pre-profile flat `settings.json` is migrated transparently.
+    pub fn load() -> Result<Self> {
+        Ok(SettingsStore::load()?.active_settings())
+    }
+
+    /// Save back into the active profile's slot, leaving the other profiles untouched
+    pub fn save(&self) -> Result<()> {
+        let mut store = SettingsStore::load().unwrap_or_default();
+        store.update_active(self.clone());
+        store.save()
+    }
+
     /// Get refresh interval as Duration
     pub fn refresh_interval(&self) -> Duration {
         Duration::from_secs(self.refresh_interval_secs)
     }
-    
-    /// Get alert threshold as Duration 
+
+    /// Get alert threshold as Duration
     #[allow(dead_code)]
     pub fn alert_threshold(&self) -> Duration {
         Duration::from_secs(self.alert_threshold_secs)
     }
-    
+
+    /// Get the session renewal threshold as a `chrono::Duration`, for comparison
+    /// against a credential expiration timestamp
+    pub fn session_renewal_threshold(&self) -> chrono::Duration {
+        chrono::Duration::seconds(self.session_renewal_threshold_secs as i64)
+    }
+
     /// Cycle refresh interval to next value
     pub fn cycle_refresh_interval(&mut self, forward: bool) {
         const INTERVALS: &[u64] = &[15, 30, 60, 120, 300]; // 15s, 30s, 1m, 2m, 5m
@@ -146,7 +409,84 @@ impl Settings {
         
         self.alert_threshold_secs = THRESHOLDS[new_idx];
     }
-    
+
+    /// Set the refresh interval to an arbitrary value, rejecting anything outside
+    /// `REFRESH_INTERVAL_BOUNDS` so a typo can't set a 0s or multi-day poll loop
+    pub fn set_refresh_interval_secs(&mut self, secs: u64) -> Result<(), String> {
+        if !REFRESH_INTERVAL_BOUNDS.contains(&secs) {
+            return Err(format!(
+                "Refresh interval must be between {}s and {}",
+                REFRESH_INTERVAL_BOUNDS.start(),
+                format_duration_secs(*REFRESH_INTERVAL_BOUNDS.end())
+            ));
+        }
+        self.refresh_interval_secs = secs;
+        Ok(())
+    }
+
+    /// Nudge the refresh interval by `REFRESH_INTERVAL_STEP_SECS`, clamped to bounds
+    pub fn nudge_refresh_interval(&mut self, steps: i64) {
+        self.refresh_interval_secs = nudge_secs(self.refresh_interval_secs, steps, REFRESH_INTERVAL_STEP_SECS, REFRESH_INTERVAL_BOUNDS);
+    }
+
+    /// Set the alert threshold to an arbitrary value, rejecting anything outside
+    /// `ALERT_THRESHOLD_BOUNDS`
+    pub fn set_alert_threshold_secs(&mut self, secs: u64) -> Result<(), String> {
+        if !ALERT_THRESHOLD_BOUNDS.contains(&secs) {
+            return Err(format!(
+                "Alert threshold must be between {} and {}",
+                format_duration_secs(*ALERT_THRESHOLD_BOUNDS.start()),
+                format_duration_secs(*ALERT_THRESHOLD_BOUNDS.end())
+            ));
+        }
+        self.alert_threshold_secs = secs;
+        Ok(())
+    }
+
+    /// Nudge the alert threshold by `ALERT_THRESHOLD_STEP_SECS`, clamped to bounds
+    pub fn nudge_alert_threshold(&mut self, steps: i64) {
+        self.alert_threshold_secs = nudge_secs(self.alert_threshold_secs, steps, ALERT_THRESHOLD_STEP_SECS, ALERT_THRESHOLD_BOUNDS);
+    }
+
+    /// Cycle the session renewal threshold to next value
+    pub fn cycle_session_renewal_threshold(&mut self, forward: bool) {
+        const THRESHOLDS: &[u64] = &[60, 180, 300, 600, 900]; // 1m, 3m, 5m, 10m, 15m
+
+        let current_idx = THRESHOLDS.iter()
+            .position(|&x| x == self.session_renewal_threshold_secs)
+            .unwrap_or(2); // Default to 5m if not found
+
+        let new_idx = if forward {
+            (current_idx + 1) % THRESHOLDS.len()
+        } else if current_idx == 0 {
+            THRESHOLDS.len() - 1
+        } else {
+            current_idx - 1
+        };
+
+        self.session_renewal_threshold_secs = THRESHOLDS[new_idx];
+    }
+
+    /// Set the session renewal threshold to an arbitrary value, rejecting anything
+    /// outside `SESSION_RENEWAL_THRESHOLD_BOUNDS`
+    pub fn set_session_renewal_threshold_secs(&mut self, secs: u64) -> Result<(), String> {
+        if !SESSION_RENEWAL_THRESHOLD_BOUNDS.contains(&secs) {
+            return Err(format!(
+                "Session renewal threshold must be between {} and {}",
+                format_duration_secs(*SESSION_RENEWAL_THRESHOLD_BOUNDS.start()),
+                format_duration_secs(*SESSION_RENEWAL_THRESHOLD_BOUNDS.end())
+            ));
+        }
+        self.session_renewal_threshold_secs = secs;
+        Ok(())
+    }
+
+    /// Nudge the session renewal threshold by `SESSION_RENEWAL_THRESHOLD_STEP_SECS`,
+    /// clamped to bounds
+    pub fn nudge_session_renewal_threshold(&mut self, steps: i64) {
+        self.session_renewal_threshold_secs = nudge_secs(self.session_renewal_threshold_secs, steps, SESSION_RENEWAL_THRESHOLD_STEP_SECS, SESSION_RENEWAL_THRESHOLD_BOUNDS);
+    }
+
     /// Toggle show logs panel
     pub fn toggle_logs_panel(&mut self) {
         self.show_logs_panel = !self.show_logs_panel;
@@ -156,6 +496,50 @@ impl Settings {
     pub fn toggle_sound(&mut self) {
         self.sound_enabled = !self.sound_enabled;
     }
+
+    /// Toggle native OS desktop notifications for cost/idle alerts
+    pub fn toggle_notifications(&mut self) {
+        self.notifications_enabled = !self.notifications_enabled;
+    }
+
+    /// Toggle whether graceful shutdown also stops any instance whose auto-stop
+    /// schedule has already elapsed (see `App::shutdown`)
+    pub fn toggle_stop_on_exit(&mut self) {
+        self.stop_on_exit = !self.stop_on_exit;
+    }
+
+    /// Whether file logging is currently enabled
+    pub fn file_logging_enabled(&self) -> bool {
+        self.log_outputs.iter().any(|o| matches!(o, LogOutput::File { .. }))
+    }
+
+    /// Whether Warning/Error entries are mirrored to stderr
+    pub fn stderr_logging_enabled(&self) -> bool {
+        self.log_outputs.iter().any(|o| matches!(o, LogOutput::Stderr { .. }))
+    }
+
+    /// Add or remove the `File` log sink, keeping the existing path/size if re-enabled
+    pub fn toggle_file_logging(&mut self) {
+        if self.file_logging_enabled() {
+            self.log_outputs.retain(|o| !matches!(o, LogOutput::File { .. }));
+        } else {
+            self.log_outputs.push(LogOutput::File { path: default_log_file_path(), max_size: 5 * 1024 * 1024 });
+        }
+    }
+
+    /// Add or remove the `Stderr` log sink
+    pub fn toggle_stderr_logging(&mut self) {
+        if self.stderr_logging_enabled() {
+            self.log_outputs.retain(|o| !matches!(o, LogOutput::Stderr { .. }));
+        } else {
+            self.log_outputs.push(LogOutput::Stderr { min_level: LogLevel::Warning });
+        }
+    }
+
+    /// Friendly display name for a profile, falling back to the raw profile name
+    pub fn profile_display_name<'a>(&'a self, profile: &'a str) -> &'a str {
+        self.profile_aliases.get(profile).map(String::as_str).unwrap_or(profile)
+    }
     
     /// Format refresh interval for display
     pub fn format_refresh_interval(&self) -> String {
@@ -178,7 +562,18 @@ impl Settings {
             format!("{}h", self.alert_threshold_secs / 3600)
         }
     }
-    
+
+    /// Format the session renewal threshold for display
+    pub fn format_session_renewal_threshold(&self) -> String {
+        if self.session_renewal_threshold_secs < 60 {
+            format!("{}s", self.session_renewal_threshold_secs)
+        } else if self.session_renewal_threshold_secs < 3600 {
+            format!("{}m", self.session_renewal_threshold_secs / 60)
+        } else {
+            format!("{}h", self.session_renewal_threshold_secs / 3600)
+        }
+    }
+
     /// Cycle log level to next value
     pub fn cycle_log_level(&mut self, forward: bool) {
         // Debug -> Info -> Warning -> Error
@@ -212,26 +607,35 @@ impl Settings {
         }
     }
     
-    /// Check if a log level should be displayed based on current setting
-    pub fn should_show_log(&self, level: LogLevel) -> bool {
-        match self.log_level {
-            LogLevel::Debug => true, // Show all
-            LogLevel::Info => !matches!(level, LogLevel::Debug),
-            LogLevel::Warning => matches!(level, LogLevel::Warning | LogLevel::Error),
-            LogLevel::Error => matches!(level, LogLevel::Error),
-            LogLevel::Success => !matches!(level, LogLevel::Debug),
-        }
+    /// Cycle the color palette to the next (or, going backward, previous) value
+    pub fn cycle_theme_palette(&mut self, forward: bool) {
+        self.theme_palette = if forward { self.theme_palette.next() } else { self.theme_palette.prev() };
     }
+
+    /// Format the color palette for display
+    pub fn format_theme_palette(&self) -> String {
+        self.theme_palette.label().to_string()
+    }
+
 }
 
 /// Which field in the settings dialog is currently selected
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SettingsField {
+    Profile,
+    ProfileAlias,
     RefreshInterval,
     ShowLogsPanel,
     LogLevel,
     AlertThreshold,
+    SessionRenewalThreshold,
     SoundEnabled,
+    NotificationsEnabled,
+    StopOnExit,
+    FileLogging,
+    StderrLogging,
+    FileFormat,
+    Theme,
     TestSound,
 }
 
@@ -239,24 +643,212 @@ impl SettingsField {
     /// Get the next field
     pub fn next(&self) -> Self {
         match self {
+            Self::Profile => Self::ProfileAlias,
+            Self::ProfileAlias => Self::RefreshInterval,
             Self::RefreshInterval => Self::ShowLogsPanel,
             Self::ShowLogsPanel => Self::LogLevel,
             Self::LogLevel => Self::AlertThreshold,
-            Self::AlertThreshold => Self::SoundEnabled,
-            Self::SoundEnabled => Self::TestSound,
-            Self::TestSound => Self::RefreshInterval,
+            Self::AlertThreshold => Self::SessionRenewalThreshold,
+            Self::SessionRenewalThreshold => Self::SoundEnabled,
+            Self::SoundEnabled => Self::NotificationsEnabled,
+            Self::NotificationsEnabled => Self::StopOnExit,
+            Self::StopOnExit => Self::FileLogging,
+            Self::FileLogging => Self::StderrLogging,
+            Self::StderrLogging => Self::FileFormat,
+            Self::FileFormat => Self::Theme,
+            Self::Theme => Self::TestSound,
+            Self::TestSound => Self::Profile,
         }
     }
-    
+
     /// Get the previous field
     pub fn prev(&self) -> Self {
         match self {
-            Self::RefreshInterval => Self::TestSound,
+            Self::Profile => Self::TestSound,
+            Self::ProfileAlias => Self::Profile,
+            Self::RefreshInterval => Self::ProfileAlias,
             Self::ShowLogsPanel => Self::RefreshInterval,
             Self::LogLevel => Self::ShowLogsPanel,
             Self::AlertThreshold => Self::LogLevel,
-            Self::SoundEnabled => Self::AlertThreshold,
-            Self::TestSound => Self::SoundEnabled,
+            Self::SessionRenewalThreshold => Self::AlertThreshold,
+            Self::SoundEnabled => Self::SessionRenewalThreshold,
+            Self::NotificationsEnabled => Self::SoundEnabled,
+            Self::StopOnExit => Self::NotificationsEnabled,
+            Self::FileLogging => Self::StopOnExit,
+            Self::StderrLogging => Self::FileLogging,
+            Self::FileFormat => Self::StderrLogging,
+            Self::Theme => Self::FileFormat,
+            Self::TestSound => Self::Theme,
+        }
+    }
+}
+
+/// A named bundle of settings a user can flip between in one keystroke - e.g. separate
+/// dev/staging/prod contexts. Wraps the same fields tracked by `Settings` plus the AWS
+/// region to use while that profile is active.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SettingsProfile {
+    #[serde(default)]
+    pub region: Option<String>,
+    #[serde(flatten)]
+    pub settings: Settings,
+}
+
+/// On-disk shape of `settings.json`: a named map of settings profiles plus which one is
+/// active. A pre-profile file (a bare `Settings` object) is migrated into a single
+/// `"default"` profile transparently the first time it's loaded.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SettingsStore {
+    pub current_profile: String,
+    pub profiles: HashMap<String, SettingsProfile>,
+    /// Which file (`settings.json` or `settings.toml`) this store was loaded from, and
+    /// will be written back to. Not itself persisted into the file - it's derived from
+    /// which one is on disk, see `Settings::resolve_settings_format()`.
+    #[serde(skip, default)]
+    pub format: SettingsFormat,
+}
+
+impl Default for SettingsStore {
+    fn default() -> Self {
+        let mut profiles = HashMap::new();
+        profiles.insert("default".to_string(), SettingsProfile { region: None, settings: Settings::default() });
+        Self { current_profile: "default".to_string(), profiles, format: SettingsFormat::default() }
+    }
+}
+
+impl SettingsStore {
+    /// Load the settings profile store, migrating a pre-profile flat `Settings` file
+    /// into a single `"default"` profile and persisting that migration back to disk.
+    /// Reads `settings.toml` if present, otherwise `settings.json`.
+    pub fn load() -> Result<Self> {
+        let format = Settings::resolve_settings_format()?;
+        let path = Settings::get_settings_path_for(format)?;
+
+        if !path.exists() {
+            let mut store = Self::default();
+            store.format = format;
+            store.save()?;
+            return Ok(store);
+        }
+
+        let contents = fs::read_to_string(&path).context("Failed to read settings file")?;
+
+        if let Some(mut store) = Self::deserialize(&contents, format) {
+            store.format = format;
+            return Ok(store);
+        }
+
+        let legacy: Settings = Self::deserialize_settings(&contents, format).context("Failed to parse settings file")?;
+        let mut profiles = HashMap::new();
+        profiles.insert("default".to_string(), SettingsProfile { region: None, settings: legacy });
+        let store = Self { current_profile: "default".to_string(), profiles, format };
+        store.save().context("Failed to save migrated settings file")?;
+        Ok(store)
+    }
+
+    fn deserialize(contents: &str, format: SettingsFormat) -> Option<Self> {
+        match format {
+            SettingsFormat::Json => serde_json::from_str(contents).ok(),
+            SettingsFormat::Toml => toml::from_str(contents).ok(),
+        }
+    }
+
+    fn deserialize_settings(contents: &str, format: SettingsFormat) -> Result<Settings> {
+        match format {
+            SettingsFormat::Json => serde_json::from_str(contents).context("Failed to parse settings file"),
+            SettingsFormat::Toml => toml::from_str(contents).context("Failed to parse settings file"),
+        }
+    }
+
+    /// Save the whole store back to whichever file it was loaded from
+    pub fn save(&self) -> Result<()> {
+        let path = Settings::get_settings_path_for(self.format)?;
+        let contents = match self.format {
+            SettingsFormat::Json => serde_json::to_string_pretty(self).context("Failed to serialize settings")?,
+            SettingsFormat::Toml => toml::to_string_pretty(self).context("Failed to serialize settings")?,
+        };
+        fs::write(&path, contents).context("Failed to write settings file")?;
+        Ok(())
+    }
+
+    /// Switch to the other persisted format (JSON <-> TOML), immediately rewriting the
+    /// file under its new extension and removing the stale one so the two files never
+    /// disagree about which is current
+    pub fn toggle_format(&mut self) -> Result<()> {
+        let old_path = Settings::get_settings_path_for(self.format)?;
+        self.format = self.format.other();
+        self.save()?;
+        if old_path.exists() {
+            fs::remove_file(&old_path).context("Failed to remove stale settings file")?;
+        }
+        Ok(())
+    }
+
+    /// Names of all known profiles, in a stable (sorted) order
+    pub fn list_profiles(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.profiles.keys().map(String::as_str).collect();
+        names.sort();
+        names
+    }
+
+    /// Settings for the currently active profile
+    pub fn active_settings(&self) -> Settings {
+        self.profiles.get(&self.current_profile).map(|p| p.settings.clone()).unwrap_or_default()
+    }
+
+    /// AWS region configured for the currently active profile, if any
+    pub fn active_region(&self) -> Option<String> {
+        self.profiles.get(&self.current_profile).and_then(|p| p.region.clone())
+    }
+
+    /// Switch the active profile, returning its settings. Errors if it doesn't exist.
+    pub fn switch_profile(&mut self, name: &str) -> Result<Settings> {
+        let profile = self.profiles.get(name).ok_or_else(|| anyhow!("No settings profile named '{}'", name))?;
+        self.current_profile = name.to_string();
+        Ok(profile.settings.clone())
+    }
+
+    /// Cycle to the next/previous profile name (alphabetically, wrapping around),
+    /// returning the newly active name and its settings
+    pub fn cycle_profile(&mut self, forward: bool) -> Result<(String, Settings)> {
+        let names = self.list_profiles();
+        if names.is_empty() {
+            return Err(anyhow!("No settings profiles configured"));
+        }
+        let current_idx = names.iter().position(|&n| n == self.current_profile).unwrap_or(0);
+        let new_idx = if forward {
+            (current_idx + 1) % names.len()
+        } else if current_idx == 0 {
+            names.len() - 1
+        } else {
+            current_idx - 1
+        };
+        let name = names[new_idx].to_string();
+        let settings = self.switch_profile(&name)?;
+        Ok((name, settings))
+    }
+
+    /// Add a new profile (or overwrite an existing one with the same name)
+    pub fn create_profile(&mut self, name: String, region: Option<String>, settings: Settings) {
+        self.profiles.insert(name, SettingsProfile { region, settings });
+    }
+
+    /// Remove a profile. Refuses to delete the active profile or the last remaining one.
+    pub fn delete_profile(&mut self, name: &str) -> Result<()> {
+        if self.profiles.len() <= 1 {
+            return Err(anyhow!("Can't delete the only remaining settings profile"));
+        }
+        if name == self.current_profile {
+            return Err(anyhow!("Can't delete the active profile; switch to another one first"));
+        }
+        self.profiles.remove(name);
+        Ok(())
+    }
+
+    /// Write the given settings back into the active profile's slot
+    pub fn update_active(&mut self, settings: Settings) {
+        if let Some(profile) = self.profiles.get_mut(&self.current_profile) {
+            profile.settings = settings;
         }
     }
 }
@@ -0,0 +1,350 @@
+//! SSH key management and an in-process ssh-agent for EC2 connections
+//!
+//! Keys are persisted as metadata only (label, path, algorithm) in the settings
+//! directory. Unlocking a key decrypts it from disk with a passphrase that is held
+//! only in memory for the session and fed into `SshAgent`, which answers the standard
+//! ssh-agent wire protocol over a per-session Unix socket. The spawned `ssh` process
+//! authenticates through that socket, so the decrypted key never touches disk.
+
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use ssh_key::PrivateKey;
+
+use crate::aws::Ec2Instance;
+use crate::settings::Settings;
+
+// Subset of the ssh-agent wire protocol (draft-miller-ssh-agent) this module needs.
+const SSH_AGENTC_REQUEST_IDENTITIES: u8 = 11;
+const SSH_AGENTC_SIGN_REQUEST: u8 = 13;
+const SSH_AGENT_FAILURE: u8 = 5;
+const SSH_AGENT_IDENTITIES_ANSWER: u8 = 12;
+const SSH_AGENT_SIGN_RESPONSE: u8 = 14;
+
+/// Supported SSH private key algorithms
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SshKeyType {
+    Rsa,
+    Ed25519,
+}
+
+impl SshKeyType {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Rsa => "RSA",
+            Self::Ed25519 => "Ed25519",
+        }
+    }
+
+    pub fn toggled(&self) -> Self {
+        match self {
+            Self::Rsa => Self::Ed25519,
+            Self::Ed25519 => Self::Rsa,
+        }
+    }
+}
+
+/// Metadata for a user SSH key. The private key material itself stays on disk
+/// (encrypted, if the user set a passphrase) and is only read into memory when unlocked.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SshKeyInfo {
+    pub label: String,
+    pub path: PathBuf,
+    pub key_type: SshKeyType,
+}
+
+/// Persisted list of known SSH keys
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SshKeyStore {
+    pub keys: Vec<SshKeyInfo>,
+}
+
+impl SshKeyStore {
+    fn store_path() -> Result<PathBuf> {
+        Ok(Settings::get_config_dir()?.join("ssh_keys.json"))
+    }
+
+    /// Load the key manifest, or an empty store if none has been saved yet
+    pub fn load() -> Self {
+        Self::store_path()
+            .ok()
+            .filter(|p| p.exists())
+            .and_then(|p| std::fs::read_to_string(p).ok())
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = Self::store_path()?;
+        let contents = serde_json::to_string_pretty(self).context("Failed to serialize SSH key store")?;
+        std::fs::write(path, contents).context("Failed to write SSH key store")?;
+        Ok(())
+    }
+
+    pub fn add_key(&mut self, info: SshKeyInfo) {
+        self.keys.retain(|k| k.label != info.label);
+        self.keys.push(info);
+    }
+
+    pub fn remove_key(&mut self, label: &str) {
+        self.keys.retain(|k| k.label != label);
+    }
+}
+
+/// Keys currently unlocked in memory, shared between the TUI thread and the
+/// ssh-agent's connection-handling threads
+#[derive(Clone, Default)]
+struct UnlockedKeys(Arc<Mutex<HashMap<String, PrivateKey>>>);
+
+impl UnlockedKeys {
+    fn insert(&self, label: &str, key: PrivateKey) {
+        self.0.lock().unwrap().insert(label.to_string(), key);
+    }
+
+    fn find_by_blob(&self, blob: &[u8]) -> Option<PrivateKey> {
+        self.0
+            .lock()
+            .unwrap()
+            .values()
+            .find(|key| key.public_key().to_bytes().map(|b| b == blob).unwrap_or(false))
+            .cloned()
+    }
+
+    fn all_blobs(&self) -> Vec<Vec<u8>> {
+        self.0
+            .lock()
+            .unwrap()
+            .values()
+            .filter_map(|key| key.public_key().to_bytes().ok())
+            .collect()
+    }
+}
+
+/// Handle to a running in-process ssh-agent: the Unix socket it listens on, plus a
+/// way to push newly-unlocked keys into it for the rest of the session.
+pub struct SshAgentHandle {
+    pub socket_path: PathBuf,
+    unlocked: UnlockedKeys,
+}
+
+impl SshAgentHandle {
+    /// Spawn the agent listening on a fresh per-session Unix socket
+    pub fn spawn() -> Result<Self> {
+        let socket_path = std::env::temp_dir()
+            .join(format!("aws-cloud-controller-ssh-agent-{}.sock", std::process::id()));
+        let _ = std::fs::remove_file(&socket_path); // stale socket from a crashed prior run
+
+        let listener = UnixListener::bind(&socket_path)
+            .with_context(|| format!("Failed to bind ssh-agent socket at {}", socket_path.display()))?;
+
+        let unlocked = UnlockedKeys::default();
+        let unlocked_for_thread = unlocked.clone();
+
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let unlocked = unlocked_for_thread.clone();
+                std::thread::spawn(move || {
+                    let _ = handle_connection(stream, &unlocked);
+                });
+            }
+        });
+
+        Ok(Self { socket_path, unlocked })
+    }
+
+    /// Decrypt a private key with the given passphrase and load it into the running agent
+    pub fn unlock_key(&self, info: &SshKeyInfo, passphrase: &str) -> Result<()> {
+        let contents = std::fs::read_to_string(&info.path)
+            .with_context(|| format!("Failed to read SSH key at {}", info.path.display()))?;
+        let key = PrivateKey::from_openssh(&contents).context("Failed to parse OpenSSH private key")?;
+        let key = if key.is_encrypted() {
+            key.decrypt(passphrase.as_bytes()).context("Incorrect passphrase")?
+        } else {
+            key
+        };
+        self.unlocked.insert(&info.label, key);
+        Ok(())
+    }
+}
+
+/// Serve ssh-agent requests on one accepted connection until it closes
+fn handle_connection(mut stream: UnixStream, unlocked: &UnlockedKeys) -> Result<()> {
+    loop {
+        let mut len_buf = [0u8; 4];
+        if stream.read_exact(&mut len_buf).is_err() {
+            return Ok(()); // client disconnected
+        }
+        let len = u32::from_be_bytes(len_buf) as usize;
+        let mut body = vec![0u8; len];
+        stream.read_exact(&mut body)?;
+
+        let response = match body.first().copied() {
+            Some(SSH_AGENTC_REQUEST_IDENTITIES) => encode_identities_answer(unlocked),
+            Some(SSH_AGENTC_SIGN_REQUEST) => encode_sign_response(&body[1..], unlocked),
+            _ => vec![SSH_AGENT_FAILURE],
+        };
+
+        stream.write_all(&(response.len() as u32).to_be_bytes())?;
+        stream.write_all(&response)?;
+    }
+}
+
+/// `SSH_AGENT_IDENTITIES_ANSWER`: count + (blob, comment) per unlocked key
+fn encode_identities_answer(unlocked: &UnlockedKeys) -> Vec<u8> {
+    let blobs = unlocked.all_blobs();
+    let mut out = vec![SSH_AGENT_IDENTITIES_ANSWER];
+    out.extend((blobs.len() as u32).to_be_bytes());
+    for blob in blobs {
+        out.extend((blob.len() as u32).to_be_bytes());
+        out.extend(blob);
+        out.extend(0u32.to_be_bytes()); // empty comment, already shown in our own key list
+    }
+    out
+}
+
+/// `SSH_AGENTC_SIGN_REQUEST` body (after the opcode byte): blob, data, flags
+fn encode_sign_response(body: &[u8], unlocked: &UnlockedKeys) -> Vec<u8> {
+    let Some((blob, rest)) = read_string(body) else { return vec![SSH_AGENT_FAILURE] };
+    let Some((data, _flags)) = read_string(rest) else { return vec![SSH_AGENT_FAILURE] };
+
+    let Some(key) = unlocked.find_by_blob(blob) else { return vec![SSH_AGENT_FAILURE] };
+    let Ok(signature) = key.try_sign(data) else { return vec![SSH_AGENT_FAILURE] };
+    let Ok(sig_blob) = signature.to_bytes() else { return vec![SSH_AGENT_FAILURE] };
+
+    let mut out = vec![SSH_AGENT_SIGN_RESPONSE];
+    out.extend((sig_blob.len() as u32).to_be_bytes());
+    out.extend(sig_blob);
+    out
+}
+
+/// Read a length-prefixed byte string, returning it and the remaining buffer
+fn read_string(buf: &[u8]) -> Option<(&[u8], &[u8])> {
+    if buf.len() < 4 {
+        return None;
+    }
+    let len = u32::from_be_bytes(buf[0..4].try_into().ok()?) as usize;
+    let rest = &buf[4..];
+    if rest.len() < len {
+        return None;
+    }
+    Some((&rest[..len], &rest[len..]))
+}
+
+/// An argv-style command to run in a freshly opened terminal window: a program, its
+/// arguments, and any environment variables to set for it (e.g. `SSH_AUTH_SOCK`). Kept
+/// structured rather than a pre-built shell string so values from outside our control
+/// (e.g. an EC2 `SshUser` tag, settable by anyone with `ec2:CreateTags`) can never be
+/// interpreted by a shell - `launch_terminal_command` is the only place that has to
+/// reason about quoting, and it does so per-platform below.
+pub struct TerminalCommand {
+    pub program: String,
+    pub args: Vec<String>,
+    pub env: Vec<(String, String)>,
+}
+
+impl TerminalCommand {
+    pub fn new(program: impl Into<String>) -> Self {
+        Self { program: program.into(), args: Vec::new(), env: Vec::new() }
+    }
+
+    pub fn arg(mut self, arg: impl Into<String>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    pub fn env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env.push((key.into(), value.into()));
+        self
+    }
+}
+
+/// Launch `cmd` in a new terminal window, cross-platform.
+///
+/// On Linux, `-e` is passed the program and its arguments directly with no shell in
+/// between, so nothing in `cmd.args` can ever be interpreted as shell syntax; any `env`
+/// vars are set on the terminal emulator process itself and inherited by the child it
+/// execs. macOS's `do script` and Windows' `cmd /K` both only accept a single command
+/// line, so a shell is unavoidable there - every component is quoted for that shell
+/// before being joined, so an untrusted value can only ever appear as one opaque
+/// argument, never break out into its own command.
+pub fn launch_terminal_command(cmd: &TerminalCommand) -> Result<()> {
+    let spawn_result = if cfg!(target_os = "macos") {
+        let script = format!(
+            "tell application \"Terminal\" to do script \"{}\"",
+            escape_applescript_string(&posix_shell_line(cmd))
+        );
+        std::process::Command::new("osascript").args(["-e", &script]).spawn()
+    } else if cfg!(target_os = "windows") {
+        std::process::Command::new("cmd")
+            .args(["/C", "start", "cmd", "/K", &cmd_shell_line(cmd)])
+            .spawn()
+    } else {
+        let mut command = std::process::Command::new("x-terminal-emulator");
+        for (key, value) in &cmd.env {
+            command.env(key, value);
+        }
+        command.arg("-e").arg(&cmd.program).args(&cmd.args);
+        command.spawn()
+    };
+
+    spawn_result.context("Failed to launch a terminal")?;
+    Ok(())
+}
+
+/// Quote `s` as a single POSIX shell word: wrap in single quotes, escaping any embedded
+/// single quote as `'\''`.
+fn posix_shell_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', r"'\''"))
+}
+
+/// Render `cmd` as one POSIX shell command line (`KEY='val' program 'arg'...`), every
+/// component individually quoted.
+fn posix_shell_line(cmd: &TerminalCommand) -> String {
+    let mut parts: Vec<String> = cmd.env.iter().map(|(k, v)| format!("{}={}", k, posix_shell_quote(v))).collect();
+    parts.push(posix_shell_quote(&cmd.program));
+    parts.extend(cmd.args.iter().map(|a| posix_shell_quote(a)));
+    parts.join(" ")
+}
+
+/// Escape `s` for embedding inside a double-quoted AppleScript string literal.
+fn escape_applescript_string(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Quote `s` as one `cmd.exe` argument: wrap in double quotes, doubling any embedded
+/// double quote and `%` (cmd.exe has no fully safe quoting, but this closes off the
+/// quote-breakout and `%VAR%`-expansion cases an untrusted tag value could otherwise hit).
+fn cmd_quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('"', "\"\"").replace('%', "%%"))
+}
+
+/// Render `cmd` as one `cmd.exe` command line (`set KEY="val" && program "arg"...`).
+fn cmd_shell_line(cmd: &TerminalCommand) -> String {
+    let mut parts: Vec<String> = cmd.env.iter().map(|(k, v)| format!("set {}={} &&", k, cmd_quote(v))).collect();
+    parts.push(cmd_quote(&cmd.program));
+    parts.extend(cmd.args.iter().map(|a| cmd_quote(a)));
+    parts.join(" ")
+}
+
+/// Build the `ssh` invocation for an instance and launch it in the user's terminal,
+/// pointing `SSH_AUTH_SOCK` at our in-process agent so `ssh` signs via it. `user` is
+/// the instance's `ssh_user_tag` if set and valid, else `Settings::ssh_default_user` -
+/// see `Ec2Instance::ssh_user`.
+pub fn launch_terminal_ssh(instance: &Ec2Instance, agent_socket: &Path, user: &str) -> Result<()> {
+    let host = instance
+        .public_ip
+        .clone()
+        .ok_or_else(|| anyhow::anyhow!("Instance {} has no public IP to connect to", instance.id))?;
+
+    let cmd = TerminalCommand::new("ssh")
+        .env("SSH_AUTH_SOCK", agent_socket.display().to_string())
+        .arg(format!("{}@{}", user, host));
+
+    launch_terminal_command(&cmd).context("Failed to launch a terminal for the SSH session")
+}
@@ -0,0 +1,180 @@
+//! Bridges `tracing` into the TUI's own log panel, plus optional OTLP export for the
+//! `#[tracing::instrument]` spans around AWS operations (see `app::actions`).
+//!
+//! `main.rs` pipes the `fmt` layer to `std::io::sink`, since a raw `tracing` dump would
+//! fight with the alternate-screen UI for stdout. [`log_bridge_layer`] is the real sink
+//! for everyday visibility: it turns `tracing` events (aws-sdk retries, hyper throttling,
+//! etc.) into `LogEntry` values on `LogManager`'s channel instead of dropping them.
+//! Configuring `Settings::otlp_endpoint` adds a second, richer sink on top of that.
+//!
+//! [`build_env_filter`] gates both [`rolling_file_layer`] and [`log_bridge_layer`] behind
+//! one reloadable `EnvFilter` (`RUST_LOG`, defaulting to [`DEFAULT_FILTER_DIRECTIVE`]), so
+//! `App::toggle_verbose_tracing` can flip them both to `debug` at once without a restart.
+
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use opentelemetry::trace::TracerProvider as _;
+use tracing::field::{Field, Visit};
+use tracing_appender::rolling::{Builder as RollingBuilder, Rotation};
+use tracing_subscriber::layer::Filter;
+use tracing_subscriber::{EnvFilter, Layer, Registry};
+
+use crate::logger::{LogEntry, LogLevel};
+
+/// Type-erased layer so `main.rs` can hold either "no exporter" or "OTLP exporter"
+/// behind one `tracing_subscriber::reload::Layer` without naming the concrete type.
+pub type BoxedLayer = Box<dyn Layer<tracing_subscriber::Registry> + Send + Sync>;
+
+/// How many daily rolled-over files to keep under `log_dir()` before the oldest is
+/// deleted; the in-memory `LogManager` ring buffer and its own JSONL history already
+/// cap themselves, but this is the raw `tracing` firehose (every span/event, not just
+/// what code explicitly logs), so it needs its own retention.
+const RETAINED_LOG_FILES: usize = 7;
+
+/// Directory for the rolling `tracing` log files: `<data dir>/aws-cloud-controller/logs`.
+/// Distinct from `Settings::get_config_dir` (used by `settings.json`/`history.jsonl` and
+/// the user-configurable `LogOutput::File` sink) since this is unstructured, high-volume
+/// `tracing` output meant for post-crash debugging, not something a user points at.
+pub fn log_dir() -> PathBuf {
+    dirs::data_dir()
+        .map(|d| d.join("aws-cloud-controller").join("logs"))
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+/// Default `EnvFilter` directive when `RUST_LOG` isn't set: everyday visibility without
+/// the aws-sdk/hyper retry-and-throttling chatter that would otherwise flood both the
+/// rolling file and the in-app Logs tab.
+pub const DEFAULT_FILTER_DIRECTIVE: &str = "info,hyper=warn,aws_smithy_runtime=warn";
+
+/// What [`crate::app::App::toggle_verbose_tracing`] swaps the filter to: still quiets
+/// `hyper`/`aws_smithy_runtime` at `warn`, since even in verbose mode their retry loops
+/// are noise, not signal.
+pub const VERBOSE_FILTER_DIRECTIVE: &str = "debug,hyper=warn,aws_smithy_runtime=warn";
+
+/// Build the `EnvFilter` gating what reaches [`rolling_file_layer`] and
+/// [`log_bridge_layer`]: `RUST_LOG` if set, otherwise [`DEFAULT_FILTER_DIRECTIVE`].
+pub fn build_env_filter() -> EnvFilter {
+    EnvFilter::try_from_env("RUST_LOG").unwrap_or_else(|_| EnvFilter::new(DEFAULT_FILTER_DIRECTIVE))
+}
+
+/// Build the rolling on-disk `tracing` file appender: ANSI-free `timestamp [LEVEL]
+/// target: message` lines, rotated daily, retaining at most `RETAINED_LOG_FILES` days.
+/// `filter` is the reloadable `EnvFilter` built by [`build_env_filter`], shared with
+/// [`log_bridge_layer`] so `RUST_LOG`/the verbose-tracing toggle govern both the same way.
+/// Returns the layer to add to the registry; its files land in `log_dir()`, which is
+/// surfaced on the About screen so users can find it.
+pub fn rolling_file_layer(filter: impl Filter<Registry> + Send + Sync + 'static) -> Result<BoxedLayer> {
+    let dir = log_dir();
+    fs::create_dir_all(&dir).with_context(|| format!("failed to create log directory {}", dir.display()))?;
+
+    let appender = RollingBuilder::new()
+        .rotation(Rotation::DAILY)
+        .filename_prefix("aws-cloud-controller")
+        .filename_suffix("log")
+        .max_log_files(RETAINED_LOG_FILES)
+        .build(&dir)
+        .with_context(|| format!("failed to build rolling file appender in {}", dir.display()))?;
+
+    let layer = tracing_subscriber::fmt::layer()
+        .with_ansi(false)
+        .with_target(true)
+        .with_writer(appender)
+        .with_filter(filter);
+
+    Ok(Box::new(layer))
+}
+
+/// Build a layer that exports spans to the OTLP collector at `endpoint`
+/// (e.g. "http://localhost:4317") over gRPC.
+pub fn otlp_layer(endpoint: &str) -> Result<BoxedLayer> {
+    let exporter = opentelemetry_otlp::SpanExporter::builder()
+        .with_tonic()
+        .with_endpoint(endpoint)
+        .build()
+        .context("failed to build OTLP span exporter")?;
+
+    let provider = opentelemetry_sdk::trace::TracerProvider::builder()
+        .with_batch_exporter(exporter, opentelemetry_sdk::runtime::Tokio)
+        .build();
+
+    let tracer = provider.tracer("aws-cloud-controller");
+    Ok(Box::new(tracing_opentelemetry::layer().with_tracer(tracer)))
+}
+
+/// A `tracing_subscriber::Layer` that forwards every event into a channel as a
+/// `LogEntry`, so `App`'s per-tick drain (see `check_async_notifications`) can push it
+/// into `LogManager` without the layer needing to touch `App` directly - it runs on
+/// whichever thread the event fired on, which for aws-sdk/hyper is a Tokio worker, not
+/// the main loop's thread.
+pub struct LogBridgeLayer {
+    tx: std::sync::mpsc::Sender<LogEntry>,
+}
+
+/// Build a `LogBridgeLayer` and the receiving end of its channel. The receiver should be
+/// drained once per tick, same as `App::async_rx`.
+pub fn log_bridge_layer() -> (LogBridgeLayer, std::sync::mpsc::Receiver<LogEntry>) {
+    let (tx, rx) = std::sync::mpsc::channel();
+    (LogBridgeLayer { tx }, rx)
+}
+
+impl<S: tracing::Subscriber> Layer<S> for LogBridgeLayer {
+    fn on_event(&self, event: &tracing::Event<'_>, _ctx: tracing_subscriber::layer::Context<'_, S>) {
+        let level = match *event.metadata().level() {
+            tracing::Level::TRACE | tracing::Level::DEBUG => LogLevel::Debug,
+            tracing::Level::INFO => LogLevel::Info,
+            tracing::Level::WARN => LogLevel::Warning,
+            tracing::Level::ERROR => LogLevel::Error,
+        };
+
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        let fields: String = visitor.into();
+        let message = if fields.is_empty() {
+            event.metadata().target().to_string()
+        } else {
+            format!("{}: {}", event.metadata().target(), fields)
+        };
+
+        let _ = self.tx.send(LogEntry::new(level, message));
+    }
+}
+
+/// Collects an event's fields into a single message string: the `message` field (if
+/// present) followed by any remaining fields as `key=value`, mirroring how `tracing`'s
+/// own `fmt` layer renders a line.
+#[derive(Default)]
+struct MessageVisitor {
+    message: Option<String>,
+    extra: String,
+}
+
+impl MessageVisitor {
+    fn push_extra(&mut self, field: &Field, value: String) {
+        if !self.extra.is_empty() {
+            self.extra.push(' ');
+        }
+        self.extra.push_str(&format!("{}={}", field.name(), value));
+    }
+}
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.message = Some(format!("{:?}", value));
+        } else {
+            self.push_extra(field, format!("{:?}", value));
+        }
+    }
+}
+
+impl From<MessageVisitor> for String {
+    fn from(visitor: MessageVisitor) -> Self {
+        match visitor.message {
+            Some(message) if visitor.extra.is_empty() => message,
+            Some(message) => format!("{} {}", message, visitor.extra),
+            None => visitor.extra,
+        }
+    }
+}
@@ -0,0 +1,282 @@
+//! Color theme palette, switchable between dark, light, and high-contrast variants
+//!
+//! Centralizes the colors used across the UI so the whole TUI can be
+//! re-skinned from one place instead of hardcoding `Color::*` in every
+//! `render_*` function.
+
+use std::collections::BTreeMap;
+
+use ratatui::style::{Color, Style};
+use serde::{Deserialize, Serialize};
+
+use crate::logger::LogLevel;
+
+/// Which color palette `theme_styles` builds. Persisted on `Settings` and cyclable from
+/// the settings dialog (`SettingsField::Theme`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ThemePalette {
+    #[default]
+    Dark,
+    Light,
+    /// Maximized contrast (pure black/white/primary colors, no mid-tone grays) for
+    /// colorblind/low-vision use, and ASCII markers in place of emoji icons - some
+    /// screen readers and high-contrast terminal fonts don't render emoji reliably.
+    HighContrast,
+}
+
+impl ThemePalette {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Dark => Self::Light,
+            Self::Light => Self::HighContrast,
+            Self::HighContrast => Self::Dark,
+        }
+    }
+
+    pub fn prev(self) -> Self {
+        match self {
+            Self::Dark => Self::HighContrast,
+            Self::Light => Self::Dark,
+            Self::HighContrast => Self::Light,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Dark => "Dark",
+            Self::Light => "Light",
+            Self::HighContrast => "High Contrast",
+        }
+    }
+
+    /// Whether icons should render as plain ASCII markers instead of emoji
+    pub fn ascii_icons(self) -> bool {
+        matches!(self, Self::HighContrast)
+    }
+}
+
+// Dark-terminal palette
+const COLOR_CYAN: Color = Color::Rgb(0, 230, 230);
+const COLOR_BLUE: Color = Color::Rgb(70, 130, 230);
+const COLOR_RED: Color = Color::Rgb(230, 70, 70);
+const COLOR_YELLOW: Color = Color::Rgb(230, 200, 0);
+const COLOR_GREEN: Color = Color::Rgb(60, 200, 100);
+const COLOR_MAGENTA: Color = Color::Rgb(200, 90, 210);
+const COLOR_GRAY: Color = Color::Rgb(150, 150, 150);
+const COLOR_WHITE: Color = Color::Rgb(230, 230, 230);
+const COLOR_BLACK: Color = Color::Rgb(10, 10, 10);
+
+// Light-terminal variants (darker shades so text stays legible on a light background)
+const COLOR_CYAN_DARK: Color = Color::Rgb(0, 120, 130);
+const COLOR_BLUE_DARK: Color = Color::Rgb(20, 60, 150);
+const COLOR_RED_DARK: Color = Color::Rgb(170, 30, 30);
+const COLOR_YELLOW_DARK: Color = Color::Rgb(150, 110, 0);
+const COLOR_GREEN_DARK: Color = Color::Rgb(20, 120, 60);
+const COLOR_MAGENTA_DARK: Color = Color::Rgb(130, 40, 140);
+const COLOR_GRAY_DARK: Color = Color::Rgb(90, 90, 90);
+const COLOR_WHITE_DARK: Color = Color::Rgb(20, 20, 20);
+const COLOR_BLACK_DARK: Color = Color::Rgb(245, 245, 245);
+
+/// Semantic style slots shared across the UI
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Styles {
+    Default,
+    Logo,
+    Failure,
+    Warning,
+    Success,
+    Primary,
+    Secondary,
+    Help,
+    Background,
+    /// `LogLevel::Debug` entries in the Logs tab
+    LevelDebug,
+    /// `LogLevel::Info` entries in the Logs tab
+    LevelInfo,
+}
+
+/// Build the style palette for the chosen variant
+pub fn theme_styles(palette: ThemePalette) -> BTreeMap<Styles, Style> {
+    let mut map = BTreeMap::new();
+
+    match palette {
+        ThemePalette::Light => {
+            map.insert(Styles::Default, Style::default().fg(COLOR_WHITE_DARK));
+            map.insert(Styles::Logo, Style::default().fg(COLOR_CYAN_DARK));
+            map.insert(Styles::Failure, Style::default().fg(COLOR_RED_DARK));
+            map.insert(Styles::Warning, Style::default().fg(COLOR_YELLOW_DARK));
+            map.insert(Styles::Success, Style::default().fg(COLOR_GREEN_DARK));
+            map.insert(Styles::Primary, Style::default().fg(COLOR_BLUE_DARK));
+            map.insert(Styles::Secondary, Style::default().fg(COLOR_GRAY_DARK));
+            map.insert(Styles::Help, Style::default().fg(COLOR_MAGENTA_DARK));
+            map.insert(Styles::Background, Style::default().bg(COLOR_BLACK_DARK));
+            map.insert(Styles::LevelDebug, Style::default().fg(COLOR_MAGENTA_DARK));
+            map.insert(Styles::LevelInfo, Style::default().fg(COLOR_CYAN_DARK));
+        }
+        ThemePalette::HighContrast => {
+            // Pure primaries only - no mid-tone grays or blended RGB shades, so every
+            // slot stays distinguishable under color-deficient vision and on terminals
+            // that don't render 24-bit color faithfully.
+            map.insert(Styles::Default, Style::default().fg(Color::White));
+            map.insert(Styles::Logo, Style::default().fg(Color::White).add_modifier(ratatui::style::Modifier::BOLD));
+            map.insert(Styles::Failure, Style::default().fg(Color::Red).add_modifier(ratatui::style::Modifier::BOLD));
+            map.insert(Styles::Warning, Style::default().fg(Color::Yellow).add_modifier(ratatui::style::Modifier::BOLD));
+            map.insert(Styles::Success, Style::default().fg(Color::Green).add_modifier(ratatui::style::Modifier::BOLD));
+            map.insert(Styles::Primary, Style::default().fg(Color::Cyan).add_modifier(ratatui::style::Modifier::BOLD));
+            map.insert(Styles::Secondary, Style::default().fg(Color::White));
+            map.insert(Styles::Help, Style::default().fg(Color::Magenta).add_modifier(ratatui::style::Modifier::BOLD));
+            map.insert(Styles::Background, Style::default().bg(Color::Black));
+            map.insert(Styles::LevelDebug, Style::default().fg(Color::Magenta).add_modifier(ratatui::style::Modifier::BOLD));
+            map.insert(Styles::LevelInfo, Style::default().fg(Color::White));
+        }
+        ThemePalette::Dark => {
+            map.insert(Styles::Default, Style::default().fg(COLOR_WHITE));
+            map.insert(Styles::Logo, Style::default().fg(COLOR_CYAN));
+            map.insert(Styles::Failure, Style::default().fg(COLOR_RED));
+            map.insert(Styles::Warning, Style::default().fg(COLOR_YELLOW));
+            map.insert(Styles::Success, Style::default().fg(COLOR_GREEN));
+            map.insert(Styles::Primary, Style::default().fg(COLOR_BLUE));
+            map.insert(Styles::Secondary, Style::default().fg(COLOR_GRAY));
+            map.insert(Styles::Help, Style::default().fg(COLOR_MAGENTA));
+            map.insert(Styles::Background, Style::default().bg(COLOR_BLACK));
+            map.insert(Styles::LevelDebug, Style::default().fg(COLOR_MAGENTA));
+            map.insert(Styles::LevelInfo, Style::default().fg(COLOR_CYAN));
+        }
+    }
+
+    map
+}
+
+/// The style and icon `render_logs` shows for one `LogLevel`, honoring
+/// `ThemePalette::ascii_icons` for accessibility.
+pub fn level_style_and_icon(theme: &BTreeMap<Styles, Style>, palette: ThemePalette, level: LogLevel) -> (Style, &'static str) {
+    let ascii = palette.ascii_icons();
+    match level {
+        LogLevel::Debug => (style_of(theme, Styles::LevelDebug), if ascii { "D" } else { "🔍" }),
+        LogLevel::Info => (style_of(theme, Styles::LevelInfo), if ascii { "i" } else { "ℹ" }),
+        LogLevel::Success => (style_of(theme, Styles::Success), if ascii { "+" } else { "✓" }),
+        LogLevel::Warning => (style_of(theme, Styles::Warning), if ascii { "!" } else { "⚠" }),
+        LogLevel::Error => (style_of(theme, Styles::Failure), if ascii { "x" } else { "✗" }),
+    }
+}
+
+/// Look up a style in the theme map, falling back to `Styles::Default`
+pub fn style_of(theme: &BTreeMap<Styles, Style>, key: Styles) -> Style {
+    theme
+        .get(&key)
+        .copied()
+        .unwrap_or_else(|| theme.get(&Styles::Default).copied().unwrap_or_default())
+}
+
+/// A user-editable color, serialized as a plain lowercase string for one of the 16
+/// named terminal colors (e.g. `"cyan"`), `{"ansi": 208}` for an indexed terminal color,
+/// or `{"rgb": {"r": 0, "g": 230, "b": 230}}` for a true-color value - whatever a hand
+/// edit to `settings.json`/`settings.toml` finds most natural to write. Used by
+/// `Settings::status_bar_colors` so colorblind or light-terminal users can restyle the
+/// status bar without a code change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ColorSpec {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    Gray,
+    DarkGray,
+    LightRed,
+    LightGreen,
+    LightYellow,
+    LightBlue,
+    LightMagenta,
+    LightCyan,
+    White,
+    /// One of the 256 indexed terminal colors
+    Ansi(u8),
+    /// A 24-bit true-color value
+    Rgb { r: u8, g: u8, b: u8 },
+}
+
+impl ColorSpec {
+    pub fn to_color(self) -> Color {
+        match self {
+            Self::Black => Color::Black,
+            Self::Red => Color::Red,
+            Self::Green => Color::Green,
+            Self::Yellow => Color::Yellow,
+            Self::Blue => Color::Blue,
+            Self::Magenta => Color::Magenta,
+            Self::Cyan => Color::Cyan,
+            Self::Gray => Color::Gray,
+            Self::DarkGray => Color::DarkGray,
+            Self::LightRed => Color::LightRed,
+            Self::LightGreen => Color::LightGreen,
+            Self::LightYellow => Color::LightYellow,
+            Self::LightBlue => Color::LightBlue,
+            Self::LightMagenta => Color::LightMagenta,
+            Self::LightCyan => Color::LightCyan,
+            Self::White => Color::White,
+            Self::Ansi(i) => Color::Indexed(i),
+            Self::Rgb { r, g, b } => Color::Rgb(r, g, b),
+        }
+    }
+}
+
+/// Named color slots for the status bar and its control-hint key caps (see
+/// `ui::components::statusbar::render_status_bar`), persisted on `Settings` and editable
+/// by hand in the settings file - independent of `ThemePalette`, which recolors the rest
+/// of the UI in one step rather than slot-by-slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusBarColors {
+    /// Key-cap label text and other accented highlights
+    pub accent: ColorSpec,
+    /// The "N alerts" counter
+    pub alert: ColorSpec,
+    /// Status bar and control-hint block borders
+    pub border: ColorSpec,
+    /// Background behind each control-hint key cap
+    pub keycap_bg: ColorSpec,
+    /// The "Next refresh: Ns" countdown
+    pub timer: ColorSpec,
+}
+
+impl Default for StatusBarColors {
+    fn default() -> Self {
+        Self {
+            accent: ColorSpec::Cyan,
+            alert: ColorSpec::Red,
+            border: ColorSpec::DarkGray,
+            keycap_bg: ColorSpec::Cyan,
+            timer: ColorSpec::Cyan,
+        }
+    }
+}
+
+/// Parse a `#rrggbb` (or `rrggbb`) hex string into a color, for user-supplied overrides
+pub fn parse_hex_color(hex: &str) -> Option<Color> {
+    let hex = hex.trim().trim_start_matches('#');
+    if hex.len() != 6 {
+        return None;
+    }
+    let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
+    let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
+    let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+    Some(Color::Rgb(r, g, b))
+}
+
+/// Patch a handful of named accent slots (`status`, `accent`, `warning`, `error`) onto
+/// an existing theme map, for overrides loaded from `keymap.toml`
+pub fn apply_accent_overrides(theme: &mut BTreeMap<Styles, Style>, overrides: &[(&'static str, Color)]) {
+    for (name, color) in overrides {
+        let slot = match *name {
+            "status" => Styles::Primary,
+            "accent" => Styles::Help,
+            "warning" => Styles::Warning,
+            "error" => Styles::Failure,
+            _ => continue,
+        };
+        theme.insert(slot, Style::default().fg(*color));
+    }
+}
@@ -1,39 +1,71 @@
 //! Terminal User Interface setup and teardown
 
 use std::io::{stdout, Stdout};
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use anyhow::Result;
 use crossterm::{
     execute,
+    cursor::Show,
     event::{DisableMouseCapture, EnableMouseCapture},
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use ratatui::{backend::CrosstermBackend, Terminal};
+use ratatui::{backend::CrosstermBackend, Terminal, TerminalOptions, Viewport};
 
 /// Type alias for our terminal backend
 pub type Tui = Terminal<CrosstermBackend<Stdout>>;
 
-/// Initialize the terminal for TUI rendering
+/// Whether the terminal currently owns the alternate screen with mouse capture enabled.
+/// `Viewport::Inline`/`Viewport::Fixed` skip both (an inline status strip shouldn't take
+/// over the screen or swallow scroll-wheel events meant for the surrounding shell), so
+/// `restore()` needs to know which teardown steps actually apply.
+static FULLSCREEN: AtomicBool = AtomicBool::new(true);
+
+/// Initialize the terminal for full-screen TUI rendering (the normal interactive mode)
 pub fn init() -> Result<Tui> {
-    execute!(stdout(), EnterAlternateScreen, EnableMouseCapture)?;
+    init_with_options(Viewport::Fullscreen)
+}
+
+/// Initialize the terminal with a specific `Viewport`. `Viewport::Fullscreen` behaves
+/// exactly like `init()`; `Viewport::Inline(rows)` or `Viewport::Fixed(rect)` render in
+/// place in the existing scrollback instead, e.g. for a compact N-line status strip
+/// embedded in a normal shell session.
+pub fn init_with_options(viewport: Viewport) -> Result<Tui> {
+    let fullscreen = matches!(viewport, Viewport::Fullscreen);
+    FULLSCREEN.store(fullscreen, Ordering::SeqCst);
+
+    if fullscreen {
+        execute!(stdout(), EnterAlternateScreen, EnableMouseCapture)?;
+    }
     enable_raw_mode()?;
-    
-    let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
-    terminal.clear()?;
-    
-    // Set up panic hook to restore terminal on crash
+
+    let mut terminal = Terminal::with_options(CrosstermBackend::new(stdout()), TerminalOptions { viewport })?;
+    if fullscreen {
+        terminal.clear()?;
+    }
+
+    install_panic_hook();
+
+    Ok(terminal)
+}
+
+/// Install a panic hook that restores a clean terminal before chaining onto the
+/// default hook, so a panic inside a `render_*` function never leaves the shell
+/// stuck in raw mode on the alternate screen.
+pub fn install_panic_hook() {
     let original_hook = std::panic::take_hook();
     std::panic::set_hook(Box::new(move |panic_info| {
         let _ = restore();
         original_hook(panic_info);
     }));
-    
-    Ok(terminal)
 }
 
 /// Restore terminal to normal state
 pub fn restore() -> Result<()> {
     disable_raw_mode()?;
-    execute!(stdout(), LeaveAlternateScreen, DisableMouseCapture)?;
+    if FULLSCREEN.load(Ordering::SeqCst) {
+        execute!(stdout(), LeaveAlternateScreen, DisableMouseCapture)?;
+    }
+    execute!(stdout(), Show)?;
     Ok(())
 }
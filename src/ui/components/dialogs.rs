@@ -6,7 +6,8 @@ use ratatui::{
     Frame,
 };
 
-use crate::app::{App, Dialog};
+use crate::app::actions::AUTO_STOP_PRESETS;
+use crate::app::{AlertSeverity, App, AutoStopField, AutoStopMode, Dialog, LaunchInstanceField, SshAddField, AUTO_STOP_DAY_ORDER};
 use crate::settings::SettingsField;
 use crate::ui::utils::{centered_rect, pad_rect};
 
@@ -14,34 +15,50 @@ use crate::ui::utils::{centered_rect, pad_rect};
 pub fn render_dialog(frame: &mut Frame, app: &App) {
     let (area_size, title, content, style) = match &app.dialog {
         Dialog::Help => {
-            let help_content = vec![
+            let mut help_content = vec![
                 Line::from(""),
+                Line::from(Span::styled(
+                    format!("Binding set: {:?}", app.keymap.binding_set()),
+                    Style::default().fg(Color::DarkGray),
+                )),
+            ];
+            if !app.keymap.conflicts().is_empty() {
+                help_content.push(Line::from(Span::styled(
+                    format!("{} conflicting binding(s) ignored, see logs", app.keymap.conflicts().len()),
+                    Style::default().fg(Color::Red),
+                )));
+            }
+            help_content.push(Line::from(""));
+            help_content.extend(vec![
                 Line::from(Span::styled("Navigation", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))),
                 Line::from(""),
-                Line::from(vec![Span::styled("  1/2/3     ", Style::default().fg(Color::Yellow)), Span::raw("Switch tabs (Home/EC2/Lambda)")]),
-                Line::from(vec![Span::styled("  ↑/↓ j/k   ", Style::default().fg(Color::Yellow)), Span::raw("Navigate list")]),
+                Line::from(vec![Span::styled("  1/2/3/4   ", Style::default().fg(Color::Yellow)), Span::raw("Switch tabs (Home/EC2/Lambda/Auto Scaling)")]),
                 Line::from(vec![Span::styled("  Enter     ", Style::default().fg(Color::Yellow)), Span::raw("Select / Confirm")]),
-                Line::from(vec![Span::styled("  r         ", Style::default().fg(Color::Yellow)), Span::raw("Refresh data")]),
-                Line::from(""),
-                Line::from(Span::styled("EC2 Controls", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD))),
-                Line::from(""),
-                Line::from(vec![Span::styled("  s         ", Style::default().fg(Color::Yellow)), Span::raw("Start instance")]),
-                Line::from(vec![Span::styled("  x         ", Style::default().fg(Color::Yellow)), Span::raw("Stop instance")]),
-                Line::from(vec![Span::styled("  t         ", Style::default().fg(Color::Yellow)), Span::raw("Terminate instance")]),
-                Line::from(vec![Span::styled("  a         ", Style::default().fg(Color::Yellow)), Span::raw("Schedule auto-stop (1 hour)")]),
-                Line::from(""),
-                Line::from(Span::styled("General", Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD))),
-                Line::from(""),
-                Line::from(vec![Span::styled("  ?/h       ", Style::default().fg(Color::Yellow)), Span::raw("Show this help")]),
-                Line::from(vec![Span::styled("  q         ", Style::default().fg(Color::Red)), Span::raw("Quit application")]),
-                Line::from(""),
-                Line::from(""),
-                Line::from(vec![
-                    Span::raw("          "),
-                    Span::styled("[Enter/q/Esc]", Style::default().fg(Color::Green)),
-                    Span::raw(" Close"),
-                ]),
-            ];
+                Line::from(vec![Span::styled("  [ / ]     ", Style::default().fg(Color::Yellow)), Span::raw("Previous / next open workspace (profile+region)")]),
+                Line::from(vec![Span::styled("  /         ", Style::default().fg(Color::Yellow)), Span::raw("Live search - filter the current list/log by name, ID, or message")]),
+            ]);
+
+            for (title, actions) in crate::keymap::Action::GROUPS {
+                help_content.push(Line::from(""));
+                let color = if *title == "General" { Color::Magenta } else { Color::Green };
+                help_content.push(Line::from(Span::styled(*title, Style::default().fg(color).add_modifier(Modifier::BOLD))));
+                help_content.push(Line::from(""));
+                for action in *actions {
+                    let hint_color = if *action == crate::keymap::Action::Quit { Color::Red } else { Color::Yellow };
+                    help_content.push(Line::from(vec![
+                        Span::styled(format!("  {:10} ", app.keymap.hint_for(*action)), Style::default().fg(hint_color)),
+                        Span::raw(action.label()),
+                    ]));
+                }
+            }
+
+            help_content.push(Line::from(""));
+            help_content.push(Line::from(""));
+            help_content.push(Line::from(vec![
+                Span::raw("          "),
+                Span::styled("[Enter/q/Esc]", Style::default().fg(Color::Green)),
+                Span::raw(" Close"),
+            ]));
             ((60, 60), " ⌨️  Keyboard Shortcuts ", help_content, Style::default().fg(Color::Cyan))
         }
         Dialog::ConfirmTerminate(id) => (
@@ -74,44 +91,185 @@ pub fn render_dialog(frame: &mut Frame, app: &App) {
             ],
             Style::default().fg(Color::Red),
         ),
-        Dialog::ScheduleAutoStop(id) => (
+        Dialog::ConfirmDetachInstance(group_name, instance_id) => (
             (50, 30),
-            " ⏰ Schedule Auto-Stop ",
+            " ⚠️  Confirm Detach ",
             vec![
                 Line::from(""),
-                Line::from(format!("Instance: {}", id)),
+                Line::from(Span::styled(
+                    "Detach this instance from its Auto Scaling Group?",
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                )),
                 Line::from(""),
-                Line::from("Default: Stop in 1 hour"),
+                Line::from(vec![
+                    Span::raw("Instance: "),
+                    Span::styled(instance_id.clone(), Style::default().fg(Color::Yellow)),
+                ]),
+                Line::from(vec![
+                    Span::raw("Group:    "),
+                    Span::styled(group_name.clone(), Style::default().fg(Color::Yellow)),
+                ]),
                 Line::from(""),
                 Line::from(Span::styled(
-                    "(Custom durations coming soon)",
+                    "Desired capacity will be decremented by one.",
                     Style::default().fg(Color::DarkGray),
                 )),
                 Line::from(""),
                 Line::from(vec![
                     Span::styled("[Enter]", Style::default().fg(Color::Green)),
-                    Span::raw(" Schedule   "),
+                    Span::raw(" Confirm   "),
                     Span::styled("[q/Esc]", Style::default().fg(Color::Red)),
                     Span::raw(" Cancel"),
                 ]),
             ],
-            Style::default().fg(Color::Cyan),
+            Style::default().fg(Color::Yellow),
         ),
-        Dialog::Alert(msg) => (
-            (50, 25),
-            " 🔔 Alert ",
-            vec![
-                Line::from(""),
-                Line::from(Span::styled(msg.clone(), Style::default().fg(Color::Yellow))),
+        Dialog::ScheduleAutoStop(id) => {
+            // Blink roughly twice a second
+            let caret = if app.ui_tick / 2 % 2 == 0 { "_" } else { " " };
+            let custom_selected = app.auto_stop_custom_selected();
+
+            let field_label_style = |field: AutoStopField| {
+                if app.auto_stop_field == field {
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::White)
+                }
+            };
+            let field_marker = |field: AutoStopField| if app.auto_stop_field == field { "▶ " } else { "  " };
+
+            let mut schedule_content = vec![
                 Line::from(""),
+                Line::from(format!("Instance: {}", id)),
                 Line::from(""),
                 Line::from(vec![
+                    Span::styled(field_marker(AutoStopField::Mode), Style::default().fg(Color::Yellow)),
+                    Span::styled(format!("{:10}", "Mode"), field_label_style(AutoStopField::Mode)),
+                    Span::styled(format!("< {} >", app.auto_stop_mode.label()), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                ]),
+                Line::from(vec![
+                    Span::styled(field_marker(AutoStopField::Action), Style::default().fg(Color::Yellow)),
+                    Span::styled(format!("{:10}", "Action"), field_label_style(AutoStopField::Action)),
+                    Span::styled(format!("< {} >", app.auto_stop_action.label()), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                ]),
+                Line::from(""),
+            ];
+
+            match app.auto_stop_mode {
+                AutoStopMode::Duration => {
+                    let (preset_label, _) = AUTO_STOP_PRESETS[app.auto_stop_preset_index];
+                    schedule_content.push(Line::from(vec![
+                        Span::styled(field_marker(AutoStopField::Preset), Style::default().fg(Color::Yellow)),
+                        Span::styled(format!("{:10}", "Duration"), field_label_style(AutoStopField::Preset)),
+                        Span::styled(format!("< {} >", preset_label), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                    ]));
+                    schedule_content.push(Line::from(""));
+
+                    if custom_selected {
+                        schedule_content.push(Line::from("Custom duration (e.g. 30m, 2h, 1h30m):"));
+                        schedule_content.push(Line::from(vec![
+                            Span::styled("> ", Style::default().fg(Color::Cyan)),
+                            Span::styled(app.auto_stop_duration_input.as_str(), Style::default().fg(Color::White).add_modifier(Modifier::BOLD)),
+                            Span::styled(caret, Style::default().fg(Color::DarkGray)),
+                        ]));
+                        schedule_content.push(Line::from(""));
+                    }
+                }
+                AutoStopMode::Recurring => {
+                    schedule_content.push(Line::from(Span::styled("Days:", Style::default().fg(Color::White))));
+                    let day_spans: Vec<Span> = AUTO_STOP_DAY_ORDER
+                        .iter()
+                        .flat_map(|day| {
+                            let field = AutoStopField::Day(*day);
+                            let selected = app.auto_stop_days.contains(day);
+                            let style = if app.auto_stop_field == field {
+                                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                            } else if selected {
+                                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+                            } else {
+                                Style::default().fg(Color::DarkGray)
+                            };
+                            let label = if selected { format!("[{}]", day) } else { format!(" {} ", day) };
+                            vec![Span::styled(label, style), Span::raw(" ")]
+                        })
+                        .collect();
+                    schedule_content.push(Line::from(day_spans));
+                    schedule_content.push(Line::from(""));
+                    schedule_content.push(Line::from(vec![
+                        Span::styled(field_marker(AutoStopField::Hour), Style::default().fg(Color::Yellow)),
+                        Span::styled(format!("{:10}", "Time"), field_label_style(AutoStopField::Hour)),
+                        Span::styled(format!("{:02}", app.auto_stop_hour), field_label_style(AutoStopField::Hour).add_modifier(Modifier::BOLD)),
+                        Span::raw(":"),
+                        Span::styled(format!("{:02}", app.auto_stop_minute), field_label_style(AutoStopField::Minute).add_modifier(Modifier::BOLD)),
+                        Span::raw(" UTC"),
+                    ]));
+                    schedule_content.push(Line::from(""));
+                }
+            }
+
+            if let Some(error) = &app.auto_stop_duration_error {
+                schedule_content.push(Line::from(Span::styled(error.clone(), Style::default().fg(Color::Red))));
+                schedule_content.push(Line::from(""));
+            }
+
+            schedule_content.push(Line::from(vec![
+                Span::styled("[↑/↓]", Style::default().fg(Color::Yellow)),
+                Span::raw(" Field   "),
+                Span::styled("[←/→]", Style::default().fg(Color::Yellow)),
+                Span::raw(" Change   "),
+                Span::styled("[Enter]", Style::default().fg(Color::Green)),
+                Span::raw(" Schedule   "),
+                Span::styled("[q/Esc]", Style::default().fg(Color::Red)),
+                Span::raw(" Cancel"),
+            ]));
+
+            ((50, 45), " ⏰ Schedule Auto-Stop ", schedule_content, Style::default().fg(Color::Cyan))
+        }
+        Dialog::Alert { summary, detail, severity } => {
+            let (summary_color, border_color, title) = match severity {
+                AlertSeverity::Error => (Color::Red, Color::Red, " ⚠️  Error "),
+                AlertSeverity::Warning => (Color::Yellow, Color::Yellow, " 🔔 Alert "),
+            };
+
+            let mut alert_content = vec![
+                Line::from(""),
+                Line::from(Span::styled(summary.clone(), Style::default().fg(summary_color).add_modifier(Modifier::BOLD))),
+                Line::from(""),
+            ];
+
+            if let Some(detail) = detail {
+                if app.alert_expanded {
+                    alert_content.push(Line::from(Span::styled("Detail:", Style::default().fg(Color::DarkGray))));
+                    alert_content.push(Line::from(""));
+                    for line in detail.lines() {
+                        alert_content.push(Line::from(Span::raw(line.to_string())));
+                    }
+                    alert_content.push(Line::from(""));
+                    alert_content.push(Line::from(vec![
+                        Span::styled("[Enter]", Style::default().fg(Color::Green)),
+                        Span::raw(" Collapse   "),
+                        Span::styled("[q/Esc]", Style::default().fg(Color::Red)),
+                        Span::raw(" Dismiss"),
+                    ]));
+                } else {
+                    alert_content.push(Line::from(vec![
+                        Span::styled("[Enter]", Style::default().fg(Color::Green)),
+                        Span::raw(" Details   "),
+                        Span::styled("[q/Esc]", Style::default().fg(Color::Red)),
+                        Span::raw(" Dismiss"),
+                    ]));
+                }
+            } else {
+                alert_content.push(Line::from(""));
+                alert_content.push(Line::from(vec![
                     Span::styled("[Enter/q]", Style::default().fg(Color::Green)),
                     Span::raw(" Dismiss"),
-                ]),
-            ],
-            Style::default().fg(Color::Yellow),
-        ),
+                ]));
+            }
+
+            let area_size = if app.alert_expanded { (50, 50) } else { (50, 25) };
+            (area_size, title, alert_content, Style::default().fg(border_color))
+        }
         Dialog::SessionExpired => {
             let mut expired_content = vec![
                 Line::from(""),
@@ -122,11 +280,11 @@ pub fn render_dialog(frame: &mut Frame, app: &App) {
             ];
             
             if app.available_profiles.is_empty() {
-                expired_content.push(Line::from(Span::styled("No profiles found in ~/.aws/config", Style::default().fg(Color::Red))));
+                expired_content.push(Line::from(Span::styled("No profiles found in ~/.aws/credentials or ~/.aws/config", Style::default().fg(Color::Red))));
             } else {
                 for (i, profile) in app.available_profiles.iter().enumerate() {
                     let is_selected = i == app.selected_profile_index;
-                    let is_active = app.active_profile_name.as_ref().map(|p| p == profile).unwrap_or(false);
+                    let is_active = app.active_profile_name.as_deref().map(|p| p == profile.name).unwrap_or(false);
 
                     let style = if is_selected {
                         Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
@@ -135,20 +293,34 @@ pub fn render_dialog(frame: &mut Frame, app: &App) {
                     } else {
                         Style::default().fg(Color::White)
                     };
-                    
+
                     let prefix = if is_selected { " > " } else { "   " };
                     let status_icon = if is_active { " ✅" } else { "" };
-                    
-                    expired_content.push(Line::from(Span::styled(format!("{}{}{}", prefix, profile, status_icon), style)));
+                    let display_name = app.settings.profile_display_name(&profile.name);
+                    let region = profile.region.as_deref().unwrap_or("default");
+                    let sso_badge = if profile.sso { " [SSO]" } else { "" };
+
+                    let mut spans = vec![Span::styled(
+                        format!("{}{} ({}){}{}", prefix, display_name, region, sso_badge, status_icon),
+                        style,
+                    )];
+                    if let Some(expiration) = crate::aws::get_credential_expiration(&profile.name) {
+                        let (expiry_text, is_expired) = crate::aws::format_expiry(expiration);
+                        let expiry_color = if is_expired { Color::Red } else { Color::Green };
+                        spans.push(Span::styled(format!("  {}", expiry_text), Style::default().fg(expiry_color)));
+                    }
+                    expired_content.push(Line::from(spans));
                 }
             }
             
             expired_content.extend_from_slice(&[
                 Line::from(""),
-                Line::from(Span::styled("Quick Fix:", Style::default().fg(Color::Green))),
-                Line::from("1. Select your profile above"),
-                Line::from("2. Press 'l' (L) to launch browser login"),
-                Line::from("3. After login, press 'r' to retry"),
+                Line::from(vec![
+                    Span::styled("Quick Fix ", Style::default().fg(Color::Green)),
+                    Span::styled(format!("(via {})", app.aws_client.credential_source.label()), Style::default().fg(Color::DarkGray)),
+                    Span::styled(":", Style::default().fg(Color::Green)),
+                ]),
+                Line::from(app.aws_client.credential_source.refresh_hint()),
                 Line::from(""),
             ]);
             
@@ -209,12 +381,12 @@ pub fn render_dialog(frame: &mut Frame, app: &App) {
             ];
             
             if app.available_profiles.is_empty() {
-                config_content.push(Line::from(Span::styled("No profiles found in ~/.aws/config", Style::default().fg(Color::Red))));
+                config_content.push(Line::from(Span::styled("No profiles found in ~/.aws/credentials or ~/.aws/config", Style::default().fg(Color::Red))));
             } else {
                 for (i, profile) in app.available_profiles.iter().enumerate() {
                     let is_selected = i == app.selected_profile_index;
-                    let is_active = app.active_profile_name.as_ref().map(|p| p == profile).unwrap_or(false);
-                    
+                    let is_active = app.active_profile_name.as_deref().map(|p| p == profile.name).unwrap_or(false);
+
                     let style = if is_selected {
                         Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
                     } else if is_active {
@@ -222,11 +394,14 @@ pub fn render_dialog(frame: &mut Frame, app: &App) {
                     } else {
                         Style::default().fg(Color::White)
                     };
-                    
+
                     let prefix = if is_selected { " > " } else { "   " };
                     let status_icon = if is_active { " ✅" } else { "" };
-                    
-                    config_content.push(Line::from(Span::styled(format!("{}{}{}", prefix, profile, status_icon), style)));
+                    let display_name = app.settings.profile_display_name(&profile.name);
+                    let region = profile.region.as_deref().unwrap_or("default");
+                    let sso_badge = if profile.sso { " [SSO]" } else { "" };
+
+                    config_content.push(Line::from(Span::styled(format!("{}{} ({}){}{}", prefix, display_name, region, sso_badge, status_icon), style)));
                 }
             }
             
@@ -278,52 +453,93 @@ pub fn render_dialog(frame: &mut Frame, app: &App) {
                     Span::styled(format!("< {} >", value), value_style),
                 ])
             };
-            
-            let settings_content = vec![
+
+            // Like `make_row`, but while this field is mid direct-numeric-entry, shows
+            // the raw input buffer (with a blinking caret) instead of the formatted value
+            let editable_row = |name: &str, formatted: String, field: SettingsField| -> Line {
+                if app.settings_selected_field == field {
+                    if let Some(buf) = &app.settings_value_edit {
+                        let caret = if app.ui_tick / 2 % 2 == 0 { "_" } else { " " };
+                        return Line::from(vec![
+                            Span::styled("▶ ", Style::default().fg(Color::Yellow)),
+                            Span::styled(format!("{:20}", name), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                            Span::styled("> ", Style::default().fg(Color::Cyan)),
+                            Span::styled(buf.clone(), Style::default().fg(Color::White).add_modifier(Modifier::BOLD)),
+                            Span::styled(caret, Style::default().fg(Color::DarkGray)),
+                        ]);
+                    }
+                }
+                make_row(name, &formatted, field)
+            };
+
+            let mut settings_content = vec![
                 Line::from(""),
                 Line::from(Span::styled("⚙️  Application Settings", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))),
                 Line::from(""),
-                Line::from(Span::styled("Use ↑/↓ to navigate, ←/→ to change values", Style::default().fg(Color::DarkGray))),
+                Line::from(Span::styled(
+                    "Use ↑/↓ to navigate, ←/→ to change values (Enter: type exact value, Shift+←/→: nudge)",
+                    Style::default().fg(Color::DarkGray),
+                )),
+                Line::from(""),
+                make_row("Settings Profile", app.active_settings_profile_name(), SettingsField::Profile),
                 Line::from(""),
-                make_row("Refresh Interval", &settings.format_refresh_interval(), SettingsField::RefreshInterval),
+                editable_row(
+                    &app
+                        .active_profile_name
+                        .as_deref()
+                        .map(|p| format!("Alias for {}", p))
+                        .unwrap_or_else(|| "Profile Alias".to_string()),
+                    app.active_profile_name.as_deref().map(|p| settings.profile_display_name(p).to_string())
+                        .unwrap_or_else(|| "(no active profile)".to_string()),
+                    SettingsField::ProfileAlias,
+                ),
+                Line::from(""),
+                editable_row("Refresh Interval", settings.format_refresh_interval(), SettingsField::RefreshInterval),
                 Line::from(""),
                 make_row("Show Logs Panel", if settings.show_logs_panel { "Yes" } else { "No" }, SettingsField::ShowLogsPanel),
                 Line::from(""),
                 make_row("Log Verbosity", &settings.format_log_level(), SettingsField::LogLevel),
                 Line::from(""),
-                make_row("Alert Threshold", &settings.format_alert_threshold(), SettingsField::AlertThreshold),
+                editable_row("Alert Threshold", settings.format_alert_threshold(), SettingsField::AlertThreshold),
+                Line::from(""),
+                editable_row("Session Renewal", settings.format_session_renewal_threshold(), SettingsField::SessionRenewalThreshold),
                 Line::from(""),
                 make_row("Sound Alerts", if settings.sound_enabled { "On" } else { "Off" }, SettingsField::SoundEnabled),
                 Line::from(""),
-                make_row("Test Alert Sound", "[ Press Enter ]", SettingsField::TestSound),
+                make_row("Desktop Notifications", if settings.notifications_enabled { "On" } else { "Off" }, SettingsField::NotificationsEnabled),
                 Line::from(""),
+                make_row("Stop Due Schedules on Exit", if settings.stop_on_exit { "On" } else { "Off" }, SettingsField::StopOnExit),
+                Line::from(""),
+                make_row("Log to File", if settings.file_logging_enabled() { "On" } else { "Off" }, SettingsField::FileLogging),
+                Line::from(""),
+                make_row("Mirror Warnings to Stderr", if settings.stderr_logging_enabled() { "On" } else { "Off" }, SettingsField::StderrLogging),
+                Line::from(""),
+                make_row("Settings File Format", app.settings_profiles.format.label(), SettingsField::FileFormat),
+                Line::from(""),
+                make_row("Theme", &settings.format_theme_palette(), SettingsField::Theme),
+                Line::from(""),
+                make_row("Test Alert Sound", "[ Press Enter ]", SettingsField::TestSound),
                 Line::from(""),
-                Line::from(vec![
-                    Span::styled("[Enter]", Style::default().fg(Color::Green)),
-                    Span::raw(" Save   "),
-                    Span::styled("[Esc]", Style::default().fg(Color::Red)),
-                    Span::raw(" Cancel"),
-                ]),
             ];
+
+            if let Some(error) = &app.settings_value_edit_error {
+                settings_content.push(Line::from(Span::styled(error.clone(), Style::default().fg(Color::Red))));
+                settings_content.push(Line::from(""));
+            }
+
+            settings_content.push(Line::from(vec![
+                Span::styled("[Enter]", Style::default().fg(Color::Green)),
+                Span::raw(" Save   "),
+                Span::styled("[Esc]", Style::default().fg(Color::Red)),
+                Span::raw(" Cancel"),
+            ]));
+
             ((50, 60), " ⚙️  Settings ", settings_content, Style::default().fg(Color::Magenta))
         }
         Dialog::Changelog => {
              let changelog_text = include_str!("../../../CHANGELOG.md");
-             let content: Vec<Line> = changelog_text.lines()
-                 .map(|l: &str| {
-                     if l.starts_with("# ") {
-                         Line::from(Span::styled(l, Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD|Modifier::UNDERLINED)))
-                     } else if l.starts_with("## ") {
-                         Line::from(Span::styled(l, Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)))
-                     } else if l.starts_with("### ") {
-                         Line::from(Span::styled(l, Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD)))
-                     } else {
-                         // Default text
-                         Line::from(Span::raw(l))
-                     }
-                 })
-                 .collect();
-             
+             let content = crate::ui::markdown::render(changelog_text);
+
              let mut final_content = vec![
                  Line::from(""),
                  Line::from(vec![
@@ -340,6 +556,341 @@ pub fn render_dialog(frame: &mut Frame, app: &App) {
              
              ((70, 80), " 📜 Changelog ", final_content, Style::default().fg(Color::Cyan))
         }
+        Dialog::CommandPalette => {
+            let matches = app.filtered_palette_commands();
+
+            let mut palette_content = vec![
+                Line::from(vec![
+                    Span::styled("> ", Style::default().fg(Color::Cyan)),
+                    Span::styled(app.command_palette_query.as_str(), Style::default().fg(Color::White).add_modifier(Modifier::BOLD)),
+                    Span::styled("_", Style::default().fg(Color::DarkGray)),
+                ]),
+                Line::from(Span::styled("──────────────────────────────", Style::default().fg(Color::DarkGray))),
+            ];
+
+            if matches.is_empty() {
+                palette_content.push(Line::from(Span::styled("No matching commands", Style::default().fg(Color::DarkGray))));
+            } else {
+                for (i, (command, m)) in matches.iter().enumerate() {
+                    let is_selected = i == app.command_palette_selected;
+                    let base_style = if is_selected {
+                        Style::default().fg(Color::White).bg(Color::DarkGray)
+                    } else {
+                        Style::default().fg(Color::Gray)
+                    };
+                    let highlight_style = base_style.fg(Color::Yellow).add_modifier(Modifier::BOLD);
+
+                    let mut spans = vec![Span::styled(if is_selected { " > " } else { "   " }, base_style)];
+                    for (idx, ch) in command.label.chars().enumerate() {
+                        let style = if m.matched_indices.contains(&idx) { highlight_style } else { base_style };
+                        spans.push(Span::styled(ch.to_string(), style));
+                    }
+                    palette_content.push(Line::from(spans));
+                }
+            }
+
+            palette_content.push(Line::from(""));
+            palette_content.push(Line::from(vec![
+                Span::styled("[↑/↓]", Style::default().fg(Color::Yellow)),
+                Span::raw(" Select   "),
+                Span::styled("[Enter]", Style::default().fg(Color::Green)),
+                Span::raw(" Run   "),
+                Span::styled("[Esc]", Style::default().fg(Color::Red)),
+                Span::raw(" Close"),
+            ]));
+
+            ((50, 50), " 🔎 Command Palette ", palette_content, Style::default().fg(Color::Cyan))
+        }
+        Dialog::InvokeLambda(name) => {
+            let invoke_content = vec![
+                Line::from(Span::raw(format!("Enter a JSON payload for {}:", name))),
+                Line::from(""),
+                Line::from(vec![
+                    Span::styled("> ", Style::default().fg(Color::Cyan)),
+                    Span::styled(app.lambda_invoke_payload.as_str(), Style::default().fg(Color::White)),
+                    Span::styled("_", Style::default().fg(Color::DarkGray)),
+                ]),
+                Line::from(""),
+                Line::from(vec![
+                    Span::raw("Invocation type: "),
+                    Span::styled(app.lambda_invoke_type.label(), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                    Span::raw("  [←/→ to change]"),
+                ]),
+                Line::from(""),
+                Line::from(vec![
+                    Span::styled("[Enter]", Style::default().fg(Color::Green)),
+                    Span::raw(" Invoke   "),
+                    Span::styled("[Esc]", Style::default().fg(Color::Red)),
+                    Span::raw(" Cancel"),
+                ]),
+            ];
+            ((40, 40), " ⚡ Invoke Lambda ", invoke_content, Style::default().fg(Color::Cyan))
+        }
+        Dialog::Assistant => {
+            // Blink roughly twice a second
+            let caret = if app.ui_tick / 2 % 2 == 0 { "_" } else { " " };
+
+            let mut assistant_content = vec![
+                Line::from("Ask for an EC2/Lambda operation in plain English:"),
+                Line::from(""),
+                Line::from(vec![
+                    Span::styled("> ", Style::default().fg(Color::Cyan)),
+                    Span::styled(app.assistant_prompt.as_str(), Style::default().fg(Color::White).add_modifier(Modifier::BOLD)),
+                    Span::styled(caret, Style::default().fg(Color::DarkGray)),
+                ]),
+                Line::from(""),
+            ];
+
+            if app.assistant_busy {
+                assistant_content.push(Line::from(Span::styled("Thinking...", Style::default().fg(Color::Yellow))));
+            } else if let Some(proposed) = &app.assistant_proposed {
+                if proposed.is_empty() {
+                    assistant_content.push(Line::from(Span::styled("No actions proposed for that request.", Style::default().fg(Color::DarkGray))));
+                } else {
+                    assistant_content.push(Line::from(Span::styled("Proposed actions:", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))));
+                    assistant_content.push(Line::from(""));
+                    for (i, action) in proposed.iter().enumerate() {
+                        let is_selected = i == app.assistant_selected;
+                        let style = if is_selected {
+                            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                        } else {
+                            Style::default().fg(Color::White)
+                        };
+                        let prefix = if is_selected { " > " } else { "   " };
+                        assistant_content.push(Line::from(Span::styled(format!("{}{}", prefix, action.describe()), style)));
+                    }
+                }
+            }
+
+            assistant_content.push(Line::from(""));
+            assistant_content.push(if app.assistant_proposed.is_some() {
+                Line::from(vec![
+                    Span::styled("[↑/↓]", Style::default().fg(Color::Yellow)),
+                    Span::raw(" Select   "),
+                    Span::styled("[Enter]", Style::default().fg(Color::Green)),
+                    Span::raw(" Confirm   "),
+                    Span::styled("[q/Esc]", Style::default().fg(Color::Red)),
+                    Span::raw(" Close"),
+                ])
+            } else {
+                Line::from(vec![
+                    Span::styled("[Enter]", Style::default().fg(Color::Green)),
+                    Span::raw(" Ask   "),
+                    Span::styled("[q/Esc]", Style::default().fg(Color::Red)),
+                    Span::raw(" Close"),
+                ])
+            });
+
+            ((60, 60), " 🤖 Ops Assistant ", assistant_content, Style::default().fg(Color::Cyan))
+        }
+        Dialog::Ssh => {
+            if app.ssh_unlocking {
+                let caret = if app.ui_tick / 2 % 2 == 0 { "_" } else { " " };
+                let label = app
+                    .ssh_key_store
+                    .keys
+                    .get(app.ssh_selected)
+                    .map(|k| k.label.as_str())
+                    .unwrap_or("?");
+                let masked: String = app.ssh_passphrase_input.chars().map(|_| '*').collect();
+
+                let ssh_content = vec![
+                    Line::from(format!("Passphrase for '{}':", label)),
+                    Line::from(""),
+                    Line::from(vec![
+                        Span::styled("> ", Style::default().fg(Color::Cyan)),
+                        Span::styled(masked, Style::default().fg(Color::White).add_modifier(Modifier::BOLD)),
+                        Span::styled(caret, Style::default().fg(Color::DarkGray)),
+                    ]),
+                    Line::from(""),
+                    Line::from(vec![
+                        Span::styled("[Enter]", Style::default().fg(Color::Green)),
+                        Span::raw(" Connect   "),
+                        Span::styled("[Esc]", Style::default().fg(Color::Red)),
+                        Span::raw(" Back"),
+                    ]),
+                ];
+                ((50, 30), " 🔐 Unlock SSH Key ", ssh_content, Style::default().fg(Color::Cyan))
+            } else {
+                let mut ssh_content = vec![
+                    Line::from("Select a key to connect via SSH (Use ↑/↓, [Enter] to unlock):"),
+                    Line::from(""),
+                ];
+
+                if app.ssh_key_store.keys.is_empty() {
+                    ssh_content.push(Line::from(Span::styled("No SSH keys configured yet.", Style::default().fg(Color::DarkGray))));
+                    ssh_content.push(Line::from(""));
+                } else {
+                    for (i, key) in app.ssh_key_store.keys.iter().enumerate() {
+                        let is_selected = i == app.ssh_selected;
+                        let style = if is_selected {
+                            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                        } else {
+                            Style::default().fg(Color::White)
+                        };
+                        let prefix = if is_selected { " > " } else { "   " };
+                        ssh_content.push(Line::from(Span::styled(
+                            format!("{}{} ({}) - {}", prefix, key.label, key.key_type.label(), key.path.display()),
+                            style,
+                        )));
+                    }
+                    ssh_content.push(Line::from(""));
+                }
+
+                let add_selected = app.ssh_selected == app.ssh_key_store.keys.len();
+                let add_style = if add_selected {
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::Green)
+                };
+                ssh_content.push(Line::from(Span::styled(
+                    format!("{}+ Add new key", if add_selected { " > " } else { "   " }),
+                    add_style,
+                )));
+
+                ssh_content.push(Line::from(""));
+                ssh_content.push(Line::from(vec![
+                    Span::styled("[↑/↓]", Style::default().fg(Color::Yellow)),
+                    Span::raw(" Select   "),
+                    Span::styled("[Enter]", Style::default().fg(Color::Green)),
+                    Span::raw(" Choose   "),
+                    Span::styled("[q/Esc]", Style::default().fg(Color::Red)),
+                    Span::raw(" Close"),
+                ]));
+
+                ((50, 45), " 🖥️  SSH Into Instance ", ssh_content, Style::default().fg(Color::Cyan))
+            }
+        }
+        Dialog::SshAddKey => {
+            let make_row = |name: &str, value: &str, field: SshAddField| -> Line {
+                let is_selected = app.ssh_add_field == field;
+                let name_style = if is_selected {
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::White)
+                };
+                let value_style = if is_selected {
+                    Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::Green)
+                };
+                let arrow = if is_selected { "▶ " } else { "  " };
+
+                Line::from(vec![
+                    Span::styled(arrow, Style::default().fg(Color::Yellow)),
+                    Span::styled(format!("{:8}", name), name_style),
+                    Span::styled(value.to_string(), value_style),
+                ])
+            };
+
+            let add_content = vec![
+                Line::from(""),
+                Line::from(Span::styled("🔑 Add SSH Key", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))),
+                Line::from(""),
+                Line::from(Span::styled("Use ↑/↓ or Tab to switch fields, ←/→ to toggle type", Style::default().fg(Color::DarkGray))),
+                Line::from(""),
+                make_row("Label", &app.ssh_add_label_input, SshAddField::Label),
+                make_row("Path", &app.ssh_add_path_input, SshAddField::Path),
+                make_row("Type", app.ssh_add_key_type.label(), SshAddField::KeyType),
+                Line::from(""),
+                Line::from(vec![
+                    Span::styled("[Enter]", Style::default().fg(Color::Green)),
+                    Span::raw(" Save   "),
+                    Span::styled("[Esc]", Style::default().fg(Color::Red)),
+                    Span::raw(" Cancel"),
+                ]),
+            ];
+            ((40, 40), " 🔑 Add SSH Key ", add_content, Style::default().fg(Color::Cyan))
+        }
+        Dialog::LaunchInstance => {
+            let make_row = |name: &str, value: &str, field: LaunchInstanceField| -> Line {
+                let is_selected = app.launch_field == field;
+                let name_style = if is_selected {
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::White)
+                };
+                let value_style = if is_selected {
+                    Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::Green)
+                };
+                let arrow = if is_selected { "▶ " } else { "  " };
+
+                Line::from(vec![
+                    Span::styled(arrow, Style::default().fg(Color::Yellow)),
+                    Span::styled(format!("{:15}", name), name_style),
+                    Span::styled(value.to_string(), value_style),
+                ])
+            };
+
+            let mut launch_content = vec![
+                Line::from(""),
+                Line::from(Span::styled("🚀 Launch EC2 Instance", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))),
+                Line::from(""),
+                Line::from(Span::styled("Use ↑/↓ or Tab to switch fields, ←/→ to change Count/Spot", Style::default().fg(Color::DarkGray))),
+                Line::from(""),
+                make_row("AMI ID", &app.launch_ami_id, LaunchInstanceField::AmiId),
+                make_row("Instance Type", &app.launch_instance_type, LaunchInstanceField::InstanceType),
+                make_row("Key Pair", &app.launch_key_name, LaunchInstanceField::KeyName),
+                make_row("Security Group", &app.launch_security_group, LaunchInstanceField::SecurityGroup),
+                make_row("Name (optional)", &app.launch_name, LaunchInstanceField::Name),
+                make_row("Purchasing", &format!("< {} >", if app.launch_spot { "Spot" } else { "On-Demand" }), LaunchInstanceField::Spot),
+                make_row("Count", &app.launch_count.to_string(), LaunchInstanceField::Count),
+                Line::from(""),
+            ];
+
+            if let Some(error) = &app.launch_error {
+                launch_content.push(Line::from(Span::styled(error.clone(), Style::default().fg(Color::Red))));
+                launch_content.push(Line::from(""));
+            }
+
+            launch_content.push(Line::from(vec![
+                Span::styled("[Enter]", Style::default().fg(Color::Green)),
+                Span::raw(" Launch   "),
+                Span::styled("[Esc]", Style::default().fg(Color::Red)),
+                Span::raw(" Cancel"),
+            ]));
+
+            ((45, 45), " 🚀 Launch Instance ", launch_content, Style::default().fg(Color::Cyan))
+        }
+        Dialog::AssistantError => {
+            let error = app.assistant_error.as_ref();
+            let mut error_content = vec![
+                Line::from(""),
+                Line::from(Span::styled(
+                    error.map(|e| e.summary.as_str()).unwrap_or("Assistant request failed"),
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                )),
+                Line::from(""),
+            ];
+
+            if app.assistant_error_expanded {
+                error_content.push(Line::from(Span::styled("Detail:", Style::default().fg(Color::DarkGray))));
+                error_content.push(Line::from(""));
+                if let Some(e) = error {
+                    for line in e.detail.lines() {
+                        error_content.push(Line::from(Span::raw(line.to_string())));
+                    }
+                }
+                error_content.push(Line::from(""));
+                error_content.push(Line::from(vec![
+                    Span::styled("[Enter]", Style::default().fg(Color::Green)),
+                    Span::raw(" Collapse   "),
+                    Span::styled("[q/Esc]", Style::default().fg(Color::Red)),
+                    Span::raw(" Dismiss"),
+                ]));
+            } else {
+                error_content.push(Line::from(vec![
+                    Span::styled("[Enter]", Style::default().fg(Color::Green)),
+                    Span::raw(" Show detail   "),
+                    Span::styled("[q/Esc]", Style::default().fg(Color::Red)),
+                    Span::raw(" Dismiss"),
+                ]));
+            }
+
+            ((60, 60), " ⚠️  Assistant Error ", error_content, Style::default().fg(Color::Red))
+        }
         Dialog::None => return,
     };
 
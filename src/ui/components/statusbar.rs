@@ -6,7 +6,11 @@ use ratatui::{
     Frame,
 };
 
-use crate::app::App;
+use crate::app::{App, InputMode};
+use crate::theme::{style_of, Styles};
+
+/// Braille spinner frames cycled while an AWS operation is in flight
+const SPINNER_FRAMES: [&str; 8] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧"];
 
 /// Render status bar with control hints
 pub fn render_status_bar(frame: &mut Frame, app: &App, area: Rect) {
@@ -16,7 +20,11 @@ pub fn render_status_bar(frame: &mut Frame, app: &App, area: Rect) {
         .split(area);
 
     // Left side: Status message with refresh timer
-    let loading_indicator = if app.is_loading { "⏳ " } else { "" };
+    let loading_indicator = if app.is_loading {
+        format!("{} ", SPINNER_FRAMES[app.spinner_frame % SPINNER_FRAMES.len()])
+    } else {
+        String::new()
+    };
     let alert_count = if app.pending_alerts.is_empty() {
         String::new()
     } else {
@@ -38,43 +46,74 @@ pub fn render_status_bar(frame: &mut Frame, app: &App, area: Rect) {
         app.status_message.clone()
     };
 
+    // While in live-search mode, the typed buffer takes over the status line - there's
+    // nothing more useful to show there until the search is confirmed or cancelled
+    let search_display = if app.input_mode == InputMode::Search {
+        format!("/{}", app.search_query)
+    } else {
+        String::new()
+    };
+
+    let profile_text = app
+        .active_profile_name
+        .as_deref()
+        .map(|p| format!(" | Profile: {} ({})", app.settings.profile_display_name(p), app.aws_client.credential_source.label()))
+        .unwrap_or_default();
+
+    let settings_profile_text = format!(" | Ctx: {}", app.active_settings_profile_name());
+
+    // User-editable status-bar-specific slots (`Settings::status_bar_colors`), distinct
+    // from the `ThemePalette`-wide styles used elsewhere in this function - see
+    // `theme::StatusBarColors`.
+    let colors = app.settings.status_bar_colors;
+    let accent_color = colors.accent.to_color();
+    let border_style = Style::default().fg(colors.border.to_color());
+
+    let (expiry_text, expiry_style) = match app.credential_expiry_display() {
+        Some((text, is_urgent)) => (
+            format!(" | Credentials {}", text),
+            if is_urgent { style_of(&app.theme, Styles::Failure) } else { style_of(&app.theme, Styles::Secondary) },
+        ),
+        None => (String::new(), style_of(&app.theme, Styles::Secondary)),
+    };
+
     let status = Paragraph::new(Line::from(vec![
-        Span::styled(loading_indicator, Style::default().fg(Color::Yellow)),
-        Span::styled(status_display, Style::default().fg(Color::White)),
+        Span::styled(loading_indicator, Style::default().fg(accent_color)),
+        Span::styled(search_display, Style::default().fg(accent_color).add_modifier(ratatui::style::Modifier::BOLD)),
+        Span::styled(status_display, style_of(&app.theme, Styles::Default)),
+        Span::styled(profile_text, style_of(&app.theme, Styles::Secondary)),
+        Span::styled(settings_profile_text, style_of(&app.theme, Styles::Secondary)),
         Span::styled(
             format!(" | Region: {}", app.aws_client.region),
-            Style::default().fg(Color::DarkGray),
+            style_of(&app.theme, Styles::Secondary),
         ),
-        Span::styled(alert_count, Style::default().fg(Color::Red)),
-        Span::styled(refresh_text, Style::default().fg(Color::Cyan)),
+        Span::styled(expiry_text, expiry_style),
+        Span::styled(alert_count, Style::default().fg(colors.alert.to_color())),
+        Span::styled(refresh_text, Style::default().fg(colors.timer.to_color())),
     ]))
-    .block(
-        Block::default()
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::DarkGray)),
-    );
+    .block(Block::default().borders(Borders::ALL).border_style(border_style));
 
     frame.render_widget(status, chunks[0]);
 
-    // Right side: Control hints
-    let controls = Paragraph::new(Line::from(vec![
-        Span::styled(" c ", Style::default().fg(Color::Black).bg(Color::Cyan)),
-        Span::styled(" AWS Config ", Style::default().fg(Color::Cyan)),
-        Span::raw(" "),
-        Span::styled(" , ", Style::default().fg(Color::Black).bg(Color::Yellow)),
-        Span::styled(" Set ", Style::default().fg(Color::Yellow)),
-        Span::raw(" "),
-        Span::styled(" ?/h ", Style::default().fg(Color::Black).bg(Color::Cyan)),
-        Span::styled(" Help ", Style::default().fg(Color::Cyan)),
-        Span::raw(" "),
-        Span::styled(" q ", Style::default().fg(Color::Black).bg(Color::Red)),
-        Span::styled(" Quit ", Style::default().fg(Color::Red)),
-    ]))
-    .block(
-        Block::default()
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::DarkGray)),
-    );
+    // Right side: Control hints. Key-cap backgrounds and label text come from
+    // `Settings::status_bar_colors` (`keycap_bg`/`accent`) rather than the per-action
+    // `Styles` slots, so a user can restyle every hint at once without touching the
+    // whole-UI `ThemePalette`.
+    let badge = |key: &'static str, label: &'static str| -> Vec<Span<'static>> {
+        vec![
+            Span::styled(format!(" {} ", key), Style::default().fg(Color::Black).bg(colors.keycap_bg.to_color())),
+            Span::styled(format!(" {} ", label), Style::default().fg(accent_color)),
+            Span::raw(" "),
+        ]
+    };
+
+    let mut control_spans = Vec::new();
+    control_spans.extend(badge("c", "AWS Config"));
+    control_spans.extend(badge(",", "Set"));
+    control_spans.extend(badge("?/h", "Help"));
+    control_spans.extend(badge("q", "Quit"));
+
+    let controls = Paragraph::new(Line::from(control_spans)).block(Block::default().borders(Borders::ALL).border_style(border_style));
 
     frame.render_widget(controls, chunks[1]);
 }
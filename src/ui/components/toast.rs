@@ -1,3 +1,4 @@
+use chrono::Utc;
 use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
@@ -8,52 +9,78 @@ use ratatui::{
 
 use crate::app::{App, ToastType};
 
-/// Render toast notifications in top-right corner
+/// Render toast notifications in top-right corner, each with a thin progress bar on its
+/// bottom border showing how much of its TTL (see `Toast::remaining_fraction`) is left.
+/// Also stashes each toast's drawn rect (paired with its `app.toasts` index) on `app` so
+/// `App::handle_click` can hit-test a dismiss click against it.
 pub fn render_toasts(frame: &mut Frame, app: &App) {
+    let mut areas: [Option<(usize, Rect)>; 3] = [None, None, None];
+
     if app.toasts.is_empty() {
+        app.toast_areas.set(areas);
         return;
     }
-    
+
     let area = frame.area();
     let max_toast_width = 50;
     let toast_height = 3;
-    
-    // Stack toasts from top to bottom
-    for (idx, toast) in app.toasts.iter().rev().take(3).enumerate() {
-        let y_offset = (idx as u16 * (toast_height + 1)) + 1;
-        
+    let now = Utc::now();
+    let toast_count = app.toasts.len();
+
+    // Stack toasts from top to bottom; slot 0 (drawn first) is the most recently added
+    for (slot, toast) in app.toasts.iter().rev().take(3).enumerate() {
+        let y_offset = (slot as u16 * (toast_height + 1)) + 1;
+
         if y_offset + toast_height > area.height {
             break; // Don't render if it would go off screen
         }
-        
+
         let toast_area = Rect {
             x: area.width.saturating_sub(max_toast_width + 2),
             y: area.y + y_offset,
             width: max_toast_width.min(area.width),
             height: toast_height,
         };
-        
+        areas[slot] = Some((toast_count - 1 - slot, toast_area));
+
         let (border_color, icon) = match toast.toast_type {
             ToastType::Success => (Color::Green, "✓"),
             ToastType::Error => (Color::Red, "✗"),
             ToastType::Info => (Color::Cyan, "ℹ"),
         };
-        
+
         let toast_block = Block::default()
             .borders(Borders::ALL)
             .border_style(Style::default().fg(border_color))
             .style(Style::default().bg(Color::Black));
-        
+
         frame.render_widget(Clear, toast_area);
         frame.render_widget(toast_block.clone(), toast_area);
-        
+
         let inner = toast_block.inner(toast_area);
         let text = Paragraph::new(Line::from(vec![
             Span::styled(format!("{} ", icon), Style::default().fg(border_color).add_modifier(Modifier::BOLD)),
             Span::styled(&toast.message, Style::default().fg(Color::White)),
         ]))
         .wrap(Wrap { trim: true });
-        
+
         frame.render_widget(text, inner);
+
+        // Shrinking TTL bar drawn over the bottom border, inside the corners. Paused
+        // toasts (the topmost one, mid-read) get a dimmed bar instead of ticking down.
+        let bar_width = toast_area.width.saturating_sub(2);
+        if bar_width > 0 {
+            let fraction = toast.remaining_fraction(now);
+            let filled = ((bar_width as f64) * fraction).round() as u16;
+            let bar_color = if toast.paused_since.is_some() { Color::DarkGray } else { border_color };
+            let bar_area = Rect { x: toast_area.x + 1, y: toast_area.y + toast_area.height - 1, width: bar_width, height: 1 };
+            let bar = Line::from(vec![
+                Span::styled("━".repeat(filled as usize), Style::default().fg(bar_color)),
+                Span::styled("─".repeat((bar_width - filled) as usize), Style::default().fg(border_color)),
+            ]);
+            frame.render_widget(Paragraph::new(bar), bar_area);
+        }
     }
+
+    app.toast_areas.set(areas);
 }
@@ -0,0 +1,225 @@
+//! Small Markdown-to-ratatui renderer, shared by any dialog that wants to show
+//! Markdown as styled lines instead of raw text (currently the changelog; future
+//! help/about panels can reuse [`render`] too).
+//!
+//! This covers the subset of Markdown a changelog realistically needs: headings,
+//! bullet/numbered lists, `**bold**`/`*italic*`/`` `inline code` ``, `[links](url)`,
+//! and fenced ``` code blocks. Code blocks get a distinct background and a light
+//! hand-rolled keyword/string/number highlighter rather than a full syntax-highlighting
+//! dependency.
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+
+/// Background used behind fenced code blocks and inline code spans
+const CODE_BG: Color = Color::Rgb(30, 30, 30);
+
+/// Bare-minimum keyword list for the hand-rolled code highlighter; covers Rust, which
+/// is what this project's own changelog snippets are written in
+const KEYWORDS: &[&str] = &[
+    "fn", "let", "pub", "struct", "impl", "match", "if", "else", "for", "while", "return",
+    "use", "mod", "true", "false", "self", "Self", "enum", "trait", "const", "static",
+    "async", "await", "mut", "as", "in", "loop", "break", "continue",
+];
+
+/// Render a Markdown document into styled lines suitable for a `Paragraph`
+pub fn render(markdown: &str) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    let mut in_code_block = false;
+
+    for raw_line in markdown.lines() {
+        if raw_line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+
+        if in_code_block {
+            lines.push(render_code_line(raw_line));
+        } else {
+            lines.push(render_text_line(raw_line));
+        }
+    }
+
+    lines
+}
+
+/// Render one non-code-block line: headings and bullet/numbered lists get their own
+/// treatment, everything else is run through the inline-emphasis renderer
+fn render_text_line(line: &str) -> Line<'static> {
+    if let Some(rest) = line.strip_prefix("### ") {
+        return Line::from(Span::styled(rest.to_string(), Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD)));
+    }
+    if let Some(rest) = line.strip_prefix("## ") {
+        return Line::from(Span::styled(rest.to_string(), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)));
+    }
+    if let Some(rest) = line.strip_prefix("# ") {
+        return Line::from(Span::styled(
+            rest.to_string(),
+            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+        ));
+    }
+
+    let trimmed = line.trim_start();
+    let indent = line.len() - trimmed.len();
+
+    if let Some(rest) = trimmed.strip_prefix("- ").or_else(|| trimmed.strip_prefix("* ")) {
+        let mut spans = vec![Span::raw(" ".repeat(indent)), Span::styled("• ", Style::default().fg(Color::DarkGray))];
+        spans.extend(render_inline(rest));
+        return Line::from(spans);
+    }
+
+    if let Some(dot) = trimmed.find(". ") {
+        let marker = &trimmed[..dot];
+        if !marker.is_empty() && marker.chars().all(|c| c.is_ascii_digit()) {
+            let mut spans = vec![
+                Span::raw(" ".repeat(indent)),
+                Span::styled(format!("{}. ", marker), Style::default().fg(Color::DarkGray)),
+            ];
+            spans.extend(render_inline(&trimmed[dot + 2..]));
+            return Line::from(spans);
+        }
+    }
+
+    Line::from(render_inline(line))
+}
+
+/// Parse `**bold**`, `*italic*`, `` `code` `` and `[label](url)` out of a line of plain
+/// text, preserving everything else verbatim
+fn render_inline(text: &str) -> Vec<Span<'static>> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut spans = Vec::new();
+    let mut buf = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        if chars[i] == '`' {
+            if let Some(end) = (i + 1..chars.len()).find(|&j| chars[j] == '`') {
+                flush(&mut buf, &mut spans);
+                let code: String = chars[i + 1..end].iter().collect();
+                spans.push(Span::styled(code, Style::default().fg(Color::Green).bg(CODE_BG)));
+                i = end + 1;
+                continue;
+            }
+        } else if chars[i] == '*' && chars.get(i + 1) == Some(&'*') {
+            if let Some(end) = find_double_star(&chars, i + 2) {
+                flush(&mut buf, &mut spans);
+                let bold: String = chars[i + 2..end].iter().collect();
+                spans.push(Span::styled(bold, Style::default().add_modifier(Modifier::BOLD)));
+                i = end + 2;
+                continue;
+            }
+        } else if chars[i] == '*' {
+            if let Some(end) = (i + 1..chars.len()).find(|&j| chars[j] == '*') {
+                flush(&mut buf, &mut spans);
+                let italic: String = chars[i + 1..end].iter().collect();
+                spans.push(Span::styled(italic, Style::default().add_modifier(Modifier::ITALIC)));
+                i = end + 1;
+                continue;
+            }
+        } else if chars[i] == '[' {
+            if let Some(close_bracket) = (i + 1..chars.len()).find(|&j| chars[j] == ']') {
+                if chars.get(close_bracket + 1) == Some(&'(') {
+                    if let Some(close_paren) = (close_bracket + 2..chars.len()).find(|&j| chars[j] == ')') {
+                        flush(&mut buf, &mut spans);
+                        let label: String = chars[i + 1..close_bracket].iter().collect();
+                        spans.push(Span::styled(label, Style::default().fg(Color::Blue).add_modifier(Modifier::UNDERLINED)));
+                        i = close_paren + 1;
+                        continue;
+                    }
+                }
+            }
+        }
+
+        buf.push(chars[i]);
+        i += 1;
+    }
+    flush(&mut buf, &mut spans);
+    spans
+}
+
+fn find_double_star(chars: &[char], from: usize) -> Option<usize> {
+    let mut j = from;
+    while j + 1 < chars.len() {
+        if chars[j] == '*' && chars[j + 1] == '*' {
+            return Some(j);
+        }
+        j += 1;
+    }
+    None
+}
+
+fn flush(buf: &mut String, spans: &mut Vec<Span<'static>>) {
+    if !buf.is_empty() {
+        spans.push(Span::raw(std::mem::take(buf)));
+    }
+}
+
+/// Render one line inside a fenced code block: a distinct background, with string
+/// literals, numbers and a small keyword set picked out
+fn render_code_line(line: &str) -> Line<'static> {
+    let base = Style::default().bg(CODE_BG).fg(Color::Gray);
+    let mut spans = tokenize_code(line, base);
+    if spans.is_empty() {
+        spans.push(Span::styled(" ".to_string(), base));
+    }
+    Line::from(spans)
+}
+
+fn tokenize_code(code: &str, base: Style) -> Vec<Span<'static>> {
+    let chars: Vec<char> = code.chars().collect();
+    let mut spans = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if c == '/' && chars.get(i + 1) == Some(&'/') {
+            let comment: String = chars[i..].iter().collect();
+            spans.push(Span::styled(comment, base.fg(Color::DarkGray).add_modifier(Modifier::ITALIC)));
+            break;
+        }
+
+        if c == '"' {
+            let start = i;
+            i += 1;
+            while i < chars.len() && chars[i] != '"' {
+                i += 1;
+            }
+            if i < chars.len() {
+                i += 1;
+            }
+            spans.push(Span::styled(chars[start..i].iter().collect::<String>(), base.fg(Color::Green)));
+            continue;
+        }
+
+        if c.is_whitespace() {
+            let start = i;
+            while i < chars.len() && chars[i].is_whitespace() {
+                i += 1;
+            }
+            spans.push(Span::styled(chars[start..i].iter().collect::<String>(), base));
+            continue;
+        }
+
+        if c.is_alphanumeric() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            let word: String = chars[start..i].iter().collect();
+            if KEYWORDS.contains(&word.as_str()) {
+                spans.push(Span::styled(word, base.fg(Color::Magenta).add_modifier(Modifier::BOLD)));
+            } else if word.chars().next().map(|c| c.is_ascii_digit()).unwrap_or(false) {
+                spans.push(Span::styled(word, base.fg(Color::Cyan)));
+            } else {
+                spans.push(Span::styled(word, base));
+            }
+            continue;
+        }
+
+        spans.push(Span::styled(c.to_string(), base));
+        i += 1;
+    }
+
+    spans
+}
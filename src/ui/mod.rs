@@ -1,6 +1,7 @@
 //! UI rendering with Ratatui
 
 pub mod components;
+pub mod markdown;
 pub mod screens;
 pub mod utils;
 
@@ -17,6 +18,7 @@ use self::components::{
 };
 use self::screens::{
     about::render_about,
+    autoscaling::render_autoscaling,
     ec2::render_ec2,
     home::render_home,
     lambda::render_lambda,
@@ -54,39 +56,102 @@ pub fn render(frame: &mut Frame, app: &App) {
 
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::Modifier,
+    text::Line,
     widgets::{Block, Borders, Tabs},
 };
 
+use crate::theme::{style_of, Styles};
+
+/// The tab strip's titles, in display order. Shared between `render_tabs` (which feeds
+/// them to `Tabs`) and `App::handle_click`'s hit-test (`tab_click_index`), so the two
+/// can never drift apart.
+pub fn tab_titles(show_logs_panel: bool) -> Vec<&'static str> {
+    let mut titles = vec!["🏠 Home [1]", "💻 EC2 [2]", "λ Lambda [3]", "📈 ASG [4]", "ℹ️ About [5]"];
+    if show_logs_panel {
+        titles.push("📋 Logs [6]");
+    }
+    titles
+}
+
+/// `Tabs`'s default single-char divider (`ratatui::symbols::line::VERTICAL`) and the
+/// one-column padding it leaves on each side of every title - mirrored here since `Tabs`
+/// doesn't expose the rects it actually drew.
+const TAB_DIVIDER_WIDTH: u16 = 1;
+const TAB_PADDING: u16 = 1;
+
+/// Hit-test an x coordinate inside the tab strip's *inner* area (i.e. already inset past
+/// the block border) against the real per-tab rects `Tabs` draws: each title at its own
+/// display width, preceded and followed by one column of padding, with a one-column
+/// divider between tabs (but not after the last one). Returns `None` for a click that
+/// lands on padding, a divider, or past the last tab, rather than snapping to the
+/// nearest tab as an even-width split would.
+pub fn tab_click_index(titles: &[&str], inner_x: u16, inner_width: u16, x: u16) -> Option<usize> {
+    let right = inner_x.saturating_add(inner_width);
+    let mut cursor = inner_x;
+    for (i, title) in titles.iter().enumerate() {
+        let is_last = i + 1 == titles.len();
+        cursor = cursor.saturating_add(TAB_PADDING);
+        if cursor >= right {
+            return None;
+        }
+        let title_width = Line::from(*title).width() as u16;
+        let title_end = cursor.saturating_add(title_width).min(right);
+        if x >= cursor && x < title_end {
+            return Some(i);
+        }
+        cursor = title_end.saturating_add(TAB_PADDING);
+        if is_last || cursor >= right {
+            return None;
+        }
+        cursor = cursor.saturating_add(TAB_DIVIDER_WIDTH);
+    }
+    None
+}
+
 /// Render navigation tabs
 fn render_tabs(frame: &mut Frame, app: &App, area: Rect) {
-    let mut titles = vec!["🏠 Home [1]", "💻 EC2 [2]", "λ Lambda [3]", "ℹ️ About [4]"];
-    
-    // Only show Logs tab if enabled (always last)
-    if app.settings.show_logs_panel {
-        titles.push("📋 Logs [5]");
-    }
-    
+    app.tab_strip_area.set(area);
+
+    let titles = tab_titles(app.settings.show_logs_panel);
+
     let selected_idx = match app.current_screen {
         Screen::Home => 0,
         Screen::Ec2 => 1,
         Screen::Lambda => 2,
-        Screen::About => 3,
-        Screen::Logs => if app.settings.show_logs_panel { 4 } else { 0 },
+        Screen::AutoScaling => 3,
+        Screen::About => 4,
+        Screen::Logs => if app.settings.show_logs_panel { 5 } else { 0 },
     };
-    
+
+    // Only worth a line of chrome once there's something to switch between - see
+    // `App::open_or_switch_profile`/`cycle_workspace` (bound to `[`/`]`).
+    let title = if app.workspaces.len() > 1 {
+        let names: Vec<String> = app
+            .workspaces
+            .iter()
+            .enumerate()
+            .map(|(i, w)| {
+                let name = app.settings.profile_display_name(&w.profile_name);
+                if i == app.active_workspace { format!("[{}]", name) } else { name.to_string() }
+            })
+            .collect();
+        format!(" AWS Cloud Controller — {} ", names.join(" "))
+    } else {
+        " AWS Cloud Controller ".to_string()
+    };
+
     let tabs = Tabs::new(titles)
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(" AWS Cloud Controller ")
-                .border_style(Style::default().fg(Color::Cyan)),
+                .title(title)
+                .border_style(style_of(&app.theme, Styles::Logo)),
         )
         .select(selected_idx)
-        .style(Style::default().fg(Color::White))
+        .style(style_of(&app.theme, Styles::Default))
         .highlight_style(
-            Style::default()
-                .fg(Color::Yellow)
+            style_of(&app.theme, Styles::Warning)
                 .add_modifier(Modifier::BOLD),
         );
 
@@ -99,6 +164,7 @@ fn render_content(frame: &mut Frame, app: &App, area: Rect) {
         Screen::Home => render_home(frame, app, area),
         Screen::Ec2 => render_ec2(frame, app, area),
         Screen::Lambda => render_lambda(frame, app, area),
+        Screen::AutoScaling => render_autoscaling(frame, app, area),
         Screen::Logs => render_logs(frame, app, area),
         Screen::About => render_about(frame, app, area),
     }
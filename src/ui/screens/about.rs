@@ -7,6 +7,7 @@ use ratatui::{
 };
 
 use crate::app::App;
+use crate::theme::{style_of, Styles};
 use crate::ui::utils::pad_rect;
 
 /// Render About screen
@@ -15,7 +16,7 @@ pub fn render_about(frame: &mut Frame, app: &App, area: Rect) {
     let outer_block = Block::default()
         .borders(Borders::ALL)
         .title(" About ")
-        .border_style(Style::default().fg(Color::Blue));
+        .border_style(style_of(&app.theme, Styles::Primary));
     frame.render_widget(outer_block.clone(), area);
     
     // Get padded inner area
@@ -26,7 +27,7 @@ pub fn render_about(frame: &mut Frame, app: &App, area: Rect) {
     // About content
     let about_content = vec![
         Line::from(""),
-        Line::from(Span::styled("AWS Cloud Controller", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))),
+        Line::from(Span::styled("AWS Cloud Controller", style_of(&app.theme, Styles::Logo).add_modifier(Modifier::BOLD))),
         Line::from(""),
         Line::from(Span::styled(format!("Version: {}", env!("CARGO_PKG_VERSION")), Style::default().fg(Color::Yellow))),
         Line::from(""),
@@ -55,6 +56,11 @@ pub fn render_about(frame: &mut Frame, app: &App, area: Rect) {
         Line::from(Span::styled("🛠️  Built With", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))),
         Line::from(""),
         Line::from(Span::styled("Rust • Ratatui • AWS SDK • Tokio • Rodio", Style::default().fg(Color::DarkGray))),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Logs: ", Style::default().fg(Color::Gray)),
+            Span::styled(crate::telemetry::log_dir().display().to_string(), Style::default().fg(Color::DarkGray)),
+        ]),
     ];
 
     // Credits content
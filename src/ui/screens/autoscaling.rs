@@ -0,0 +1,145 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap},
+    Frame,
+};
+
+use crate::app::App;
+use crate::theme::{style_of, Styles};
+use crate::ui::utils::pad_rect;
+
+/// Render Auto Scaling Groups screen
+pub fn render_autoscaling(frame: &mut Frame, app: &App, area: Rect) {
+    let outer_block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(" Auto Scaling Groups ({}) ", app.asg_groups.len()))
+        .border_style(style_of(&app.theme, Styles::Primary));
+    frame.render_widget(outer_block.clone(), area);
+
+    let inner_area = outer_block.inner(area);
+    let padded_area = pad_rect(inner_area, 2, 1, 1, 0);
+
+    if app.asg_groups.is_empty() {
+        let msg = Paragraph::new(vec![
+            Line::from("No Auto Scaling Groups loaded."),
+            Line::from(""),
+            Line::from(Span::styled("Press [r] to refresh", style_of(&app.theme, Styles::Warning))),
+        ])
+        .block(Block::default());
+        frame.render_widget(msg, padded_area);
+        return;
+    }
+
+    // Split table and member-instance detail pane on wide terminals, matching the
+    // Lambda/EC2 screens' responsive layout.
+    let is_wide = area.width >= 100;
+
+    let (table_area, detail_area) = if is_wide {
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+            .split(padded_area);
+        (chunks[0], Some(chunks[1]))
+    } else {
+        (padded_area, None)
+    };
+
+    let rows: Vec<Row> = app
+        .asg_groups
+        .iter()
+        .enumerate()
+        .map(|(i, group)| {
+            let selected_style = if i == app.asg_selected {
+                Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+
+            Row::new(vec![
+                Cell::from(if i == app.asg_selected { "▶" } else { " " }),
+                Cell::from(group.name.clone()),
+                Cell::from(group.desired_capacity.to_string()),
+                Cell::from(group.min_size.to_string()),
+                Cell::from(group.max_size.to_string()),
+                Cell::from(format!("{}/{}", group.healthy_count, group.instance_ids.len())),
+            ])
+            .style(selected_style)
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(2),  // Selector
+            Constraint::Min(20),    // Name
+            Constraint::Length(9),  // Desired
+            Constraint::Length(5),  // Min
+            Constraint::Length(5),  // Max
+            Constraint::Length(10), // Healthy
+        ],
+    )
+    .header(
+        Row::new(vec!["", "Name", "Desired", "Min", "Max", "Healthy"])
+            .style(style_of(&app.theme, Styles::Logo).add_modifier(Modifier::BOLD))
+            .bottom_margin(1),
+    )
+    .block(Block::default());
+
+    frame.render_widget(table, table_area);
+
+    if let Some(detail_area) = detail_area {
+        render_detail_pane(frame, app, detail_area);
+    }
+}
+
+/// Render the selected group's capacity and member instances, with the instance that
+/// [Enter]/[d] currently act on highlighted
+fn render_detail_pane(frame: &mut Frame, app: &App, area: Rect) {
+    let Some(group) = app.asg_groups.get(app.asg_selected) else {
+        return;
+    };
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Group: ", style_of(&app.theme, Styles::Secondary)),
+            Span::styled(group.name.clone(), Style::default().add_modifier(Modifier::BOLD)),
+        ]),
+        Line::from(vec![
+            Span::styled("Capacity: ", style_of(&app.theme, Styles::Secondary)),
+            Span::raw(format!("{} desired ({}-{})", group.desired_capacity, group.min_size, group.max_size)),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled("[z] Scale to zero    [Enter] Select instance    [d] Detach", style_of(&app.theme, Styles::Help))),
+        Line::from(""),
+        Line::from(Span::styled("Instances", style_of(&app.theme, Styles::Primary).add_modifier(Modifier::BOLD))),
+        Line::from(""),
+    ];
+
+    if group.instance_ids.is_empty() {
+        lines.push(Line::from(Span::styled("No member instances.", style_of(&app.theme, Styles::Secondary))));
+    } else {
+        for (i, id) in group.instance_ids.iter().enumerate() {
+            let marker = if i == app.asg_instance_selected { "▶ " } else { "  " };
+            let style = if i == app.asg_instance_selected {
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            lines.push(Line::from(Span::styled(format!("{}{}", marker, id), style)));
+        }
+    }
+
+    let detail = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Details ")
+                .border_style(style_of(&app.theme, Styles::Secondary)),
+        )
+        .wrap(Wrap { trim: true })
+        .scroll((app.scroll_offset, 0));
+
+    frame.render_widget(detail, area);
+}
@@ -1,21 +1,30 @@
 use ratatui::{
-    layout::{Constraint, Rect},
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
+    symbols,
     text::{Line, Span},
-    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
+    widgets::{Axis, Block, Borders, Cell, Chart, Dataset, GraphType, Paragraph, Row, Table},
     Frame,
 };
 
 use crate::app::App;
+use crate::theme::{style_of, Styles};
 use crate::ui::utils::pad_rect;
 
 /// Render EC2 instances screen
 pub fn render_ec2(frame: &mut Frame, app: &App, area: Rect) {
+    let matches = app.ec2_search_matches();
+    let title = if app.search_query.is_empty() {
+        format!(" EC2 Instances ({}) ", app.ec2_instances.len())
+    } else {
+        format!(" EC2 Instances ({}/{}) — /{} ", matches.len(), app.ec2_instances.len(), app.search_query)
+    };
+
     // Render outer block
     let outer_block = Block::default()
         .borders(Borders::ALL)
-        .title(format!(" EC2 Instances ({}) ", app.ec2_instances.len()))
-        .border_style(Style::default().fg(Color::Blue));
+        .title(title)
+        .border_style(style_of(&app.theme, Styles::Primary));
     frame.render_widget(outer_block.clone(), area);
     
     // Get padded inner area
@@ -33,17 +42,56 @@ pub fn render_ec2(frame: &mut Frame, app: &App, area: Rect) {
         return;
     }
 
-    // Create table rows
-    let rows: Vec<Row> = app
+    if matches.is_empty() {
+        let msg = Paragraph::new(vec![
+            Line::from(format!("No instances match \"/{}\".", app.search_query)),
+            Line::from(""),
+            Line::from(Span::styled("Press [Esc] to clear the search", style_of(&app.theme, Styles::Help))),
+        ])
+        .block(Block::default()); // No border
+        frame.render_widget(msg, padded_area);
+        return;
+    }
+
+    // Split table and metrics chart on wide terminals with a selected instance; narrow
+    // terminals keep the table-only view since a chart needs real horizontal room.
+    let is_wide = area.width >= 100;
+    let selected_metrics = app
         .ec2_instances
+        .get(app.ec2_selected)
+        .and_then(|i| app.ec2_metrics.get(&i.id));
+
+    let (table_area, chart_area) = if is_wide && selected_metrics.is_some() {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(padded_area);
+        (chunks[0], Some(chunks[1]))
+    } else {
+        (padded_area, None)
+    };
+
+    // Create table rows, filtered down to the live-search matches
+    let rows: Vec<Row> = matches
         .iter()
-        .enumerate()
-        .map(|(i, instance)| {
-            let state_style = match instance.state.as_str() {
-                "running" => Style::default().fg(Color::Green),
-                "stopped" => Style::default().fg(Color::Red),
-                "pending" | "stopping" => Style::default().fg(Color::Yellow),
-                _ => Style::default().fg(Color::Gray),
+        .map(|&i| {
+            let instance = &app.ec2_instances[i];
+            // A sticky reboot overrides whatever transient state a refresh observes
+            // (e.g. "stopping"/"stopped") until the instance is seen running again.
+            let is_rebooting = app.rebooting_instances.contains(&instance.id);
+            let lifecycle_state = app.instance_lifecycle.get(&instance.id).map(|l| l.state);
+            let (state_label, state_style) = if is_rebooting {
+                ("rebooting".to_string(), style_of(&app.theme, Styles::Warning))
+            } else if lifecycle_state == Some(crate::app::state::InstanceLifecycleState::Draining) {
+                ("draining".to_string(), style_of(&app.theme, Styles::Warning))
+            } else {
+                let style = match instance.state.as_str() {
+                    "running" => style_of(&app.theme, Styles::Success),
+                    "stopped" => style_of(&app.theme, Styles::Failure),
+                    "pending" | "stopping" => style_of(&app.theme, Styles::Warning),
+                    _ => style_of(&app.theme, Styles::Secondary),
+                };
+                (instance.state.clone(), style)
             };
 
             let selected_style = if i == app.ec2_selected {
@@ -56,14 +104,20 @@ pub fn render_ec2(frame: &mut Frame, app: &App, area: Rect) {
             let has_schedule = app.auto_stop_schedules.iter().any(|(id, _)| *id == instance.id);
             let schedule_indicator = if has_schedule { "⏰" } else { "" };
 
+            // Connectable means `o`/`g` would actually have somewhere to connect to:
+            // running, with a public IP for the direct-SSH path
+            let is_connectable = instance.state == "running" && instance.public_ip.is_some();
+            let connect_indicator = if is_connectable { "🔌" } else { "" };
+
             Row::new(vec![
                 Cell::from(if i == app.ec2_selected { "▶" } else { " " }),
                 Cell::from(instance.name.clone()),
                 Cell::from(instance.id.clone()),
                 Cell::from(instance.instance_type.clone()),
-                Cell::from(Span::styled(instance.state.clone(), state_style)),
+                Cell::from(Span::styled(state_label, state_style)),
                 Cell::from(instance.public_ip.clone().unwrap_or_else(|| "-".to_string())),
                 Cell::from(schedule_indicator),
+                Cell::from(connect_indicator),
             ])
             .style(selected_style)
         })
@@ -79,16 +133,104 @@ pub fn render_ec2(frame: &mut Frame, app: &App, area: Rect) {
             Constraint::Length(12), // State
             Constraint::Length(16), // IP
             Constraint::Length(3),  // Schedule
+            Constraint::Length(3),  // Connectable
         ],
     )
     .header(
-        Row::new(vec!["", "Name", "Instance ID", "Type", "State", "Public IP", "⏰"])
-            .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+        Row::new(vec!["", "Name", "Instance ID", "Type", "State", "Public IP", "⏰", "🔌"])
+            .style(style_of(&app.theme, Styles::Logo).add_modifier(Modifier::BOLD))
             .bottom_margin(1),
     )
     .block(Block::default()); // No border
 
-    // Use stateful widget for scrolling
+    // Record the row area (below the 1-line header + its bottom margin) for
+    // `App::handle_click` to hit-test clicks against
+    let header_height = 2;
+    app.ec2_table_area.set(Rect {
+        y: table_area.y.saturating_add(header_height).min(table_area.y + table_area.height),
+        height: table_area.height.saturating_sub(header_height),
+        ..table_area
+    });
+
+    // Use stateful widget for scrolling, remapping the stored (absolute) selection onto
+    // its position within the filtered rows actually being rendered
     let mut state = app.ec2_table_state.clone();
-    frame.render_stateful_widget(table, padded_area, &mut state);
+    state.select(matches.iter().position(|&i| i == app.ec2_selected));
+    frame.render_stateful_widget(table, table_area, &mut state);
+
+    if let (Some(chart_area), Some(metrics)) = (chart_area, selected_metrics) {
+        render_metrics_chart(frame, app, chart_area, metrics);
+    }
+}
+
+/// Render a live CPU/network line chart for the selected instance
+fn render_metrics_chart(frame: &mut Frame, app: &App, area: Rect, metrics: &crate::app::state::MetricHistory) {
+    let cpu: Vec<(f64, f64)> = metrics.cpu.iter().copied().collect();
+    let net_in: Vec<(f64, f64)> = metrics.network_in.iter().copied().collect();
+    let net_out: Vec<(f64, f64)> = metrics.network_out.iter().copied().collect();
+
+    if cpu.is_empty() && net_in.is_empty() && net_out.is_empty() {
+        let msg = Paragraph::new("No CloudWatch datapoints yet.")
+            .block(Block::default().borders(Borders::ALL).title(" Metrics "));
+        frame.render_widget(msg, area);
+        return;
+    }
+
+    let all_points = cpu.iter().chain(net_in.iter()).chain(net_out.iter());
+    let (mut x_min, mut x_max, y_min, mut y_max) = (f64::MAX, f64::MIN, 0.0_f64, 0.0_f64);
+    for &(x, y) in all_points {
+        x_min = x_min.min(x);
+        x_max = x_max.max(x);
+        y_max = y_max.max(y);
+    }
+    if x_min > x_max {
+        x_min = 0.0;
+        x_max = 1.0;
+    }
+    y_max = y_max.max(1.0);
+
+    let datasets = vec![
+        Dataset::default()
+            .name("CPU %")
+            .graph_type(GraphType::Line)
+            .marker(symbols::Marker::Braille)
+            .style(style_of(&app.theme, Styles::Success))
+            .data(&cpu),
+        Dataset::default()
+            .name("NetIn")
+            .graph_type(GraphType::Line)
+            .marker(symbols::Marker::Braille)
+            .style(style_of(&app.theme, Styles::Primary))
+            .data(&net_in),
+        Dataset::default()
+            .name("NetOut")
+            .graph_type(GraphType::Line)
+            .marker(symbols::Marker::Braille)
+            .style(style_of(&app.theme, Styles::Help))
+            .data(&net_out),
+    ];
+
+    let chart = Chart::new(datasets)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Metrics (last 30m) ")
+                .border_style(style_of(&app.theme, Styles::Secondary)),
+        )
+        .x_axis(
+            Axis::default()
+                .style(style_of(&app.theme, Styles::Secondary))
+                .bounds([x_min, x_max]),
+        )
+        .y_axis(
+            Axis::default()
+                .style(style_of(&app.theme, Styles::Secondary))
+                .bounds([y_min, y_max])
+                .labels(vec![
+                    Line::from(format!("{:.0}", y_min)),
+                    Line::from(format!("{:.0}", y_max)),
+                ]),
+        );
+
+    frame.render_widget(chart, area);
 }
@@ -6,19 +6,58 @@ use ratatui::{
     Frame,
 };
 
+use chrono::Utc;
+
 use crate::app::App;
+use crate::theme::{style_of, Styles};
 use crate::ui::utils::pad_rect;
 
+/// "Profile: <name>  expires in HH:MM" Quick Stats line, colored yellow once under ten
+/// minutes remaining and red once the active profile's credentials have expired
+fn profile_status_line(app: &App) -> Line<'static> {
+    let profile = app.active_profile_name.as_deref().unwrap_or("(none)");
+    let profile_label = app.settings.profile_display_name(profile).to_string();
+
+    let mut spans = vec![
+        Span::styled("   Profile:           ", Style::default().fg(Color::Gray)),
+        Span::styled(profile_label, Style::default().fg(Color::Green)),
+    ];
+
+    if let Some(expiration) = app.credential_expiration {
+        let remaining = expiration.signed_duration_since(Utc::now());
+        let expired = remaining.num_seconds() <= 0;
+        let total_minutes = remaining.num_minutes().unsigned_abs();
+        let hh = total_minutes / 60;
+        let mm = total_minutes % 60;
+
+        let color = if expired {
+            Color::Red
+        } else if remaining <= chrono::Duration::minutes(10) {
+            Color::Yellow
+        } else {
+            Color::Green
+        };
+        let text = if expired {
+            format!("  expired {:02}:{:02} ago", hh, mm)
+        } else {
+            format!("  expires in {:02}:{:02}", hh, mm)
+        };
+        spans.push(Span::styled(text, Style::default().fg(color)));
+    }
+
+    Line::from(spans)
+}
+
 /// Render home screen - unified panel with responsive layout
 pub fn render_home(frame: &mut Frame, app: &App, area: Rect) {
     // Determine layout based on terminal width
     let is_wide = area.width >= 100;
-    
+
     // Render outer block first
     let outer_block = Block::default()
         .borders(Borders::ALL)
         .title(" Dashboard ")
-        .border_style(Style::default().fg(Color::Blue));
+        .border_style(style_of(&app.theme, Styles::Primary));
     frame.render_widget(outer_block.clone(), area);
     
     // Get inner area and apply padding for content
@@ -31,12 +70,12 @@ pub fn render_home(frame: &mut Frame, app: &App, area: Rect) {
         let dashboard_lines = vec![
             Line::from(""),
             Line::from(vec![
-                Span::styled("Welcome to ", Style::default().fg(Color::White)),
-                Span::styled("AWS Cloud Controller", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                Span::styled("Welcome to ", style_of(&app.theme, Styles::Default)),
+                Span::styled("AWS Cloud Controller", style_of(&app.theme, Styles::Logo).add_modifier(Modifier::BOLD)),
             ]),
             Line::from(""),
             Line::from(""),
-            Line::from(Span::styled("📊 Quick Stats", Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD))),
+            Line::from(Span::styled("📊 Quick Stats", style_of(&app.theme, Styles::Help).add_modifier(Modifier::BOLD))),
             Line::from(""),
             Line::from(vec![
                 Span::styled("   Region:            ", Style::default().fg(Color::Gray)),
@@ -54,6 +93,7 @@ pub fn render_home(frame: &mut Frame, app: &App, area: Rect) {
                 Span::styled("   Auto-stop Timers:  ", Style::default().fg(Color::Gray)),
                 Span::styled(app.auto_stop_schedules.len().to_string(), Style::default().fg(Color::Magenta)),
             ]),
+            profile_status_line(app),
             Line::from(""),
             Line::from(""),
             Line::from(Span::styled("💡 Quick Tips", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))),
@@ -138,11 +178,11 @@ pub fn render_home(frame: &mut Frame, app: &App, area: Rect) {
         let stacked_lines = vec![
             Line::from(""),
             Line::from(vec![
-                Span::styled("Welcome to ", Style::default().fg(Color::White)),
-                Span::styled("AWS Cloud Controller", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                Span::styled("Welcome to ", style_of(&app.theme, Styles::Default)),
+                Span::styled("AWS Cloud Controller", style_of(&app.theme, Styles::Logo).add_modifier(Modifier::BOLD)),
             ]),
             Line::from(""),
-            Line::from(Span::styled("📊 Quick Stats", Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD))),
+            Line::from(Span::styled("📊 Quick Stats", style_of(&app.theme, Styles::Help).add_modifier(Modifier::BOLD))),
             Line::from(""),
             Line::from(vec![
                 Span::styled("   Region: ", Style::default().fg(Color::Gray)),
@@ -152,6 +192,7 @@ pub fn render_home(frame: &mut Frame, app: &App, area: Rect) {
                 Span::styled("   Lambda: ", Style::default().fg(Color::Gray)),
                 Span::styled(app.lambda_functions.len().to_string(), Style::default().fg(Color::Yellow)),
             ]),
+            profile_status_line(app),
             Line::from(""),
             Line::from(Span::styled("💡 Tips: [r] refresh  [?] help  [2] EC2  [4] About", Style::default().fg(Color::DarkGray))),
             Line::from(""),
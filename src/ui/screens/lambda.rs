@@ -1,47 +1,172 @@
 use ratatui::{
-    layout::Rect,
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Wrap},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap},
     Frame,
 };
 
 use crate::app::App;
+use crate::theme::{style_of, Styles};
 use crate::ui::utils::pad_rect;
 
 /// Render Lambda functions screen
-pub fn render_lambda(frame: &mut Frame, _app: &App, area: Rect) {
+pub fn render_lambda(frame: &mut Frame, app: &App, area: Rect) {
+    let matches = app.lambda_search_matches();
+    let title = if app.search_query.is_empty() {
+        format!(" Lambda Functions ({}) ", app.lambda_functions.len())
+    } else {
+        format!(" Lambda Functions ({}/{}) — /{} ", matches.len(), app.lambda_functions.len(), app.search_query)
+    };
+
     // Render outer block
     let outer_block = Block::default()
         .borders(Borders::ALL)
-        .title(" Lambda Functions ")
-        .border_style(Style::default().fg(Color::Blue));
+        .title(title)
+        .border_style(style_of(&app.theme, Styles::Primary));
     frame.render_widget(outer_block.clone(), area);
-    
+
     // Get padded inner area
     let inner_area = outer_block.inner(area);
-    let padded_area = pad_rect(inner_area, 2, 1, 0, 0);
+    let padded_area = pad_rect(inner_area, 2, 1, 1, 0);
 
-    let content = Paragraph::new(vec![
-        Line::from(""),
-        Line::from(Span::styled(
-            "   🚧 Lambda Module - Coming Soon! 🚧",
-            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
-        )),
-        Line::from(""),
-        Line::from(""),
-        Line::from("   Features planned for next version:"),
-        Line::from(""),
-        Line::from(Span::styled("   • List all Lambda functions", Style::default().fg(Color::Gray))),
-        Line::from(Span::styled("   • View function details (runtime, memory, timeout)", Style::default().fg(Color::Gray))),
-        Line::from(Span::styled("   • Invoke functions directly", Style::default().fg(Color::Gray))),
-        Line::from(Span::styled("   • View recent invocation logs", Style::default().fg(Color::Gray))),
+    if app.lambda_functions.is_empty() {
+        let msg = Paragraph::new(vec![
+            Line::from("No Lambda functions loaded."),
+            Line::from(""),
+            Line::from(Span::styled("Press [r] to refresh", style_of(&app.theme, Styles::Warning))),
+        ])
+        .block(Block::default()); // No border
+        frame.render_widget(msg, padded_area);
+        return;
+    }
+
+    if matches.is_empty() {
+        let msg = Paragraph::new(vec![
+            Line::from(format!("No functions match \"/{}\".", app.search_query)),
+            Line::from(""),
+            Line::from(Span::styled("Press [Esc] to clear the search", style_of(&app.theme, Styles::Help))),
+        ])
+        .block(Block::default()); // No border
+        frame.render_widget(msg, padded_area);
+        return;
+    }
+
+    // Split table and detail/invoke pane on wide terminals; narrow terminals keep the
+    // table-only view, matching the EC2 screen's responsive layout.
+    let is_wide = area.width >= 100;
+
+    let (table_area, detail_area) = if is_wide {
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+            .split(padded_area);
+        (chunks[0], Some(chunks[1]))
+    } else {
+        (padded_area, None)
+    };
+
+    // Create table rows, filtered down to the live-search matches
+    let rows: Vec<Row> = matches
+        .iter()
+        .map(|&i| {
+            let func = &app.lambda_functions[i];
+            let selected_style = if i == app.lambda_selected {
+                Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+
+            Row::new(vec![
+                Cell::from(if i == app.lambda_selected { "▶" } else { " " }),
+                Cell::from(func.name.clone()),
+                Cell::from(func.runtime.clone()),
+                Cell::from(format!("{} MB", func.memory)),
+                Cell::from(format!("{}s", func.timeout)),
+                Cell::from(func.last_modified.clone()),
+            ])
+            .style(selected_style)
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(2),  // Selector
+            Constraint::Min(20),    // Name
+            Constraint::Length(14), // Runtime
+            Constraint::Length(9),  // Memory
+            Constraint::Length(7),  // Timeout
+            Constraint::Length(20), // Last modified
+        ],
+    )
+    .header(
+        Row::new(vec!["", "Name", "Runtime", "Memory", "Timeout", "Last Modified"])
+            .style(style_of(&app.theme, Styles::Logo).add_modifier(Modifier::BOLD))
+            .bottom_margin(1),
+    )
+    .block(Block::default()); // No border
+
+    frame.render_widget(table, table_area);
+
+    if let Some(detail_area) = detail_area {
+        render_detail_pane(frame, app, detail_area);
+    }
+}
+
+/// Render the selected function's detail, invoke response and recent logs tail
+fn render_detail_pane(frame: &mut Frame, app: &App, area: Rect) {
+    let Some(func) = app.lambda_functions.get(app.lambda_selected) else {
+        return;
+    };
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Function: ", style_of(&app.theme, Styles::Secondary)),
+            Span::styled(func.name.clone(), Style::default().add_modifier(Modifier::BOLD)),
+        ]),
+        Line::from(vec![
+            Span::styled("Description: ", style_of(&app.theme, Styles::Secondary)),
+            Span::raw(if func.description.is_empty() { "-" } else { &func.description }),
+        ]),
         Line::from(""),
+        Line::from(Span::styled("[i] Invoke with a JSON payload", style_of(&app.theme, Styles::Help))),
         Line::from(""),
-        Line::from(Span::styled("   Stay tuned! 🎉", Style::default().fg(Color::Cyan))),
-    ])
-    .block(Block::default()) // No border
-    .wrap(Wrap { trim: true });
+    ];
+
+    lines.push(Line::from(Span::styled(
+        "Last Response",
+        style_of(&app.theme, Styles::Primary).add_modifier(Modifier::BOLD),
+    )));
+    lines.push(Line::from(""));
+    match &app.lambda_last_response {
+        Some(response) => lines.push(Line::from(response.clone())),
+        None => lines.push(Line::from(Span::styled("No invocations yet.", style_of(&app.theme, Styles::Secondary)))),
+    }
+    lines.push(Line::from(""));
+
+    lines.push(Line::from(Span::styled(
+        "Recent Logs",
+        style_of(&app.theme, Styles::Primary).add_modifier(Modifier::BOLD),
+    )));
+    lines.push(Line::from(""));
+    if app.lambda_recent_logs.is_empty() {
+        lines.push(Line::from(Span::styled("No recent logs.", style_of(&app.theme, Styles::Secondary))));
+    } else {
+        for entry in &app.lambda_recent_logs {
+            lines.push(Line::from(entry.clone()));
+        }
+    }
+
+    let detail = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Details ")
+                .border_style(style_of(&app.theme, Styles::Secondary)),
+        )
+        .wrap(Wrap { trim: true })
+        .scroll((app.scroll_offset, 0));
 
-    frame.render_widget(content, padded_area);
+    frame.render_widget(detail, area);
 }
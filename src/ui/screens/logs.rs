@@ -7,23 +7,33 @@ use ratatui::{
 };
 
 use crate::app::App;
-use crate::logger::LogLevel;
+use crate::theme::{level_style_and_icon, style_of, Styles};
 use crate::ui::utils::pad_rect;
 
 /// Render logs screen
 pub fn render_logs(frame: &mut Frame, app: &App, area: Rect) {
+    // Filter entries based on the minimum level setting and the live search query, if any
+    let filtered_entries = app.log_manager.filtered_entries(app.settings.log_level, &app.search_query);
+    let total = app.log_manager.entries().len();
+
+    let title = if app.search_query.is_empty() {
+        format!(" Activity Logs ({}/{}, min {}) ", filtered_entries.len(), total, app.settings.format_log_level())
+    } else {
+        format!(" Activity Logs — /{} ({}/{}, min {}) ", app.search_query, filtered_entries.len(), total, app.settings.format_log_level())
+    };
+
     // Render outer block
     let outer_block = Block::default()
         .borders(Borders::ALL)
-        .title(format!(" Activity Logs ({}) ", app.log_manager.entries().len()))
-        .border_style(Style::default().fg(Color::Blue));
+        .title(title)
+        .border_style(style_of(&app.theme, Styles::Primary));
     frame.render_widget(outer_block.clone(), area);
     
     // Get padded inner area
     let inner_area = outer_block.inner(area);
     let padded_area = pad_rect(inner_area, 1, 1, 0, 0);
     
-    if app.log_manager.entries().is_empty() {
+    if total == 0 {
         let msg = Paragraph::new(vec![
             Line::from(""),
             Line::from(Span::styled("No log entries yet.", Style::default().fg(Color::DarkGray))),
@@ -34,17 +44,20 @@ pub fn render_logs(frame: &mut Frame, app: &App, area: Rect) {
         frame.render_widget(msg, padded_area);
         return;
     }
-    
+
+    if filtered_entries.is_empty() {
+        let msg = Paragraph::new(vec![
+            Line::from(""),
+            Line::from(Span::styled("No log entries match the current filter.", Style::default().fg(Color::DarkGray))),
+        ])
+        .block(Block::default());
+        frame.render_widget(msg, padded_area);
+        return;
+    }
+
     // Create log lines (showing most recent at the bottom)
     let visible_height = padded_area.height as usize;
-    let entries = app.log_manager.entries();
-    
-    // Filter entries based on verbosity setting
-    let filtered_entries: Vec<&crate::logger::LogEntry> = entries.iter()
-        .filter(|e| app.settings.should_show_log(e.level))
-        .collect();
-        
-    let scroll_offset = app.log_manager.scroll_offset();
+    let scroll_offset = app.scroll_offset as usize;
     
     // Calculate which entries to show
     let start_idx = filtered_entries.len().saturating_sub(visible_height + scroll_offset);
@@ -53,13 +66,7 @@ pub fn render_logs(frame: &mut Frame, app: &App, area: Rect) {
     let log_lines: Vec<Line> = filtered_entries[start_idx..end_idx]
         .iter()
         .map(|entry| {
-            let (level_style, level_icon) = match entry.level {
-                LogLevel::Debug => (Style::default().fg(Color::Magenta), "🔍"),
-                LogLevel::Info => (Style::default().fg(Color::Cyan), "ℹ"),
-                LogLevel::Success => (Style::default().fg(Color::Green), "✓"),
-                LogLevel::Warning => (Style::default().fg(Color::Yellow), "⚠"),
-                LogLevel::Error => (Style::default().fg(Color::Red), "✗"),
-            };
+            let (level_style, level_icon) = level_style_and_icon(&app.theme, app.settings.theme_palette, entry.level);
             
             let timestamp = entry.timestamp.format("%H:%M:%S").to_string();
             
@@ -0,0 +1,6 @@
+pub mod about;
+pub mod autoscaling;
+pub mod ec2;
+pub mod home;
+pub mod lambda;
+pub mod logs;